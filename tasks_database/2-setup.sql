@@ -1,10 +1,303 @@
+CREATE TABLE projects (
+    id SHORTKEY PRIMARY KEY,
+    name VARCHAR (100) NOT NULL,
+    description TEXT NOT NULL,
+    creator_id VARCHAR (50) NOT NULL,
+    created_at TIMESTAMP NOT NULL
+);
+
+CREATE TRIGGER trigger_projects_genid BEFORE INSERT ON projects FOR EACH ROW EXECUTE PROCEDURE shortkey_generate();
+
+CREATE INDEX projects_creator_id_idx ON projects (creator_id);
+
+-- Creator of the project isn't listed here since creator is always an owner
+CREATE TABLE project_members (
+    project_id SHORTKEY REFERENCES projects (id) ON DELETE CASCADE,
+    user_id VARCHAR (50) NOT NULL,
+    role INT NOT NULL,
+    PRIMARY KEY (project_id, user_id)
+);
+
+CREATE INDEX project_members_user_id_idx ON project_members (user_id);
+
+-- Role of the user in the project, creator is always an owner
+CREATE FUNCTION project_role(project SHORTKEY, username VARCHAR) RETURNS INT AS $$
+    SELECT
+        GREATEST(
+            CASE WHEN p.creator_id=username THEN 2 END,
+            (SELECT m.role FROM project_members m WHERE m.project_id=p.id AND m.user_id=username))
+    FROM
+        projects p
+    WHERE
+        p.id=project
+$$ LANGUAGE SQL STABLE;
+
+-- New tasks go to the bottom of board columns, positions are spaced out so
+-- tasks can be moved between neighbours without renumbering the column
+CREATE SEQUENCE task_positions;
+
 CREATE TABLE tasks (
     id SHORTKEY PRIMARY KEY,
     creator_id VARCHAR (50) NOT NULL, 
     created_at TIMESTAMP NOT NULL,
     title VARCHAR (150) NOT NULL,
     description TEXT,
-    status INT NOT NULL
+    status INT NOT NULL,
+    assigned_to VARCHAR (50),
+    reviewed_by VARCHAR (50),
+    version BIGINT NOT NULL DEFAULT 1,
+    deleted_at TIMESTAMP,
+    deleted_by VARCHAR (50),
+    start_date DATE,
+    due_date DATE,
+    priority INT NOT NULL DEFAULT 1 CHECK (priority BETWEEN 0 AND 3),
+    estimate_minutes INT,
+    -- Tasks of deleted project stay with their creators
+    project_id SHORTKEY REFERENCES projects (id) ON DELETE SET NULL,
+    position BIGINT NOT NULL DEFAULT nextval('task_positions') * 1024,
+    -- Subtasks of purged task become top-level tasks
+    parent_id SHORTKEY REFERENCES tasks (id) ON DELETE SET NULL,
+    -- Words of comments of the task, maintained by trigger on comments
+    comments_vector TSVECTOR NOT NULL DEFAULT '',
+    search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', title), 'A') ||
+        setweight(to_tsvector('simple', COALESCE(description, '')), 'B') ||
+        comments_vector
+    ) STORED,
+    -- Task can't start after it is due, dates may be changed one at a time
+    CHECK (start_date <= due_date)
 );
 
 CREATE TRIGGER trigger_tasks_genid BEFORE INSERT ON tasks FOR EACH ROW EXECUTE PROCEDURE shortkey_generate();
+
+CREATE INDEX tasks_creator_id_idx ON tasks (creator_id, created_at, id);
+CREATE INDEX tasks_assigned_to_idx ON tasks (assigned_to, created_at, id);
+CREATE INDEX tasks_reviewed_by_idx ON tasks (reviewed_by, created_at, id);
+CREATE INDEX tasks_search_vector_idx ON tasks USING GIN (search_vector);
+CREATE INDEX tasks_project_id_idx ON tasks (project_id, created_at, id);
+CREATE INDEX tasks_status_position_idx ON tasks (status, position, id) WHERE deleted_at IS NULL;
+CREATE INDEX tasks_due_date_idx ON tasks (due_date) WHERE due_date IS NOT NULL;
+CREATE INDEX tasks_deleted_at_idx ON tasks (deleted_at) WHERE deleted_at IS NOT NULL;
+CREATE INDEX tasks_parent_id_idx ON tasks (parent_id) WHERE parent_id IS NOT NULL;
+
+-- Task is blocked by another task until the blocker is completed or cancelled
+CREATE TABLE task_dependencies (
+    task_id SHORTKEY REFERENCES tasks (id) ON DELETE CASCADE,
+    blocker_id SHORTKEY REFERENCES tasks (id) ON DELETE CASCADE,
+    creator_id VARCHAR (50) NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    PRIMARY KEY (task_id, blocker_id)
+);
+
+CREATE INDEX task_dependencies_blocker_id_idx ON task_dependencies (blocker_id);
+
+-- Number of subtasks and completed ones as JSON object, cancelled and deleted
+-- subtasks aren't counted (statuses are Completed=4 and Cancelled=5)
+CREATE FUNCTION task_subtask_progress(task SHORTKEY) RETURNS JSONB AS $$
+    SELECT
+        jsonb_build_object('total', COUNT(*), 'completed', COUNT(*) FILTER (WHERE status=4))
+    FROM
+        tasks
+    WHERE
+        parent_id=task AND deleted_at IS NULL AND status<>5
+$$ LANGUAGE SQL STABLE;
+
+-- Number of blockers of the task which are neither completed nor cancelled
+CREATE FUNCTION task_open_blocker_count(task SHORTKEY) RETURNS INT AS $$
+    SELECT
+        COUNT(*)
+    FROM
+        task_dependencies d JOIN tasks b ON b.id=d.blocker_id
+    WHERE
+        d.task_id=task AND b.deleted_at IS NULL AND b.status NOT IN (4, 5)
+$$ LANGUAGE SQL STABLE;
+
+CREATE TABLE task_access (
+    task_id SHORTKEY REFERENCES tasks (id) ON DELETE CASCADE,
+    user_id VARCHAR (50) NOT NULL,
+    role INT NOT NULL,
+    PRIMARY KEY (task_id, user_id)
+);
+
+CREATE INDEX task_access_user_id_idx ON task_access (user_id);
+
+CREATE TABLE labels (
+    id SHORTKEY PRIMARY KEY,
+    name VARCHAR (50) NOT NULL,
+    color CHAR (7) NOT NULL,
+    description TEXT NOT NULL,
+    creator_id VARCHAR (50) NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    -- Each user has own labels
+    UNIQUE (creator_id, name)
+);
+
+CREATE TRIGGER trigger_labels_genid BEFORE INSERT ON labels FOR EACH ROW EXECUTE PROCEDURE shortkey_generate();
+
+CREATE TABLE task_labels (
+    task_id SHORTKEY REFERENCES tasks (id) ON DELETE CASCADE,
+    label_id SHORTKEY REFERENCES labels (id) ON DELETE CASCADE,
+    PRIMARY KEY (task_id, label_id)
+);
+
+CREATE INDEX task_labels_label_id_idx ON task_labels (label_id);
+
+-- Labels of the task as JSON array sorted by name
+CREATE FUNCTION task_label_list(task SHORTKEY) RETURNS JSONB AS $$
+    SELECT
+        COALESCE(jsonb_agg(jsonb_build_object(
+            'id', l.id, 'name', l.name, 'color', l.color, 'description', l.description, 'creator_id', l.creator_id
+        ) ORDER BY l.name), '[]')
+    FROM
+        task_labels tl JOIN labels l ON l.id=tl.label_id
+    WHERE
+        tl.task_id=task
+$$ LANGUAGE SQL STABLE;
+
+CREATE TABLE checklist_items (
+    id SHORTKEY PRIMARY KEY,
+    task_id SHORTKEY NOT NULL REFERENCES tasks (id) ON DELETE CASCADE,
+    text VARCHAR (500) NOT NULL,
+    done BOOLEAN NOT NULL DEFAULT FALSE,
+    assigned_to VARCHAR (50),
+    position INT NOT NULL,
+    created_at TIMESTAMP NOT NULL
+);
+
+CREATE TRIGGER trigger_checklist_items_genid BEFORE INSERT ON checklist_items FOR EACH ROW EXECUTE PROCEDURE shortkey_generate();
+
+CREATE INDEX checklist_items_task_id_idx ON checklist_items (task_id, position);
+
+-- Number of checklist items of the task and done ones as JSON object
+CREATE FUNCTION task_checklist_progress(task SHORTKEY) RETURNS JSONB AS $$
+    SELECT
+        jsonb_build_object('total', COUNT(*), 'done', COUNT(*) FILTER (WHERE done))
+    FROM
+        checklist_items
+    WHERE
+        task_id=task
+$$ LANGUAGE SQL STABLE;
+
+-- Content of attachments is kept in user_service storage under storage_key
+CREATE TABLE attachments (
+    id SHORTKEY PRIMARY KEY,
+    task_id SHORTKEY NOT NULL REFERENCES tasks (id) ON DELETE CASCADE,
+    uploader_id VARCHAR (50) NOT NULL,
+    filename VARCHAR (255) NOT NULL,
+    content_type VARCHAR (255) NOT NULL,
+    size BIGINT NOT NULL,
+    storage_key VARCHAR (100) UNIQUE NOT NULL,
+    created_at TIMESTAMP NOT NULL
+);
+
+CREATE TRIGGER trigger_attachments_genid BEFORE INSERT ON attachments FOR EACH ROW EXECUTE PROCEDURE shortkey_generate();
+
+CREATE INDEX attachments_task_id_idx ON attachments (task_id, created_at);
+
+-- Storage keys of deleted attachments which content is yet to be removed
+-- from storage, including attachments of purged tasks
+CREATE TABLE deleted_attachments (
+    storage_key VARCHAR (100) PRIMARY KEY,
+    deleted_at TIMESTAMP NOT NULL
+);
+
+CREATE FUNCTION attachments_deleted() RETURNS TRIGGER AS $$
+BEGIN
+    INSERT INTO deleted_attachments (storage_key, deleted_at) VALUES (OLD.storage_key, NOW()) ON CONFLICT DO NOTHING;
+    RETURN OLD;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trigger_attachments_deleted AFTER DELETE ON attachments FOR EACH ROW EXECUTE PROCEDURE attachments_deleted();
+
+-- Attachments of the task as JSON array, oldest first
+CREATE FUNCTION task_attachment_list(task SHORTKEY) RETURNS JSONB AS $$
+    SELECT
+        COALESCE(jsonb_agg(jsonb_build_object(
+            'id', id, 'filename', filename, 'content_type', content_type, 'size', size, 'uploader_id', uploader_id
+        ) ORDER BY created_at, id), '[]')
+    FROM
+        attachments
+    WHERE
+        task_id=task
+$$ LANGUAGE SQL STABLE;
+
+-- Time logged on tasks, entry of a running timer has started_at but no
+-- minutes yet
+CREATE TABLE time_entries (
+    id SHORTKEY PRIMARY KEY,
+    task_id SHORTKEY NOT NULL REFERENCES tasks (id) ON DELETE CASCADE,
+    user_id VARCHAR (50) NOT NULL,
+    spent_on DATE NOT NULL,
+    minutes INT,
+    started_at TIMESTAMP,
+    note VARCHAR (500) NOT NULL DEFAULT '',
+    created_at TIMESTAMP NOT NULL,
+    CHECK (minutes IS NOT NULL OR started_at IS NOT NULL)
+);
+
+CREATE TRIGGER trigger_time_entries_genid BEFORE INSERT ON time_entries FOR EACH ROW EXECUTE PROCEDURE shortkey_generate();
+
+CREATE INDEX time_entries_task_id_idx ON time_entries (task_id, spent_on);
+CREATE INDEX time_entries_spent_on_idx ON time_entries (spent_on);
+-- User has at most one running timer
+CREATE UNIQUE INDEX time_entries_running_idx ON time_entries (user_id) WHERE minutes IS NULL;
+
+-- Minutes logged on the task by finished entries
+CREATE FUNCTION task_logged_minutes(task SHORTKEY) RETURNS INT AS $$
+    SELECT
+        COALESCE(SUM(minutes), 0)::INT
+    FROM
+        time_entries
+    WHERE
+        task_id=task
+$$ LANGUAGE SQL STABLE;
+
+CREATE TABLE comments (
+    id SHORTKEY PRIMARY KEY,
+    task_id SHORTKEY NOT NULL REFERENCES tasks (id) ON DELETE CASCADE,
+    author_id VARCHAR (50) NOT NULL,
+    parent_id SHORTKEY REFERENCES comments (id) ON DELETE CASCADE,
+    text TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP
+);
+
+CREATE TRIGGER trigger_comments_genid BEFORE INSERT ON comments FOR EACH ROW EXECUTE PROCEDURE shortkey_generate();
+
+CREATE INDEX comments_task_id_idx ON comments (task_id, created_at);
+CREATE INDEX comments_parent_id_idx ON comments (parent_id);
+
+CREATE FUNCTION comments_changed() RETURNS TRIGGER AS $$
+DECLARE
+    task SHORTKEY;
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        task := OLD.task_id;
+    ELSE
+        task := NEW.task_id;
+    END IF;
+    UPDATE
+        tasks
+    SET
+        comments_vector=(SELECT setweight(to_tsvector('simple', COALESCE(string_agg(text, ' '), '')), 'C') FROM comments WHERE task_id=task)
+    WHERE
+        id=task;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trigger_comments_changed AFTER INSERT OR DELETE OR UPDATE OF text ON comments FOR EACH ROW EXECUTE PROCEDURE comments_changed();
+
+-- History isn't removed with the task to keep the audit trail
+CREATE TABLE task_history (
+    id BIGSERIAL PRIMARY KEY,
+    task_id SHORTKEY NOT NULL,
+    version BIGINT NOT NULL,
+    actor_id VARCHAR (50) NOT NULL,
+    changed_at TIMESTAMP NOT NULL,
+    action INT NOT NULL,
+    changes JSONB NOT NULL
+);
+
+CREATE INDEX task_history_task_id_idx ON task_history (task_id, id);
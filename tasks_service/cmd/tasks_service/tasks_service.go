@@ -2,25 +2,89 @@ package main
 
 import (
 	"context"
+	"errors"
 	"flag"
 	"fmt"
 	"log"
 	"net"
 	"os"
+	"slices"
+	"strconv"
+	"strings"
 	"time"
 
 	pb "github.com/MetaGigachad/task-tracker/tasks_service/internal/proto"
+	"github.com/jackc/pgx/v5"
+	"github.com/jackc/pgx/v5/pgconn"
+	"github.com/jackc/pgx/v5/pgtype"
 	"github.com/jackc/pgx/v5/pgxpool"
 	"google.golang.org/grpc"
 	"google.golang.org/grpc/reflection"
 	"google.golang.org/protobuf/types/known/timestamppb"
 )
 
+const (
+	ErrCodeDatabase           int32 = 1
+	ErrCodeAccess             int32 = 2
+	ErrCodeConflict           int32 = 3
+	ErrCodeTransition         int32 = 4
+	ErrCodeVersion            int32 = 5
+	ErrCodeLabelExists        int32 = 6
+	ErrCodeLabelNotFound      int32 = 7
+	ErrCodeProjectAccess      int32 = 8
+	ErrCodePosition           int32 = 9
+	ErrCodeCycle              int32 = 10
+	ErrCodeBlocked            int32 = 11
+	ErrCodeItemNotFound       int32 = 12
+	ErrCodeAttachmentNotFound int32 = 13
+	ErrCodeTimeEntryNotFound  int32 = 14
+	ErrCodeTimerNotRunning    int32 = 15
+	ErrCodeDates              int32 = 16
+)
+
+// SQLSTATE codes of constraint violations.
+const (
+	pgUniqueViolation     = "23505"
+	pgForeignKeyViolation = "23503"
+	pgCheckViolation      = "23514"
+)
+
 type server struct {
 	pb.UnimplementedTasksServiceServer
 	dbConn *pgxpool.Pool
 }
 
+// Connection pool or transaction which task operations are executed in.
+type querier interface {
+	Exec(ctx context.Context, sql string, args ...any) (pgconn.CommandTag, error)
+	Query(ctx context.Context, sql string, args ...any) (pgx.Rows, error)
+	QueryRow(ctx context.Context, sql string, args ...any) pgx.Row
+}
+
+// Response of operation which has error if the operation failed.
+type errorResponse interface {
+	GetError() *pb.Error
+}
+
+// Executes operation in a transaction which is committed only if the
+// operation succeeds. Errors of the transaction itself are made into response
+// by makeError.
+func inTransaction[T errorResponse](ctx context.Context, dbConn *pgxpool.Pool, makeError func(int32, string) (T, error), op func(db querier) (T, error)) (T, error) {
+	tx, err := dbConn.Begin(ctx)
+	if err != nil {
+		return makeError(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	defer tx.Rollback(ctx)
+	response, err := op(tx)
+	if err != nil || response.GetError() != nil {
+		return response, err
+	}
+	if err := tx.Commit(ctx); err != nil {
+		return makeError(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return response, nil
+}
+
 func MakeErrorResponse(id int32, msg string) (*pb.TaskResponse, error) {
 	return &pb.TaskResponse{
 		Response: &pb.TaskResponse_Error{
@@ -32,186 +96,3014 @@ func MakeErrorResponse(id int32, msg string) (*pb.TaskResponse, error) {
 	}, nil
 }
 
+const taskColumns = `id, created_at, title, description, status, assigned_to, reviewed_by, version, start_date, due_date, priority, estimate_minutes, task_label_list(id), project_id, position,
+	parent_id, task_subtask_progress(id), task_open_blocker_count(id), task_checklist_progress(id), task_attachment_list(id),
+	task_logged_minutes(id)`
+
+// Dates of tasks are passed as strings in this layout.
+const dateLayout = "2006-01-02"
+
+// Escapes wildcards of LIKE pattern.
+var likeEscaper = strings.NewReplacer(`\`, `\\`, `%`, `\%`, `_`, `\_`)
+
+func MakeTaskResponse(task *pb.Task) (*pb.TaskResponse, error) {
+	return &pb.TaskResponse{
+		Response: &pb.TaskResponse_Task{
+			Task: task,
+		},
+	}, nil
+}
+
+func MakeTaskPageErrorResponse(id int32, msg string) (*pb.TaskPageResponse, error) {
+	return &pb.TaskPageResponse{
+		Response: &pb.TaskPageResponse_Error{
+			Error: &pb.Error{
+				Code:    id,
+				Message: msg,
+			},
+		},
+	}, nil
+}
+
+// Effective role of user $2 on task t. Creator is an owner and assignee or
+// reviewer is an editor unless access table or membership in the project of
+// the task grants a higher role.
+const taskRoleExpression = `
+	GREATEST(
+		CASE WHEN t.creator_id=$2 THEN 2 END,
+		CASE WHEN t.assigned_to=$2 OR t.reviewed_by=$2 THEN 1 END,
+		(SELECT a.role FROM task_access a WHERE a.task_id=t.id AND a.user_id=$2),
+		project_role(t.project_id, $2))`
+
+// Effective role of user $2 on task $1 which is in trash if $3 is true.
+const taskRoleQuery = `
+	SELECT
+		` + taskRoleExpression + `
+	FROM
+		tasks t
+	WHERE
+		t.id=$1 AND (t.deleted_at IS NOT NULL)=$3`
+
+// Checks that user has at least given role on task. Returns error code and
+// message if access is denied.
+func checkTaskRole(ctx context.Context, db querier, taskId string, userId string, role pb.TaskRole) (int32, string) {
+	return checkRole(ctx, db, taskId, userId, role, false)
+}
+
+// Same as checkTaskRole but for task in trash.
+func checkTrashedTaskRole(ctx context.Context, db querier, taskId string, userId string, role pb.TaskRole) (int32, string) {
+	return checkRole(ctx, db, taskId, userId, role, true)
+}
+
+func checkRole(ctx context.Context, db querier, taskId string, userId string, role pb.TaskRole, trashed bool) (int32, string) {
+	var actual *int32
+	err := db.QueryRow(ctx, taskRoleQuery, taskId, userId, trashed).Scan(&actual)
+	if err != nil {
+		return ErrCodeDatabase, fmt.Sprintf("Database error: %v", err)
+	}
+	if actual == nil || *actual < int32(role) {
+		return ErrCodeAccess, fmt.Sprintf("User doesn't have %v access to this task", role)
+	}
+	return 0, ""
+}
+
+// Checks that user has at least given role in the project. Returns error code
+// and message if access is denied.
+func checkProjectRole(ctx context.Context, db querier, projectId string, userId string, role pb.TaskRole) (int32, string) {
+	var actual *int32
+	err := db.QueryRow(ctx, `SELECT project_role($1, $2)`, projectId, userId).Scan(&actual)
+	if err != nil {
+		return ErrCodeDatabase, fmt.Sprintf("Database error: %v", err)
+	}
+	if actual == nil || *actual < int32(role) {
+		return ErrCodeProjectAccess, fmt.Sprintf("User doesn't have %v access to this project", role)
+	}
+	return 0, ""
+}
+
+// Tasks which user $1 created, is assigned to or reviews, or which are
+// shared with user directly or through membership in their project.
+const visibleTasksCondition = `(creator_id=$1 OR assigned_to=$1 OR reviewed_by=$1
+	OR id IN (SELECT task_id FROM task_access WHERE user_id=$1)
+	OR project_id IN (SELECT id FROM projects WHERE creator_id=$1 UNION SELECT project_id FROM project_members WHERE user_id=$1))`
+
+// Scans row selected with taskColumns followed by extra columns.
+func scanTask(row pgx.Row, extra ...any) (*pb.Task, error) {
+	task := pb.Task{}
+	var createdAt time.Time
+	var startDate, dueDate *time.Time
+	dest := append([]any{&task.Id, &createdAt, &task.Title, &task.Description, &task.Status, &task.AssignedTo, &task.ReviewedBy, &task.Version,
+		&startDate, &dueDate, &task.Priority, &task.EstimateMinutes, &task.Labels, &task.ProjectId, &task.Position,
+		&task.ParentId, &task.Subtasks, &task.OpenBlockers, &task.Checklist, &task.Attachments, &task.LoggedMinutes}, extra...)
+	if err := row.Scan(dest...); err != nil {
+		return nil, err
+	}
+	task.CreatedAt = timestamppb.New(createdAt)
+	task.StartDate = formatDate(startDate)
+	task.DueDate = formatDate(dueDate)
+	return &task, nil
+}
+
+func formatDate(date *time.Time) *string {
+	if date == nil {
+		return nil
+	}
+	x := date.Format(dateLayout)
+	return &x
+}
+
 func (s *server) CreateTask(ctx context.Context, req *pb.CreateTaskRequest) (*pb.TaskResponse, error) {
 	log.Printf("Handling CreateTask")
-	var id string
-	createdAt := time.Now()
-	status := pb.TaskStatus_Open
-	err := s.dbConn.QueryRow(context.Background(), `
+	return inTransaction(ctx, s.dbConn, MakeErrorResponse, func(db querier) (*pb.TaskResponse, error) {
+		return createTask(ctx, db, req)
+	})
+}
+
+func createTask(ctx context.Context, db querier, req *pb.CreateTaskRequest) (*pb.TaskResponse, error) {
+	if req.ProjectId != nil {
+		if code, msg := checkProjectRole(ctx, db, *req.ProjectId, req.UserId, pb.TaskRole_Editor); code != 0 {
+			return MakeErrorResponse(code, msg)
+		}
+	}
+	bumpDependents := func() error { return nil }
+	if req.ParentId != nil {
+		if code, msg := checkTaskRole(ctx, db, *req.ParentId, req.UserId, pb.TaskRole_Editor); code != 0 {
+			return MakeErrorResponse(code, msg)
+		}
+		var err error
+		if bumpDependents, err = lockDependentTasks(ctx, db, req.UserId, `id=$1`, *req.ParentId); err != nil {
+			return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+	}
+	now := time.Now()
+	var taskId string
+	err := db.QueryRow(ctx, `
 		INSERT INTO 
-			tasks (creator_id, created_at, title, description, status)
+			tasks (creator_id, created_at, title, description, status, assigned_to, reviewed_by, start_date, due_date, priority, estimate_minutes, project_id, parent_id)
 		VALUES
-			($1, $2, $3, $4, $5)
+			($1, $2, $3, $4, $5, $6, $7, $8::TEXT::DATE, $9::TEXT::DATE, $10, $11, $12, $13)
 		RETURNING id`,
-		req.UserId, createdAt, req.Title, req.Description, status).Scan(&id)
+		req.UserId, now, req.Title, req.Description, req.GetStatus(), req.AssignedTo, req.ReviewedBy,
+		req.StartDate, req.DueDate, req.Priority, req.EstimateMinutes, req.ProjectId, req.ParentId).Scan(&taskId)
+	if err == nil && len(req.LabelIds) > 0 {
+		var tag pgconn.CommandTag
+		tag, err = db.Exec(ctx, `
+			INSERT INTO
+				task_labels (task_id, label_id)
+			SELECT
+				$1, id
+			FROM
+				labels
+			WHERE
+				id=ANY($2) AND creator_id=$3`,
+			taskId, req.LabelIds, req.UserId)
+		if err == nil && tag.RowsAffected() != int64(len(req.LabelIds)) {
+			return MakeErrorResponse(ErrCodeLabelNotFound, "Label doesn't exist or belongs to another user")
+		}
+	}
+	if err == nil && len(req.Checklist) > 0 {
+		_, err = db.Exec(ctx, `
+			INSERT INTO
+				checklist_items (task_id, text, position, created_at)
+			SELECT
+				$1, x.text, x.n, $3
+			FROM
+				unnest($2::TEXT[]) WITH ORDINALITY x(text, n)`,
+			taskId, req.Checklist, now)
+	}
+	var task *pb.Task
+	if err == nil {
+		task, err = scanTask(db.QueryRow(ctx, `SELECT `+taskColumns+` FROM tasks WHERE id=$1`, taskId))
+	}
+	if err == nil {
+		err = recordChange(ctx, db, req.UserId, pb.TaskChangeAction_Created, nil, task)
+	}
+	if err == nil {
+		err = bumpDependents()
+	}
 	if err != nil {
-		return MakeErrorResponse(1, fmt.Sprintf("Database error: %v", err))
+		return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
 	}
-	return &pb.TaskResponse{
-		Response: &pb.TaskResponse_Task{
-			Task: &pb.Task{
-				Id:          id,
-				CreatedAt:   timestamppb.New(createdAt),
-				Title:       req.Title,
-				Description: req.Description,
-				Status:      status,
-			},
-		},
-	}, nil
-
+	return MakeTaskResponse(task)
 }
 
 func (s *server) GetTask(ctx context.Context, req *pb.GetTaskRequest) (*pb.TaskResponse, error) {
 	log.Printf("Handling GetTask")
-	var (
-		creatorId   string
-		createdAt   time.Time
-		title       string
-		description string
-		status      int32
-	)
-	err := s.dbConn.QueryRow(context.Background(), `
+	if code, msg := checkTaskRole(ctx, s.dbConn, req.TaskId, req.UserId, pb.TaskRole_Viewer); code != 0 {
+		return MakeErrorResponse(code, msg)
+	}
+	task, err := scanTask(s.dbConn.QueryRow(context.Background(), `
 		SELECT
-			creator_id, created_at, title, description, status
+			`+taskColumns+`
 		FROM
 			tasks
 		WHERE
 			id=$1`,
-		req.TaskId).Scan(&creatorId, &createdAt, &title, &description, &status)
+		req.TaskId))
 	if err != nil {
-		return MakeErrorResponse(1, fmt.Sprintf("Database error: %v", err))
+		return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
 	}
-	if creatorId != req.UserId {
-		return MakeErrorResponse(2, "User is not a creator of this task")
-	}
-	return &pb.TaskResponse{
-		Response: &pb.TaskResponse_Task{
-			Task: &pb.Task{
-				Id:          req.TaskId,
-				CreatedAt:   timestamppb.New(createdAt),
-				Title:       title,
-				Description: description,
-				Status:      pb.TaskStatus(status),
-			},
-		},
-	}, nil
+	return MakeTaskResponse(task)
 }
 
 func (s *server) UpdateTask(ctx context.Context, req *pb.UpdateTaskRequest) (*pb.TaskResponse, error) {
 	log.Printf("Handling UpdateTask")
-	var (
-		createdAt   time.Time
-		title       string
-		description string
-		status      int32
-	)
-	err := s.dbConn.QueryRow(context.Background(), `
+	return inTransaction(ctx, s.dbConn, MakeErrorResponse, func(db querier) (*pb.TaskResponse, error) {
+		return updateTask(ctx, db, req, pb.TaskChangeAction_Updated)
+	})
+}
+
+// Updates the task and records the change as given action in its history.
+func updateTask(ctx context.Context, db querier, req *pb.UpdateTaskRequest, action pb.TaskChangeAction) (*pb.TaskResponse, error) {
+	if code, msg := checkTaskRole(ctx, db, req.TaskId, req.UserId, pb.TaskRole_Editor); code != 0 {
+		return MakeErrorResponse(code, msg)
+	}
+	old, err := scanTask(db.QueryRow(ctx, `SELECT `+taskColumns+` FROM tasks WHERE id=$1 FOR UPDATE`, req.TaskId))
+	if err != nil {
+		return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	if req.CheckBlockers && req.GetNewStatus() == pb.TaskStatus_Completed && old.OpenBlockers > 0 {
+		return MakeErrorResponse(ErrCodeBlocked, "Task is blocked by unfinished tasks")
+	}
+	bumpDependents := func() error { return nil }
+	if req.NewStatus != nil && *req.NewStatus != old.Status {
+		if bumpDependents, err = lockDependentTasks(ctx, db, req.UserId, dependentTasksCondition, req.TaskId); err != nil {
+			return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+	}
+	var newStatus, expectedStatus, newPriority *int32
+	if req.NewStatus != nil {
+		x := int32(*req.NewStatus)
+		newStatus = &x
+	}
+	if req.ExpectedStatus != nil {
+		x := int32(*req.ExpectedStatus)
+		expectedStatus = &x
+	}
+	if req.NewPriority != nil {
+		x := int32(*req.NewPriority)
+		newPriority = &x
+	}
+	// Empty date and zero estimate remove the value
+	task, err := scanTask(db.QueryRow(ctx, `
 		UPDATE
 			tasks
 		SET
 			title=COALESCE($1, title),
-			description=COALESCE($2, description)
+			description=COALESCE($2, description),
+			status=COALESCE($3, status),
+			assigned_to=NULLIF(COALESCE($6, assigned_to, ''), ''),
+			reviewed_by=NULLIF(COALESCE($7, reviewed_by, ''), ''),
+			start_date=CASE WHEN $9::TEXT IS NULL THEN start_date ELSE NULLIF($9, '')::DATE END,
+			due_date=CASE WHEN $10::TEXT IS NULL THEN due_date ELSE NULLIF($10, '')::DATE END,
+			priority=COALESCE($11, priority),
+			estimate_minutes=CASE WHEN $12::INT IS NULL THEN estimate_minutes ELSE NULLIF($12, 0) END,
+			version=version+1
 		WHERE
-			id=$3 AND creator_id=$4
-		RETURNING
-			created_at, title, description, status`,
-		req.NewTitle, req.NewDescription, req.TaskId, req.UserId).Scan(&createdAt, &title, &description, &status)
+			id=$4 AND deleted_at IS NULL AND ($5::INT IS NULL OR status=$5) AND ($8::BIGINT IS NULL OR version=$8)
+		RETURNING `+taskColumns,
+		req.NewTitle, req.NewDescription, newStatus, req.TaskId, expectedStatus, req.NewAssignedTo, req.NewReviewedBy, req.ExpectedVersion,
+		req.NewStartDate, req.NewDueDate, newPriority, req.NewEstimateMinutes))
+	// Every change increases version, so mismatch of status means mismatch of
+	// version too if both are expected
+	if errors.Is(err, pgx.ErrNoRows) && req.ExpectedVersion != nil {
+		return MakeErrorResponse(ErrCodeVersion, "Task doesn't exist or its version has changed")
+	}
+	if errors.Is(err, pgx.ErrNoRows) && expectedStatus != nil {
+		return MakeErrorResponse(ErrCodeConflict, "Task doesn't exist or its status has changed")
+	}
+	// Only one of the dates may be changed, so the other one is checked here
+	var pgErr *pgconn.PgError
+	if errors.As(err, &pgErr) && pgErr.Code == pgCheckViolation {
+		return MakeErrorResponse(ErrCodeDates, "Task can't start after it is due")
+	}
+	if err == nil {
+		err = recordChange(ctx, db, req.UserId, action, old, task)
+	}
+	if err == nil {
+		err = bumpDependents()
+	}
 	if err != nil {
-		return MakeErrorResponse(1, fmt.Sprintf("Database error: %v", err))
+		return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
 	}
-	return &pb.TaskResponse{
-		Response: &pb.TaskResponse_Task{
-			Task: &pb.Task{
-				Id:          req.TaskId,
-				CreatedAt:   timestamppb.New(createdAt),
-				Title:       title,
-				Description: description,
-				Status:      pb.TaskStatus(status),
-			},
-		},
-	}, nil
+	return MakeTaskResponse(task)
 }
 
 func (s *server) DeleteTask(ctx context.Context, req *pb.DeleteTaskRequest) (*pb.TaskResponse, error) {
 	log.Printf("Handling DeleteTask")
-	var (
-		createdAt   time.Time
-		title       string
-		description string
-		status      int32
-	)
-	err := s.dbConn.QueryRow(context.Background(), `
-		DELETE FROM
+	return inTransaction(ctx, s.dbConn, MakeErrorResponse, func(db querier) (*pb.TaskResponse, error) {
+		return deleteTask(ctx, db, req)
+	})
+}
+
+func deleteTask(ctx context.Context, db querier, req *pb.DeleteTaskRequest) (*pb.TaskResponse, error) {
+	if code, msg := checkTaskRole(ctx, db, req.TaskId, req.UserId, pb.TaskRole_Owner); code != 0 {
+		return MakeErrorResponse(code, msg)
+	}
+	bumpDependents, err := lockDependentTasks(ctx, db, req.UserId, dependentTasksCondition, req.TaskId)
+	if err != nil {
+		return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	task, err := scanTask(db.QueryRow(ctx, `
+		UPDATE
 			tasks
+		SET
+			deleted_at=$3,
+			deleted_by=$4,
+			version=version+1
 		WHERE
-			id=$1 AND creator_id=$2
-		RETURNING
-			created_at, title, description, status`,
-		req.TaskId, req.UserId).Scan(&createdAt, &title, &description, &status)
+			id=$1 AND deleted_at IS NULL AND ($2::BIGINT IS NULL OR version=$2)
+		RETURNING `+taskColumns,
+		req.TaskId, req.ExpectedVersion, time.Now(), req.UserId))
+	if errors.Is(err, pgx.ErrNoRows) && req.ExpectedVersion != nil {
+		return MakeErrorResponse(ErrCodeVersion, "Task doesn't exist or its version has changed")
+	}
+	if err == nil {
+		err = recordChange(ctx, db, req.UserId, pb.TaskChangeAction_Deleted, task, nil)
+	}
+	if err == nil {
+		err = bumpDependents()
+	}
 	if err != nil {
-		return MakeErrorResponse(1, fmt.Sprintf("Database error: %v", err))
+		return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
 	}
-	return &pb.TaskResponse{
-		Response: &pb.TaskResponse_Task{
-			Task: &pb.Task{
-				Id:          req.TaskId,
-				CreatedAt:   timestamppb.New(createdAt),
-				Title:       title,
-				Description: description,
-				Status:      pb.TaskStatus(status),
+	return MakeTaskResponse(task)
+}
+
+// Moves task by the status change whose source is the current status of the
+// task. Changes are resolved from the workflow by user service.
+func transitionTask(ctx context.Context, db querier, req *pb.TransitionTaskRequest) (*pb.TaskResponse, error) {
+	if code, msg := checkTaskRole(ctx, db, req.TaskId, req.UserId, pb.TaskRole_Editor); code != 0 {
+		return MakeErrorResponse(code, msg)
+	}
+	var status int32
+	err := db.QueryRow(ctx, `SELECT status FROM tasks WHERE id=$1 FOR UPDATE`, req.TaskId).Scan(&status)
+	if err != nil {
+		return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	for _, change := range req.Changes {
+		if int32(change.From) == status {
+			return updateTask(ctx, db, &pb.UpdateTaskRequest{
+				UserId:          req.UserId,
+				TaskId:          req.TaskId,
+				NewStatus:       &change.To,
+				ExpectedStatus:  &change.From,
+				ExpectedVersion: req.ExpectedVersion,
+				CheckBlockers:   req.CheckBlockers,
+			}, pb.TaskChangeAction_Updated)
+		}
+	}
+	return MakeErrorResponse(ErrCodeTransition, "Transition is not allowed from current task status")
+}
+
+func MakeBatchTasksErrorResponse(id int32, msg string) (*pb.BatchTasksResponse, error) {
+	return &pb.BatchTasksResponse{
+		Response: &pb.BatchTasksResponse_Error{
+			Error: &pb.Error{
+				Code:    id,
+				Message: msg,
 			},
 		},
 	}, nil
 }
 
-func (s *server) GetTaskPage(ctx context.Context, req *pb.GetTaskPageRequest) (*pb.TaskPageResponse, error) {
-	log.Printf("Handling GetTaskPage")
-	result, err := s.dbConn.Query(context.Background(), `
+func MakeBatchTasksResponse(results *pb.BatchResults) (*pb.BatchTasksResponse, error) {
+	return &pb.BatchTasksResponse{
+		Response: &pb.BatchTasksResponse_Results{
+			Results: results,
+		},
+	}, nil
+}
+
+// Executes operation of the batch on behalf of the batch user.
+func executeOperation(ctx context.Context, db querier, userId string, op *pb.TaskOperation) (*pb.TaskResponse, error) {
+	switch x := op.Operation.(type) {
+	case *pb.TaskOperation_Create:
+		x.Create.UserId = userId
+		return createTask(ctx, db, x.Create)
+	case *pb.TaskOperation_Update:
+		x.Update.UserId = userId
+		return updateTask(ctx, db, x.Update, pb.TaskChangeAction_Updated)
+	case *pb.TaskOperation_Delete:
+		x.Delete.UserId = userId
+		return deleteTask(ctx, db, x.Delete)
+	case *pb.TaskOperation_Transition:
+		x.Transition.UserId = userId
+		return transitionTask(ctx, db, x.Transition)
+	}
+	return MakeErrorResponse(ErrCodeDatabase, "Unknown operation")
+}
+
+// Executes operations one by one in a single transaction. Failed operation is
+// rolled back to its savepoint, in atomic mode the whole batch is rolled back
+// and remaining operations are skipped.
+func (s *server) BatchTasks(ctx context.Context, req *pb.BatchTasksRequest) (*pb.BatchTasksResponse, error) {
+	log.Printf("Handling BatchTasks")
+	tx, err := s.dbConn.Begin(ctx)
+	if err != nil {
+		return MakeBatchTasksErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	defer tx.Rollback(ctx)
+	results := &pb.BatchResults{}
+	for _, op := range req.Operations {
+		savepoint, err := tx.Begin(ctx)
+		if err != nil {
+			return MakeBatchTasksErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		result, err := executeOperation(ctx, savepoint, req.UserId, op)
+		if err != nil {
+			return nil, err
+		}
+		results.Results = append(results.Results, result)
+		if result.GetError() == nil {
+			err = savepoint.Commit(ctx)
+		} else if req.Atomic {
+			return MakeBatchTasksResponse(results)
+		} else {
+			err = savepoint.Rollback(ctx)
+		}
+		if err != nil {
+			return MakeBatchTasksErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+	}
+	if err := tx.Commit(ctx); err != nil {
+		return MakeBatchTasksErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	results.Committed = true
+	return MakeBatchTasksResponse(results)
+}
+
+// Fields of task which changes are recorded in its history.
+var historyFields = []string{"title", "description", "status", "assigned_to", "reviewed_by", "start_date", "due_date", "priority", "estimate_minutes", "labels", "project_id", "parent_id", "checklist", "attachments", "logged_minutes", "subtasks", "open_blockers"}
+
+// Change of task field stored in history as JSON.
+type fieldChange struct {
+	Field    string  `json:"field"`
+	OldValue *string `json:"old_value"`
+	NewValue *string `json:"new_value"`
+}
+
+// Values of historyFields, nil task has none of them.
+func historyValues(task *pb.Task) []*string {
+	if task == nil {
+		return make([]*string, len(historyFields))
+	}
+	status, priority := task.Status.String(), task.Priority.String()
+	var estimate *string
+	if task.EstimateMinutes != nil {
+		x := strconv.Itoa(int(*task.EstimateMinutes))
+		estimate = &x
+	}
+	var labels *string
+	if len(task.Labels) > 0 {
+		names := make([]string, len(task.Labels))
+		for i, x := range task.Labels {
+			names[i] = x.Name
+		}
+		x := strings.Join(names, ", ")
+		labels = &x
+	}
+	var checklist *string
+	if task.Checklist.GetTotal() > 0 {
+		x := fmt.Sprintf("%d/%d done", task.Checklist.Done, task.Checklist.Total)
+		checklist = &x
+	}
+	var attachments *string
+	if len(task.Attachments) > 0 {
+		names := make([]string, len(task.Attachments))
+		for i, x := range task.Attachments {
+			names[i] = x.Filename
+		}
+		x := strings.Join(names, ", ")
+		attachments = &x
+	}
+	var logged *string
+	if task.LoggedMinutes > 0 {
+		x := strconv.Itoa(int(task.LoggedMinutes))
+		logged = &x
+	}
+	var subtasks *string
+	if task.Subtasks.GetTotal() > 0 {
+		x := fmt.Sprintf("%d/%d completed", task.Subtasks.Completed, task.Subtasks.Total)
+		subtasks = &x
+	}
+	var blockers *string
+	if task.OpenBlockers > 0 {
+		x := strconv.Itoa(int(task.OpenBlockers))
+		blockers = &x
+	}
+	return []*string{&task.Title, &task.Description, &status, task.AssignedTo, task.ReviewedBy, task.StartDate, task.DueDate, &priority, estimate, labels, task.ProjectId, task.ParentId, checklist, attachments, logged, subtasks, blockers}
+}
+
+func diffTasks(before *pb.Task, after *pb.Task) []fieldChange {
+	changes := []fieldChange{}
+	oldValues, newValues := historyValues(before), historyValues(after)
+	for i, field := range historyFields {
+		x, y := oldValues[i], newValues[i]
+		if x == nil && y == nil || x != nil && y != nil && *x == *y {
+			continue
+		}
+		changes = append(changes, fieldChange{Field: field, OldValue: x, NewValue: y})
+	}
+	return changes
+}
+
+// Increases version of the task locked as old after a change of its parts
+// which are stored outside of the task, e.g. checklist, and records the change
+// in its history. Such parts are included in the task, so its version has to
+// change with them.
+func bumpVersion(ctx context.Context, db querier, userId string, old *pb.Task) (*pb.Task, error) {
+	task, err := scanTask(db.QueryRow(ctx, `UPDATE tasks SET version=version+1 WHERE id=$1 RETURNING `+taskColumns, old.Id))
+	if err == nil {
+		err = recordChange(ctx, db, userId, pb.TaskChangeAction_Updated, old, task)
+	}
+	return task, err
+}
+
+// Tasks which derived counts depend on the task $1: its parent counts its
+// subtasks and tasks blocked by it count their open blockers.
+const dependentTasksCondition = `id IN (SELECT parent_id FROM tasks WHERE id=$1 UNION SELECT task_id FROM task_dependencies WHERE blocker_id=$1)`
+
+// Locks tasks matching the condition before a change of other tasks which may
+// alter their derived counts. Returned function has to be called after the
+// change, it increases versions of the tasks which counts have changed and
+// records the change in their history.
+func lockDependentTasks(ctx context.Context, db querier, userId string, condition string, args ...any) (func() error, error) {
+	result, err := db.Query(ctx, `SELECT `+taskColumns+` FROM tasks WHERE deleted_at IS NULL AND (`+condition+`) ORDER BY id FOR UPDATE`, args...)
+	if err != nil {
+		return nil, err
+	}
+	var tasks []*pb.Task
+	for result.Next() {
+		task, err := scanTask(result)
+		if err != nil {
+			result.Close()
+			return nil, err
+		}
+		tasks = append(tasks, task)
+	}
+	result.Close()
+	if err := result.Err(); err != nil {
+		return nil, err
+	}
+	return func() error {
+		for _, old := range tasks {
+			task, err := scanTask(db.QueryRow(ctx, `SELECT `+taskColumns+` FROM tasks WHERE id=$1`, old.Id))
+			if err == nil && len(diffTasks(old, task)) > 0 {
+				_, err = bumpVersion(ctx, db, userId, old)
+			}
+			if err != nil {
+				return err
+			}
+		}
+		return nil
+	}, nil
+}
+
+// Records change of the task between two states in its history, one of the
+// states is nil for created and deleted tasks.
+func recordChange(ctx context.Context, db querier, actorId string, action pb.TaskChangeAction, before *pb.Task, after *pb.Task) error {
+	task := after
+	if task == nil {
+		task = before
+	}
+	_, err := db.Exec(ctx, `
+		INSERT INTO
+			task_history (task_id, version, actor_id, changed_at, action, changes)
+		VALUES
+			($1, $2, $3, $4, $5, $6)`,
+		task.Id, task.Version, actorId, time.Now(), action, diffTasks(before, after))
+	return err
+}
+
+func MakeTaskHistoryErrorResponse(id int32, msg string) (*pb.TaskHistoryResponse, error) {
+	return &pb.TaskHistoryResponse{
+		Response: &pb.TaskHistoryResponse_Error{
+			Error: &pb.Error{
+				Code:    id,
+				Message: msg,
+			},
+		},
+	}, nil
+}
+
+// Lists changes of the task from the latest ones, starting before the change
+// with BeforeId if it is set.
+func (s *server) GetTaskHistory(ctx context.Context, req *pb.GetTaskHistoryRequest) (*pb.TaskHistoryResponse, error) {
+	log.Printf("Handling GetTaskHistory")
+	if code, msg := checkTaskRole(ctx, s.dbConn, req.TaskId, req.UserId, pb.TaskRole_Viewer); code != 0 {
+		return MakeTaskHistoryErrorResponse(code, msg)
+	}
+	// One extra change is selected to find out if there are more of them
+	result, err := s.dbConn.Query(ctx, `
 		SELECT
-			id, created_at, title, description, status
+			id, version, actor_id, changed_at, action, changes
 		FROM
-			tasks
+			task_history
 		WHERE
-			creator_id=$1
+			task_id=$1 AND ($2::BIGINT=0 OR id<$2)
 		ORDER BY
-			created_at
-		LIMIT $2 OFFSET $3`,
-		req.UserId, req.PageSize, req.StartId)
+			id DESC
+		LIMIT $3`,
+		req.TaskId, req.BeforeId, req.PageSize+1)
 	if err != nil {
-		return &pb.TaskPageResponse{
-			Response: &pb.TaskPageResponse_Error{
-				Error: &pb.Error{
-					Code:    1,
-					Message: fmt.Sprintf("Database error: %v", err),
-				},
-			},
-		}, nil
+		return MakeTaskHistoryErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
 	}
-	taskPage := pb.TaskPage{}
+	defer result.Close()
+	history := pb.TaskHistory{}
 	for result.Next() {
-		task := pb.Task{}
-		var createdAt time.Time
-		err = result.Scan(&task.Id, &createdAt, &task.Title, &task.Description, &task.Status)
-		if err != nil {
-			return &pb.TaskPageResponse{
-				Response: &pb.TaskPageResponse_Error{
-					Error: &pb.Error{
-						Code:    2,
-						Message: fmt.Sprintf("Database error: %v", err),
-					},
-				},
-			}, nil
+		change := pb.TaskChange{}
+		var changedAt time.Time
+		var fields []fieldChange
+		if err := result.Scan(&change.Id, &change.Version, &change.ActorId, &changedAt, &change.Action, &fields); err != nil {
+			return MakeTaskHistoryErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		change.ChangedAt = timestamppb.New(changedAt)
+		for _, x := range fields {
+			change.Fields = append(change.Fields, &pb.FieldChange{Field: x.Field, OldValue: x.OldValue, NewValue: x.NewValue})
 		}
-		task.CreatedAt = timestamppb.New(createdAt)
-		taskPage.Tasks = append(taskPage.Tasks, &task)
+		history.Changes = append(history.Changes, &change)
 	}
-	return &pb.TaskPageResponse{
-		Response: &pb.TaskPageResponse_TaskPage{
-			TaskPage: &taskPage,
+	if err := result.Err(); err != nil {
+		return MakeTaskHistoryErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	if len(history.Changes) > int(req.PageSize) {
+		history.Changes = history.Changes[:req.PageSize]
+		history.HasMore = true
+	}
+	return &pb.TaskHistoryResponse{
+		Response: &pb.TaskHistoryResponse_History{
+			History: &history,
+		},
+	}, nil
+}
+
+func (s *server) RestoreTaskVersion(ctx context.Context, req *pb.RestoreTaskVersionRequest) (*pb.TaskResponse, error) {
+	log.Printf("Handling RestoreTaskVersion")
+	return inTransaction(ctx, s.dbConn, MakeErrorResponse, func(db querier) (*pb.TaskResponse, error) {
+		return restoreTaskVersion(ctx, db, req)
+	})
+}
+
+// Restores fields of the task to their values at given version by replaying
+// its history. Status isn't restored since it changes only by transitions,
+// labels and project aren't restored either.
+func restoreTaskVersion(ctx context.Context, db querier, req *pb.RestoreTaskVersionRequest) (*pb.TaskResponse, error) {
+	if code, msg := checkTaskRole(ctx, db, req.TaskId, req.UserId, pb.TaskRole_Editor); code != 0 {
+		return MakeErrorResponse(code, msg)
+	}
+	result, err := db.Query(ctx, `
+		SELECT
+			version, changes
+		FROM
+			task_history
+		WHERE
+			task_id=$1 AND version<=$2
+		ORDER BY
+			id`,
+		req.TaskId, req.Version)
+	if err != nil {
+		return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	values := map[string]*string{}
+	found := false
+	for result.Next() {
+		var version int64
+		var fields []fieldChange
+		if err := result.Scan(&version, &fields); err != nil {
+			result.Close()
+			return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		for _, x := range fields {
+			values[x.Field] = x.NewValue
+		}
+		found = found || version == req.Version
+	}
+	result.Close()
+	if err := result.Err(); err != nil {
+		return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	if !found {
+		return MakeErrorResponse(ErrCodeDatabase, "Task has no such version in its history")
+	}
+	// Empty username or date removes the value
+	orEmpty := func(x *string) *string {
+		if x == nil {
+			return new(string)
+		}
+		return x
+	}
+	var priority *pb.TaskPriority
+	if x, ok := pb.TaskPriority_value[*orEmpty(values["priority"])]; ok {
+		priority = pb.TaskPriority(x).Enum()
+	}
+	// Zero estimate removes it
+	estimate := new(int32)
+	if x, err := strconv.Atoi(*orEmpty(values["estimate_minutes"])); err == nil {
+		*estimate = int32(x)
+	}
+	return updateTask(ctx, db, &pb.UpdateTaskRequest{
+		UserId:             req.UserId,
+		TaskId:             req.TaskId,
+		NewTitle:           values["title"],
+		NewDescription:     values["description"],
+		NewAssignedTo:      orEmpty(values["assigned_to"]),
+		NewReviewedBy:      orEmpty(values["reviewed_by"]),
+		NewStartDate:       orEmpty(values["start_date"]),
+		NewDueDate:         orEmpty(values["due_date"]),
+		NewPriority:        priority,
+		NewEstimateMinutes: estimate,
+		ExpectedVersion:    req.ExpectedVersion,
+	}, pb.TaskChangeAction_Restored)
+}
+
+func MakeTrashErrorResponse(id int32, msg string) (*pb.TrashResponse, error) {
+	return &pb.TrashResponse{
+		Response: &pb.TrashResponse_Error{
+			Error: &pb.Error{
+				Code:    id,
+				Message: msg,
+			},
 		},
 	}, nil
 }
 
+// Lists tasks in trash which user owns, recently deleted first.
+func (s *server) ListTrash(ctx context.Context, req *pb.ListTrashRequest) (*pb.TrashResponse, error) {
+	log.Printf("Handling ListTrash")
+	// One extra task is selected to find out if there are more of them
+	result, err := s.dbConn.Query(ctx, `
+		SELECT
+			`+taskColumns+`, deleted_at, deleted_by
+		FROM
+			tasks t
+		WHERE
+			deleted_at IS NOT NULL AND `+taskRoleExpression+`>=$1
+		ORDER BY
+			deleted_at DESC, id
+		OFFSET $3 LIMIT $4`,
+		pb.TaskRole_Owner, req.UserId, req.Offset, req.PageSize+1)
+	if err != nil {
+		return MakeTrashErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	defer result.Close()
+	trash := pb.TrashPage{}
+	for result.Next() {
+		item := pb.TrashItem{}
+		var deletedAt time.Time
+		item.Task, err = scanTask(result, &deletedAt, &item.DeletedBy)
+		if err != nil {
+			return MakeTrashErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		item.DeletedAt = timestamppb.New(deletedAt)
+		trash.Items = append(trash.Items, &item)
+	}
+	if err := result.Err(); err != nil {
+		return MakeTrashErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	if len(trash.Items) > int(req.PageSize) {
+		trash.Items = trash.Items[:req.PageSize]
+		trash.HasMore = true
+	}
+	return &pb.TrashResponse{
+		Response: &pb.TrashResponse_Trash{
+			Trash: &trash,
+		},
+	}, nil
+}
+
+func (s *server) RestoreTask(ctx context.Context, req *pb.RestoreTaskRequest) (*pb.TaskResponse, error) {
+	log.Printf("Handling RestoreTask")
+	return inTransaction(ctx, s.dbConn, MakeErrorResponse, func(db querier) (*pb.TaskResponse, error) {
+		if code, msg := checkTrashedTaskRole(ctx, db, req.TaskId, req.UserId, pb.TaskRole_Owner); code != 0 {
+			return MakeErrorResponse(code, msg)
+		}
+		bumpDependents, err := lockDependentTasks(ctx, db, req.UserId, dependentTasksCondition, req.TaskId)
+		if err != nil {
+			return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		task, err := scanTask(db.QueryRow(ctx, `
+			UPDATE
+				tasks
+			SET
+				deleted_at=NULL,
+				deleted_by=NULL,
+				version=version+1
+			WHERE
+				id=$1 AND deleted_at IS NOT NULL
+			RETURNING `+taskColumns,
+			req.TaskId))
+		if err == nil {
+			err = recordChange(ctx, db, req.UserId, pb.TaskChangeAction_RestoredFromTrash, nil, task)
+		}
+		if err == nil {
+			err = bumpDependents()
+		}
+		if err != nil {
+			return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		return MakeTaskResponse(task)
+	})
+}
+
+// Permanently deletes the task from trash. History of the task is kept.
+func (s *server) PurgeTask(ctx context.Context, req *pb.PurgeTaskRequest) (*pb.TaskResponse, error) {
+	log.Printf("Handling PurgeTask")
+	if code, msg := checkTrashedTaskRole(ctx, s.dbConn, req.TaskId, req.UserId, pb.TaskRole_Owner); code != 0 {
+		return MakeErrorResponse(code, msg)
+	}
+	task, err := scanTask(s.dbConn.QueryRow(ctx, `
+		DELETE FROM
+			tasks
+		WHERE
+			id=$1 AND deleted_at IS NOT NULL
+		RETURNING `+taskColumns,
+		req.TaskId))
+	if err != nil {
+		return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return MakeTaskResponse(task)
+}
+
+// Permanently deletes tasks which were moved to trash before given time.
+func (s *server) PurgeExpiredTasks(ctx context.Context, req *pb.PurgeExpiredTasksRequest) (*pb.PurgeExpiredTasksResponse, error) {
+	log.Printf("Handling PurgeExpiredTasks")
+	tag, err := s.dbConn.Exec(ctx, `DELETE FROM tasks WHERE deleted_at<$1`, req.DeletedBefore.AsTime())
+	if err != nil {
+		return &pb.PurgeExpiredTasksResponse{
+			Response: &pb.PurgeExpiredTasksResponse_Error{
+				Error: &pb.Error{
+					Code:    ErrCodeDatabase,
+					Message: fmt.Sprintf("Database error: %v", err),
+				},
+			},
+		}, nil
+	}
+	return &pb.PurgeExpiredTasksResponse{
+		Response: &pb.PurgeExpiredTasksResponse_PurgedCount{
+			PurgedCount: tag.RowsAffected(),
+		},
+	}, nil
+}
+
+// Makes SQL conditions for tasks matching the filter, arg adds query argument
+// and returns its placeholder. Query has to take the user as $1, label names
+// are looked up among labels of the user.
+func filterConditions(filter *pb.TaskFilter, arg func(any) string) []string {
+	var conditions []string
+	if filter == nil {
+		return conditions
+	}
+	if len(filter.Statuses) > 0 {
+		statuses := make([]int32, len(filter.Statuses))
+		for i, x := range filter.Statuses {
+			statuses[i] = int32(x)
+		}
+		conditions = append(conditions, "status=ANY("+arg(statuses)+")")
+	}
+	if filter.CreatedAfter != nil {
+		conditions = append(conditions, "created_at>="+arg(filter.CreatedAfter.AsTime()))
+	}
+	if filter.CreatedBefore != nil {
+		conditions = append(conditions, "created_at<"+arg(filter.CreatedBefore.AsTime()))
+	}
+	if filter.Text != nil {
+		pattern := arg("%" + likeEscaper.Replace(*filter.Text) + "%")
+		conditions = append(conditions, "(title ILIKE "+pattern+" OR description ILIKE "+pattern+")")
+	}
+	if filter.AssignedTo != nil {
+		conditions = append(conditions, "assigned_to="+arg(*filter.AssignedTo))
+	}
+	if len(filter.Priorities) > 0 {
+		priorities := make([]int32, len(filter.Priorities))
+		for i, x := range filter.Priorities {
+			priorities[i] = int32(x)
+		}
+		conditions = append(conditions, "priority=ANY("+arg(priorities)+")")
+	}
+	if filter.DueFrom != nil {
+		conditions = append(conditions, "due_date>="+arg(*filter.DueFrom)+"::TEXT::DATE")
+	}
+	if filter.DueTo != nil {
+		conditions = append(conditions, "due_date<="+arg(*filter.DueTo)+"::TEXT::DATE")
+	}
+	// Tasks which have all of the labels
+	if len(filter.Labels) > 0 {
+		conditions = append(conditions, fmt.Sprintf(
+			"id IN (SELECT tl.task_id FROM task_labels tl JOIN labels l ON l.id=tl.label_id WHERE l.creator_id=$1 AND l.name=ANY(%s) GROUP BY tl.task_id HAVING COUNT(*)=%d)",
+			arg(filter.Labels), len(filter.Labels)))
+	}
+	if filter.ProjectId != nil {
+		conditions = append(conditions, "project_id="+arg(*filter.ProjectId))
+	}
+	if filter.ParentId != nil {
+		conditions = append(conditions, "parent_id="+arg(*filter.ParentId))
+	}
+	if len(filter.Ids) > 0 {
+		conditions = append(conditions, "id=ANY("+arg(filter.Ids)+")")
+	}
+	if filter.Overdue {
+		conditions = append(conditions, fmt.Sprintf("due_date<CURRENT_DATE AND status NOT IN (%d, %d)", pb.TaskStatus_Completed, pb.TaskStatus_Cancelled))
+	}
+	return conditions
+}
+
+// Returns page of filtered tasks which follows the After cursor or precedes
+// the Before cursor. Tasks are sorted by the requested field, ties are broken
+// by creation time and id so the order is stable.
+func (s *server) GetTaskPage(ctx context.Context, req *pb.GetTaskPageRequest) (*pb.TaskPageResponse, error) {
+	log.Printf("Handling GetTaskPage")
+	args := []any{req.UserId}
+	arg := func(x any) string {
+		args = append(args, x)
+		return fmt.Sprintf("$%d", len(args))
+	}
+	conditions := []string{"deleted_at IS NULL"}
+	switch req.Relation {
+	case pb.TaskRelation_CreatedOrShared:
+		conditions = append(conditions, "(creator_id=$1 OR id IN (SELECT task_id FROM task_access WHERE user_id=$1))")
+	case pb.TaskRelation_Assigned:
+		conditions = append(conditions, "assigned_to=$1")
+	case pb.TaskRelation_AwaitingReview:
+		conditions = append(conditions, fmt.Sprintf("reviewed_by=$1 AND status=%d", pb.TaskStatus_PendingReview))
+	case pb.TaskRelation_Visible:
+		conditions = append(conditions, visibleTasksCondition)
+	default:
+		return MakeTaskPageErrorResponse(ErrCodeDatabase, fmt.Sprintf("Unknown task relation: %v", req.Relation))
+	}
+	if req.PageSize <= 0 {
+		return MakeTaskPageErrorResponse(ErrCodeDatabase, fmt.Sprintf("Invalid page size: %d", req.PageSize))
+	}
+
+	conditions = append(conditions, filterConditions(req.Filter, arg)...)
+
+	taskPage := pb.TaskPage{}
+	if req.IncludeTotal {
+		err := s.dbConn.QueryRow(ctx, `SELECT COUNT(*) FROM tasks WHERE `+strings.Join(conditions, " AND "), args...).Scan(&taskPage.Total)
+		if err != nil {
+			return MakeTaskPageErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+	}
+
+	var sortColumns []string
+	var cursorValues func(*pb.TaskCursor) []any
+	switch req.SortField {
+	case pb.TaskSortField_CreatedAt:
+		sortColumns = []string{"created_at", "id"}
+		cursorValues = func(c *pb.TaskCursor) []any { return []any{c.CreatedAt.AsTime(), c.Id} }
+	case pb.TaskSortField_Title:
+		sortColumns = []string{"title", "created_at", "id"}
+		cursorValues = func(c *pb.TaskCursor) []any { return []any{c.Title, c.CreatedAt.AsTime(), c.Id} }
+	case pb.TaskSortField_Status:
+		sortColumns = []string{"status", "created_at", "id"}
+		cursorValues = func(c *pb.TaskCursor) []any { return []any{int32(c.Status), c.CreatedAt.AsTime(), c.Id} }
+	case pb.TaskSortField_DueDate:
+		// Tasks without due date go last in ascending order
+		sortColumns = []string{"COALESCE(due_date, 'infinity')", "created_at", "id"}
+		cursorValues = func(c *pb.TaskCursor) []any {
+			dueDate := pgtype.Date{InfinityModifier: pgtype.Infinity, Valid: true}
+			if c.DueDate != nil {
+				if x, err := time.Parse(dateLayout, *c.DueDate); err == nil {
+					dueDate = pgtype.Date{Time: x, Valid: true}
+				}
+			}
+			return []any{dueDate, c.CreatedAt.AsTime(), c.Id}
+		}
+	case pb.TaskSortField_Priority:
+		sortColumns = []string{"priority", "created_at", "id"}
+		cursorValues = func(c *pb.TaskCursor) []any { return []any{int32(c.Priority), c.CreatedAt.AsTime(), c.Id} }
+	case pb.TaskSortField_Position:
+		sortColumns = []string{"position", "id"}
+		cursorValues = func(c *pb.TaskCursor) []any { return []any{c.Position, c.Id} }
+	default:
+		return MakeTaskPageErrorResponse(ErrCodeDatabase, fmt.Sprintf("Unknown sort field: %v", req.SortField))
+	}
+	// Previous page is selected in reversed order and then reversed back
+	descending := req.SortDirection == pb.SortDirection_Descending
+	cursor := req.After
+	if req.Before != nil {
+		cursor = req.Before
+		descending = !descending
+	}
+	if cursor != nil {
+		var placeholders []string
+		for _, x := range cursorValues(cursor) {
+			placeholders = append(placeholders, arg(x))
+		}
+		op := ">"
+		if descending {
+			op = "<"
+		}
+		conditions = append(conditions, fmt.Sprintf("(%s) %s (%s)", strings.Join(sortColumns, ", "), op, strings.Join(placeholders, ", ")))
+	}
+	order := make([]string, len(sortColumns))
+	for i, x := range sortColumns {
+		order[i] = x
+		if descending {
+			order[i] += " DESC"
+		}
+	}
+	// One extra task is selected to find out if there are more of them
+	limit := arg(req.PageSize + 1)
+	result, err := s.dbConn.Query(ctx, fmt.Sprintf(`
+		SELECT
+			%s
+		FROM
+			tasks
+		WHERE
+			%s
+		ORDER BY
+			%s
+		LIMIT %s`, taskColumns, strings.Join(conditions, " AND "), strings.Join(order, ", "), limit),
+		args...)
+	if err != nil {
+		return MakeTaskPageErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	defer result.Close()
+	for result.Next() {
+		task, err := scanTask(result)
+		if err != nil {
+			return MakeTaskPageErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		taskPage.Tasks = append(taskPage.Tasks, task)
+	}
+	if err := result.Err(); err != nil {
+		return MakeTaskPageErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	if len(taskPage.Tasks) > int(req.PageSize) {
+		taskPage.Tasks = taskPage.Tasks[:req.PageSize]
+		taskPage.HasMore = true
+	}
+	if req.Before != nil {
+		slices.Reverse(taskPage.Tasks)
+	}
+	return &pb.TaskPageResponse{
+		Response: &pb.TaskPageResponse_TaskPage{
+			TaskPage: &taskPage,
+		},
+	}, nil
+}
+
+// Lists assigned tasks which are not finished yet and are due between today
+// and given date, for reminders about them.
+func (s *server) GetDueTasks(ctx context.Context, req *pb.GetDueTasksRequest) (*pb.TaskPageResponse, error) {
+	log.Printf("Handling GetDueTasks")
+	result, err := s.dbConn.Query(ctx, `
+		SELECT
+			`+taskColumns+`
+		FROM
+			tasks
+		WHERE
+			deleted_at IS NULL AND assigned_to IS NOT NULL AND status NOT IN ($2, $3)
+			AND due_date BETWEEN CURRENT_DATE AND $1::TEXT::DATE
+		ORDER BY
+			due_date, id`,
+		req.DueTo, pb.TaskStatus_Completed, pb.TaskStatus_Cancelled)
+	if err != nil {
+		return MakeTaskPageErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	defer result.Close()
+	taskPage := pb.TaskPage{}
+	for result.Next() {
+		task, err := scanTask(result)
+		if err != nil {
+			return MakeTaskPageErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		taskPage.Tasks = append(taskPage.Tasks, task)
+	}
+	if err := result.Err(); err != nil {
+		return MakeTaskPageErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return &pb.TaskPageResponse{
+		Response: &pb.TaskPageResponse_TaskPage{
+			TaskPage: &taskPage,
+		},
+	}, nil
+}
+
+const labelColumns = `id, name, color, description, creator_id`
+
+func scanLabel(row pgx.Row) (*pb.Label, error) {
+	label := pb.Label{}
+	if err := row.Scan(&label.Id, &label.Name, &label.Color, &label.Description, &label.CreatorId); err != nil {
+		return nil, err
+	}
+	return &label, nil
+}
+
+func MakeLabelResponse(label *pb.Label) (*pb.LabelResponse, error) {
+	return &pb.LabelResponse{
+		Response: &pb.LabelResponse_Label{
+			Label: label,
+		},
+	}, nil
+}
+
+func MakeLabelErrorResponse(id int32, msg string) (*pb.LabelResponse, error) {
+	return &pb.LabelResponse{
+		Response: &pb.LabelResponse_Error{
+			Error: &pb.Error{
+				Code:    id,
+				Message: msg,
+			},
+		},
+	}, nil
+}
+
+// Makes response for the label changed by creator or for the error of the
+// change.
+func makeLabelResponse(label *pb.Label, err error) (*pb.LabelResponse, error) {
+	var pgErr *pgconn.PgError
+	if errors.Is(err, pgx.ErrNoRows) {
+		return MakeLabelErrorResponse(ErrCodeLabelNotFound, "Label doesn't exist or belongs to another user")
+	}
+	if errors.As(err, &pgErr) && pgErr.Code == pgUniqueViolation {
+		return MakeLabelErrorResponse(ErrCodeLabelExists, "Label with this name already exists")
+	}
+	if err != nil {
+		return MakeLabelErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return MakeLabelResponse(label)
+}
+
+func (s *server) CreateLabel(ctx context.Context, req *pb.CreateLabelRequest) (*pb.LabelResponse, error) {
+	log.Printf("Handling CreateLabel")
+	return makeLabelResponse(scanLabel(s.dbConn.QueryRow(ctx, `
+		INSERT INTO
+			labels (name, color, description, creator_id, created_at)
+		VALUES
+			($1, $2, $3, $4, $5)
+		RETURNING `+labelColumns,
+		req.Name, req.Color, req.Description, req.UserId, time.Now())))
+}
+
+// Only creator of the label can change or delete it.
+func (s *server) UpdateLabel(ctx context.Context, req *pb.UpdateLabelRequest) (*pb.LabelResponse, error) {
+	log.Printf("Handling UpdateLabel")
+	return makeLabelResponse(scanLabel(s.dbConn.QueryRow(ctx, `
+		UPDATE
+			labels
+		SET
+			name=COALESCE($3, name),
+			color=COALESCE($4, color),
+			description=COALESCE($5, description)
+		WHERE
+			id=$1 AND creator_id=$2
+		RETURNING `+labelColumns,
+		req.LabelId, req.UserId, req.NewName, req.NewColor, req.NewDescription)))
+}
+
+// Label is detached from its tasks, so their versions are increased and the
+// change is recorded in their history.
+func (s *server) DeleteLabel(ctx context.Context, req *pb.DeleteLabelRequest) (*pb.LabelResponse, error) {
+	log.Printf("Handling DeleteLabel")
+	return inTransaction(ctx, s.dbConn, MakeLabelErrorResponse, func(db querier) (*pb.LabelResponse, error) {
+		result, err := db.Query(ctx, `
+			SELECT
+				`+taskColumns+`
+			FROM
+				tasks
+			WHERE
+				id IN (SELECT task_id FROM task_labels WHERE label_id=$1)
+				AND EXISTS (SELECT 1 FROM labels WHERE id=$1 AND creator_id=$2)
+			ORDER BY
+				id
+			FOR UPDATE`,
+			req.LabelId, req.UserId)
+		if err != nil {
+			return makeLabelResponse(nil, err)
+		}
+		var tasks []*pb.Task
+		for result.Next() {
+			task, err := scanTask(result)
+			if err != nil {
+				result.Close()
+				return makeLabelResponse(nil, err)
+			}
+			tasks = append(tasks, task)
+		}
+		result.Close()
+		if err := result.Err(); err != nil {
+			return makeLabelResponse(nil, err)
+		}
+		label, err := scanLabel(db.QueryRow(ctx, `
+			DELETE FROM
+				labels
+			WHERE
+				id=$1 AND creator_id=$2
+			RETURNING `+labelColumns,
+			req.LabelId, req.UserId))
+		for _, task := range tasks {
+			if err != nil {
+				break
+			}
+			_, err = bumpVersion(ctx, db, req.UserId, task)
+		}
+		return makeLabelResponse(label, err)
+	})
+}
+
+func (s *server) ListLabels(ctx context.Context, req *pb.ListLabelsRequest) (*pb.LabelListResponse, error) {
+	log.Printf("Handling ListLabels")
+	makeError := func(err error) (*pb.LabelListResponse, error) {
+		return &pb.LabelListResponse{
+			Response: &pb.LabelListResponse_Error{
+				Error: &pb.Error{
+					Code:    ErrCodeDatabase,
+					Message: fmt.Sprintf("Database error: %v", err),
+				},
+			},
+		}, nil
+	}
+	result, err := s.dbConn.Query(ctx, `SELECT `+labelColumns+` FROM labels WHERE creator_id=$1 ORDER BY name`, req.UserId)
+	if err != nil {
+		return makeError(err)
+	}
+	defer result.Close()
+	labels := pb.LabelList{}
+	for result.Next() {
+		label, err := scanLabel(result)
+		if err != nil {
+			return makeError(err)
+		}
+		labels.Labels = append(labels.Labels, label)
+	}
+	if err := result.Err(); err != nil {
+		return makeError(err)
+	}
+	return &pb.LabelListResponse{
+		Response: &pb.LabelListResponse_Labels{
+			Labels: &labels,
+		},
+	}, nil
+}
+
+// Users attach only their own labels.
+func (s *server) AddTaskLabel(ctx context.Context, req *pb.TaskLabelRequest) (*pb.TaskResponse, error) {
+	log.Printf("Handling AddTaskLabel")
+	return inTransaction(ctx, s.dbConn, MakeErrorResponse, func(db querier) (*pb.TaskResponse, error) {
+		err := db.QueryRow(ctx, `SELECT id FROM labels WHERE id=$1 AND creator_id=$2 FOR SHARE`, req.LabelId, req.UserId).Scan(&req.LabelId)
+		if errors.Is(err, pgx.ErrNoRows) {
+			return MakeErrorResponse(ErrCodeLabelNotFound, "Label doesn't exist or belongs to another user")
+		}
+		if err != nil {
+			return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		return changeTaskLabels(ctx, db, req, `INSERT INTO task_labels (task_id, label_id) VALUES ($1, $2) ON CONFLICT DO NOTHING`)
+	})
+}
+
+func (s *server) RemoveTaskLabel(ctx context.Context, req *pb.TaskLabelRequest) (*pb.TaskResponse, error) {
+	log.Printf("Handling RemoveTaskLabel")
+	return inTransaction(ctx, s.dbConn, MakeErrorResponse, func(db querier) (*pb.TaskResponse, error) {
+		return changeTaskLabels(ctx, db, req, `DELETE FROM task_labels WHERE task_id=$1 AND label_id=$2`)
+	})
+}
+
+// Attaches or detaches the label by given statement and records the change
+// in task history. Task is left as is if the statement changes nothing.
+func changeTaskLabels(ctx context.Context, db querier, req *pb.TaskLabelRequest, statement string) (*pb.TaskResponse, error) {
+	if code, msg := checkTaskRole(ctx, db, req.TaskId, req.UserId, pb.TaskRole_Editor); code != 0 {
+		return MakeErrorResponse(code, msg)
+	}
+	old, err := scanTask(db.QueryRow(ctx, `SELECT `+taskColumns+` FROM tasks WHERE id=$1 FOR UPDATE`, req.TaskId))
+	if err != nil {
+		return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	tag, err := db.Exec(ctx, statement, req.TaskId, req.LabelId)
+	var pgErr *pgconn.PgError
+	if errors.As(err, &pgErr) && pgErr.Code == pgForeignKeyViolation {
+		return MakeErrorResponse(ErrCodeLabelNotFound, "Label doesn't exist")
+	}
+	if err != nil {
+		return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	if tag.RowsAffected() == 0 {
+		return MakeTaskResponse(old)
+	}
+	task, err := bumpVersion(ctx, db, req.UserId, old)
+	if err != nil {
+		return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return MakeTaskResponse(task)
+}
+
+const projectColumns = `id, name, description, creator_id, created_at`
+
+// Scans row selected with projectColumns followed by role of the user.
+func scanProject(row pgx.Row) (*pb.Project, error) {
+	project := pb.Project{}
+	var createdAt time.Time
+	if err := row.Scan(&project.Id, &project.Name, &project.Description, &project.CreatorId, &createdAt, &project.Role); err != nil {
+		return nil, err
+	}
+	project.CreatedAt = timestamppb.New(createdAt)
+	return &project, nil
+}
+
+func MakeProjectErrorResponse(id int32, msg string) (*pb.ProjectResponse, error) {
+	return &pb.ProjectResponse{
+		Response: &pb.ProjectResponse_Error{
+			Error: &pb.Error{
+				Code:    id,
+				Message: msg,
+			},
+		},
+	}, nil
+}
+
+func makeProjectResponse(project *pb.Project, err error) (*pb.ProjectResponse, error) {
+	if err != nil {
+		return MakeProjectErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return &pb.ProjectResponse{
+		Response: &pb.ProjectResponse_Project{
+			Project: project,
+		},
+	}, nil
+}
+
+func (s *server) CreateProject(ctx context.Context, req *pb.CreateProjectRequest) (*pb.ProjectResponse, error) {
+	log.Printf("Handling CreateProject")
+	return makeProjectResponse(scanProject(s.dbConn.QueryRow(ctx, `
+		INSERT INTO
+			projects (name, description, creator_id, created_at)
+		VALUES
+			($1, $2, $3, $4)
+		RETURNING `+projectColumns+`, $5::INT`,
+		req.Name, req.Description, req.UserId, time.Now(), pb.TaskRole_Owner)))
+}
+
+func (s *server) GetProject(ctx context.Context, req *pb.GetProjectRequest) (*pb.ProjectResponse, error) {
+	log.Printf("Handling GetProject")
+	if code, msg := checkProjectRole(ctx, s.dbConn, req.ProjectId, req.UserId, pb.TaskRole_Viewer); code != 0 {
+		return MakeProjectErrorResponse(code, msg)
+	}
+	return makeProjectResponse(scanProject(s.dbConn.QueryRow(ctx, `
+		SELECT
+			`+projectColumns+`, project_role(id, $2)
+		FROM
+			projects
+		WHERE
+			id=$1`,
+		req.ProjectId, req.UserId)))
+}
+
+func (s *server) UpdateProject(ctx context.Context, req *pb.UpdateProjectRequest) (*pb.ProjectResponse, error) {
+	log.Printf("Handling UpdateProject")
+	if code, msg := checkProjectRole(ctx, s.dbConn, req.ProjectId, req.UserId, pb.TaskRole_Owner); code != 0 {
+		return MakeProjectErrorResponse(code, msg)
+	}
+	return makeProjectResponse(scanProject(s.dbConn.QueryRow(ctx, `
+		UPDATE
+			projects
+		SET
+			name=COALESCE($2, name),
+			description=COALESCE($3, description)
+		WHERE
+			id=$1
+		RETURNING `+projectColumns+`, $4::INT`,
+		req.ProjectId, req.NewName, req.NewDescription, pb.TaskRole_Owner)))
+}
+
+// Deletes the project, its tasks stay with their creators.
+func (s *server) DeleteProject(ctx context.Context, req *pb.DeleteProjectRequest) (*pb.ProjectResponse, error) {
+	log.Printf("Handling DeleteProject")
+	return inTransaction(ctx, s.dbConn, MakeProjectErrorResponse, func(db querier) (*pb.ProjectResponse, error) {
+		if code, msg := checkProjectRole(ctx, db, req.ProjectId, req.UserId, pb.TaskRole_Owner); code != 0 {
+			return MakeProjectErrorResponse(code, msg)
+		}
+		// Tasks of the project, including those in trash, stay without it,
+		// which is a change of each of them
+		result, err := db.Query(ctx, `
+			SELECT
+				`+taskColumns+`
+			FROM
+				tasks
+			WHERE
+				project_id=$1
+			ORDER BY
+				id
+			FOR UPDATE`,
+			req.ProjectId)
+		if err != nil {
+			return makeProjectResponse(nil, err)
+		}
+		var tasks []*pb.Task
+		for result.Next() {
+			task, err := scanTask(result)
+			if err != nil {
+				result.Close()
+				return makeProjectResponse(nil, err)
+			}
+			tasks = append(tasks, task)
+		}
+		result.Close()
+		if err := result.Err(); err != nil {
+			return makeProjectResponse(nil, err)
+		}
+		for _, old := range tasks {
+			task, err := scanTask(db.QueryRow(ctx, `UPDATE tasks SET project_id=NULL, version=version+1 WHERE id=$1 RETURNING `+taskColumns, old.Id))
+			if err == nil {
+				err = recordChange(ctx, db, req.UserId, pb.TaskChangeAction_Updated, old, task)
+			}
+			if err != nil {
+				return makeProjectResponse(nil, err)
+			}
+		}
+		return makeProjectResponse(scanProject(db.QueryRow(ctx, `
+			DELETE FROM
+				projects
+			WHERE
+				id=$1
+			RETURNING `+projectColumns+`, $2::INT`,
+			req.ProjectId, pb.TaskRole_Owner)))
+	})
+}
+
+// Lists projects which user created or is a member of.
+func (s *server) ListProjects(ctx context.Context, req *pb.ListProjectsRequest) (*pb.ProjectListResponse, error) {
+	log.Printf("Handling ListProjects")
+	makeError := func(err error) (*pb.ProjectListResponse, error) {
+		return &pb.ProjectListResponse{
+			Response: &pb.ProjectListResponse_Error{
+				Error: &pb.Error{
+					Code:    ErrCodeDatabase,
+					Message: fmt.Sprintf("Database error: %v", err),
+				},
+			},
+		}, nil
+	}
+	result, err := s.dbConn.Query(ctx, `
+		SELECT
+			`+projectColumns+`, project_role(id, $1)
+		FROM
+			projects
+		WHERE
+			creator_id=$1 OR id IN (SELECT project_id FROM project_members WHERE user_id=$1)
+		ORDER BY
+			name, id`,
+		req.UserId)
+	if err != nil {
+		return makeError(err)
+	}
+	defer result.Close()
+	projects := pb.ProjectList{}
+	for result.Next() {
+		project, err := scanProject(result)
+		if err != nil {
+			return makeError(err)
+		}
+		projects.Projects = append(projects.Projects, project)
+	}
+	if err := result.Err(); err != nil {
+		return makeError(err)
+	}
+	return &pb.ProjectListResponse{
+		Response: &pb.ProjectListResponse_Projects{
+			Projects: &projects,
+		},
+	}, nil
+}
+
+func MakeProjectMembersErrorResponse(id int32, msg string) (*pb.ProjectMembersResponse, error) {
+	return &pb.ProjectMembersResponse{
+		Response: &pb.ProjectMembersResponse_Error{
+			Error: &pb.Error{
+				Code:    id,
+				Message: msg,
+			},
+		},
+	}, nil
+}
+
+func (s *server) makeProjectMembersResponse(ctx context.Context, projectId string) (*pb.ProjectMembersResponse, error) {
+	result, err := s.dbConn.Query(ctx, `
+		SELECT
+			creator_id, $2::INT
+		FROM
+			projects
+		WHERE
+			id=$1
+		UNION ALL
+		SELECT
+			user_id, role
+		FROM
+			project_members
+		WHERE
+			project_id=$1`,
+		projectId, int32(pb.TaskRole_Owner))
+	if err != nil {
+		return MakeProjectMembersErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	defer result.Close()
+	members := pb.ProjectMembers{}
+	for result.Next() {
+		member := pb.ProjectMember{}
+		if err := result.Scan(&member.UserId, &member.Role); err != nil {
+			return MakeProjectMembersErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		members.Members = append(members.Members, &member)
+	}
+	if err := result.Err(); err != nil {
+		return MakeProjectMembersErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return &pb.ProjectMembersResponse{
+		Response: &pb.ProjectMembersResponse_Members{
+			Members: &members,
+		},
+	}, nil
+}
+
+func (s *server) GetProjectMembers(ctx context.Context, req *pb.GetProjectRequest) (*pb.ProjectMembersResponse, error) {
+	log.Printf("Handling GetProjectMembers")
+	if code, msg := checkProjectRole(ctx, s.dbConn, req.ProjectId, req.UserId, pb.TaskRole_Viewer); code != 0 {
+		return MakeProjectMembersErrorResponse(code, msg)
+	}
+	return s.makeProjectMembersResponse(ctx, req.ProjectId)
+}
+
+// Adds member to the project or changes role of the member. Role of the
+// creator can't be changed.
+func (s *server) SetProjectMember(ctx context.Context, req *pb.SetProjectMemberRequest) (*pb.ProjectMembersResponse, error) {
+	log.Printf("Handling SetProjectMember")
+	if code, msg := checkProjectRole(ctx, s.dbConn, req.ProjectId, req.UserId, pb.TaskRole_Owner); code != 0 {
+		return MakeProjectMembersErrorResponse(code, msg)
+	}
+	_, err := s.dbConn.Exec(ctx, `
+		INSERT INTO
+			project_members (project_id, user_id, role)
+		SELECT
+			id, $2, $3::INT
+		FROM
+			projects
+		WHERE
+			id=$1 AND creator_id<>$2
+		ON CONFLICT (project_id, user_id) DO UPDATE SET
+			role=EXCLUDED.role`,
+		req.ProjectId, req.MemberId, int32(req.Role))
+	if err != nil {
+		return MakeProjectMembersErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return s.makeProjectMembersResponse(ctx, req.ProjectId)
+}
+
+func (s *server) RemoveProjectMember(ctx context.Context, req *pb.RemoveProjectMemberRequest) (*pb.ProjectMembersResponse, error) {
+	log.Printf("Handling RemoveProjectMember")
+	if code, msg := checkProjectRole(ctx, s.dbConn, req.ProjectId, req.UserId, pb.TaskRole_Owner); code != 0 {
+		return MakeProjectMembersErrorResponse(code, msg)
+	}
+	_, err := s.dbConn.Exec(ctx, `
+		DELETE FROM
+			project_members
+		WHERE
+			project_id=$1 AND user_id=$2`,
+		req.ProjectId, req.MemberId)
+	if err != nil {
+		return MakeProjectMembersErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return s.makeProjectMembersResponse(ctx, req.ProjectId)
+}
+
+func MakeTaskAccessErrorResponse(id int32, msg string) (*pb.TaskAccessResponse, error) {
+	return &pb.TaskAccessResponse{
+		Response: &pb.TaskAccessResponse_Error{
+			Error: &pb.Error{
+				Code:    id,
+				Message: msg,
+			},
+		},
+	}, nil
+}
+
+// Lists creator of the task as owner followed by users task was shared with.
+func (s *server) makeTaskAccessResponse(ctx context.Context, taskId string) (*pb.TaskAccessResponse, error) {
+	result, err := s.dbConn.Query(ctx, `
+		SELECT
+			creator_id, $2::INT
+		FROM
+			tasks
+		WHERE
+			id=$1
+		UNION ALL
+		SELECT
+			user_id, role
+		FROM
+			task_access
+		WHERE
+			task_id=$1`,
+		taskId, int32(pb.TaskRole_Owner))
+	if err != nil {
+		return MakeTaskAccessErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	defer result.Close()
+	accessList := pb.TaskAccessList{}
+	for result.Next() {
+		entry := pb.TaskAccess{}
+		if err := result.Scan(&entry.UserId, &entry.Role); err != nil {
+			return MakeTaskAccessErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		accessList.Entries = append(accessList.Entries, &entry)
+	}
+	if err := result.Err(); err != nil {
+		return MakeTaskAccessErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return &pb.TaskAccessResponse{
+		Response: &pb.TaskAccessResponse_AccessList{
+			AccessList: &accessList,
+		},
+	}, nil
+}
+
+func (s *server) GetTaskAccess(ctx context.Context, req *pb.GetTaskAccessRequest) (*pb.TaskAccessResponse, error) {
+	log.Printf("Handling GetTaskAccess")
+	if code, msg := checkTaskRole(ctx, s.dbConn, req.TaskId, req.UserId, pb.TaskRole_Viewer); code != 0 {
+		return MakeTaskAccessErrorResponse(code, msg)
+	}
+	return s.makeTaskAccessResponse(ctx, req.TaskId)
+}
+
+func (s *server) GrantTaskAccess(ctx context.Context, req *pb.GrantTaskAccessRequest) (*pb.TaskAccessResponse, error) {
+	log.Printf("Handling GrantTaskAccess")
+	if code, msg := checkTaskRole(ctx, s.dbConn, req.TaskId, req.UserId, pb.TaskRole_Owner); code != 0 {
+		return MakeTaskAccessErrorResponse(code, msg)
+	}
+	_, err := s.dbConn.Exec(ctx, `
+		INSERT INTO
+			task_access (task_id, user_id, role)
+		SELECT
+			id, $2, $3::INT
+		FROM
+			tasks
+		WHERE
+			id=$1 AND creator_id<>$2
+		ON CONFLICT (task_id, user_id) DO UPDATE SET
+			role=EXCLUDED.role`,
+		req.TaskId, req.GranteeId, int32(req.Role))
+	if err != nil {
+		return MakeTaskAccessErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return s.makeTaskAccessResponse(ctx, req.TaskId)
+}
+
+func (s *server) RevokeTaskAccess(ctx context.Context, req *pb.RevokeTaskAccessRequest) (*pb.TaskAccessResponse, error) {
+	log.Printf("Handling RevokeTaskAccess")
+	if code, msg := checkTaskRole(ctx, s.dbConn, req.TaskId, req.UserId, pb.TaskRole_Owner); code != 0 {
+		return MakeTaskAccessErrorResponse(code, msg)
+	}
+	_, err := s.dbConn.Exec(ctx, `
+		DELETE FROM
+			task_access
+		WHERE
+			task_id=$1 AND user_id=$2`,
+		req.TaskId, req.GranteeId)
+	if err != nil {
+		return MakeTaskAccessErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return s.makeTaskAccessResponse(ctx, req.TaskId)
+}
+
+const commentColumns = `id, task_id, author_id, parent_id, text, created_at, updated_at`
+
+// Page of comments has at most this many replies to its top level comments
+const maxCommentReplies = 500
+
+func MakeCommentResponse(comment *pb.Comment) (*pb.CommentResponse, error) {
+	return &pb.CommentResponse{
+		Response: &pb.CommentResponse_Comment{
+			Comment: comment,
+		},
+	}, nil
+}
+
+func MakeCommentErrorResponse(id int32, msg string) (*pb.CommentResponse, error) {
+	return &pb.CommentResponse{
+		Response: &pb.CommentResponse_Error{
+			Error: &pb.Error{
+				Code:    id,
+				Message: msg,
+			},
+		},
+	}, nil
+}
+
+func MakeCommentPageErrorResponse(id int32, msg string) (*pb.CommentPageResponse, error) {
+	return &pb.CommentPageResponse{
+		Response: &pb.CommentPageResponse_Error{
+			Error: &pb.Error{
+				Code:    id,
+				Message: msg,
+			},
+		},
+	}, nil
+}
+
+// Scans row selected with commentColumns.
+func scanComment(row pgx.Row) (*pb.Comment, error) {
+	comment := pb.Comment{}
+	var createdAt time.Time
+	var updatedAt *time.Time
+	err := row.Scan(&comment.Id, &comment.TaskId, &comment.AuthorId, &comment.ParentId, &comment.Text, &createdAt, &updatedAt)
+	if err != nil {
+		return nil, err
+	}
+	comment.CreatedAt = timestamppb.New(createdAt)
+	if updatedAt != nil {
+		comment.UpdatedAt = timestamppb.New(*updatedAt)
+	}
+	return &comment, nil
+}
+
+func (s *server) CreateComment(ctx context.Context, req *pb.CreateCommentRequest) (*pb.CommentResponse, error) {
+	log.Printf("Handling CreateComment")
+	if code, msg := checkTaskRole(ctx, s.dbConn, req.TaskId, req.UserId, pb.TaskRole_Viewer); code != 0 {
+		return MakeCommentErrorResponse(code, msg)
+	}
+	comment, err := scanComment(s.dbConn.QueryRow(ctx, `
+		INSERT INTO
+			comments (task_id, author_id, parent_id, text, created_at)
+		SELECT
+			$1::VARCHAR, $2::VARCHAR, $3::VARCHAR, $4::TEXT, $5::TIMESTAMP
+		WHERE
+			$3::VARCHAR IS NULL OR EXISTS (SELECT 1 FROM comments WHERE id=$3 AND task_id=$1)
+		RETURNING `+commentColumns,
+		req.TaskId, req.UserId, req.ParentId, req.Text, time.Now()))
+	if errors.Is(err, pgx.ErrNoRows) {
+		return MakeCommentErrorResponse(ErrCodeDatabase, "Parent comment doesn't exist in this task")
+	}
+	if err != nil {
+		return MakeCommentErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return MakeCommentResponse(comment)
+}
+
+func (s *server) UpdateComment(ctx context.Context, req *pb.UpdateCommentRequest) (*pb.CommentResponse, error) {
+	log.Printf("Handling UpdateComment")
+	if code, msg := checkTaskRole(ctx, s.dbConn, req.TaskId, req.UserId, pb.TaskRole_Viewer); code != 0 {
+		return MakeCommentErrorResponse(code, msg)
+	}
+	comment, err := scanComment(s.dbConn.QueryRow(ctx, `
+		UPDATE
+			comments
+		SET
+			text=$1,
+			updated_at=$2
+		WHERE
+			id=$3 AND task_id=$4 AND author_id=$5
+		RETURNING `+commentColumns,
+		req.NewText, time.Now(), req.CommentId, req.TaskId, req.UserId))
+	if errors.Is(err, pgx.ErrNoRows) {
+		return MakeCommentErrorResponse(ErrCodeAccess, "Comment doesn't exist or user is not its author")
+	}
+	if err != nil {
+		return MakeCommentErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return MakeCommentResponse(comment)
+}
+
+// Deletes comment together with all replies to it. Comment can be deleted by
+// its author or by owner of the task.
+func (s *server) DeleteComment(ctx context.Context, req *pb.DeleteCommentRequest) (*pb.CommentResponse, error) {
+	log.Printf("Handling DeleteComment")
+	if code, msg := checkTaskRole(ctx, s.dbConn, req.TaskId, req.UserId, pb.TaskRole_Viewer); code != 0 {
+		return MakeCommentErrorResponse(code, msg)
+	}
+	code, _ := checkTaskRole(ctx, s.dbConn, req.TaskId, req.UserId, pb.TaskRole_Owner)
+	comment, err := scanComment(s.dbConn.QueryRow(ctx, `
+		DELETE FROM
+			comments
+		WHERE
+			id=$1 AND task_id=$2 AND (author_id=$3 OR $4)
+		RETURNING `+commentColumns,
+		req.CommentId, req.TaskId, req.UserId, code == 0))
+	if errors.Is(err, pgx.ErrNoRows) {
+		return MakeCommentErrorResponse(ErrCodeAccess, "Comment doesn't exist or user can't delete it")
+	}
+	if err != nil {
+		return MakeCommentErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return MakeCommentResponse(comment)
+}
+
+// Returns page of top level comments (sorted by creation time) followed by
+// all replies to them.
+func (s *server) GetCommentPage(ctx context.Context, req *pb.GetCommentPageRequest) (*pb.CommentPageResponse, error) {
+	log.Printf("Handling GetCommentPage")
+	if code, msg := checkTaskRole(ctx, s.dbConn, req.TaskId, req.UserId, pb.TaskRole_Viewer); code != 0 {
+		return MakeCommentPageErrorResponse(code, msg)
+	}
+	// One extra reply is selected to find out if there are more of them.
+	// Replies are ordered by depth, so that parents of selected replies are
+	// selected as well.
+	result, err := s.dbConn.Query(ctx, `
+		WITH RECURSIVE thread AS (
+			(SELECT
+				`+commentColumns+`, 0 AS depth
+			FROM
+				comments
+			WHERE
+				task_id=$1 AND parent_id IS NULL
+			ORDER BY
+				created_at, id
+			LIMIT $2 OFFSET $3)
+			UNION ALL
+			SELECT
+				c.id, c.task_id, c.author_id, c.parent_id, c.text, c.created_at, c.updated_at, t.depth+1
+			FROM
+				comments c JOIN thread t ON c.parent_id=t.id
+		)
+		SELECT
+			`+commentColumns+`
+		FROM
+			thread
+		ORDER BY
+			depth, created_at, id
+		LIMIT $4`,
+		req.TaskId, req.PageSize, req.Offset, req.PageSize+maxCommentReplies+1)
+	if err != nil {
+		return MakeCommentPageErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	defer result.Close()
+	commentPage := pb.CommentPage{}
+	replies := 0
+	for result.Next() {
+		comment, err := scanComment(result)
+		if err != nil {
+			return MakeCommentPageErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		if comment.ParentId != nil {
+			if replies == maxCommentReplies {
+				commentPage.RepliesTruncated = true
+				break
+			}
+			replies++
+		}
+		commentPage.Comments = append(commentPage.Comments, comment)
+	}
+	if err := result.Err(); err != nil {
+		return MakeCommentPageErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	result.Close()
+	err = s.dbConn.QueryRow(ctx, `
+		SELECT
+			COUNT(*)>$2::INT+$3::INT
+		FROM
+			comments
+		WHERE
+			task_id=$1 AND parent_id IS NULL`,
+		req.TaskId, req.Offset, req.PageSize).Scan(&commentPage.HasMore)
+	if err != nil {
+		return MakeCommentPageErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return &pb.CommentPageResponse{
+		Response: &pb.CommentPageResponse_CommentPage{
+			CommentPage: &commentPage,
+		},
+	}, nil
+}
+
+func MakeSearchTasksErrorResponse(id int32, msg string) (*pb.SearchTasksResponse, error) {
+	return &pb.SearchTasksResponse{
+		Response: &pb.SearchTasksResponse_Error{
+			Error: &pb.Error{
+				Code:    id,
+				Message: msg,
+			},
+		},
+	}, nil
+}
+
+// Options of ts_headline, matches are wrapped into <mark> tags.
+const headlineOptions = `StartSel=<mark>, StopSel=</mark>`
+
+// Escapes HTML special characters of text expression, so that <mark> tags
+// added by ts_headline are the only markup of highlights. Escaped characters
+// become entities which aren't indexed, so they are never highlighted.
+func escapeHTML(expr string) string {
+	return `replace(replace(replace(` + expr + `, '&', '&amp;'), '<', '&lt;'), '>', '&gt;')`
+}
+
+// Query text of to_tsquery which matches words starting with all lexemes of
+// the prefix. Prefix is quoted, so it may contain any characters, and its
+// lexemes are all marked as prefixes. Prefix without lexemes matches nothing
+// instead of failing the query.
+func prefixQuery(prefix string) string {
+	return `'` + strings.NewReplacer(`\`, `\\`, `'`, `''`).Replace(prefix) + `':*`
+}
+
+// Searches tasks visible to user which match all terms. Title, description
+// and comments of the task are searched, matches in title are ranked higher.
+func (s *server) SearchTasks(ctx context.Context, req *pb.SearchTasksRequest) (*pb.SearchTasksResponse, error) {
+	log.Printf("Handling SearchTasks")
+	if len(req.Terms) == 0 {
+		return MakeSearchTasksErrorResponse(ErrCodeDatabase, "Search query is empty")
+	}
+	if req.PageSize <= 0 || req.Offset < 0 {
+		return MakeSearchTasksErrorResponse(ErrCodeDatabase, "Invalid page size or offset")
+	}
+	args := []any{req.UserId}
+	arg := func(x any) string {
+		args = append(args, x)
+		return fmt.Sprintf("$%d", len(args))
+	}
+	var queries []string
+	for _, term := range req.Terms {
+		switch term.Kind {
+		case pb.SearchTermKind_Word:
+			queries = append(queries, "plainto_tsquery('simple', "+arg(term.Text)+")")
+		case pb.SearchTermKind_Phrase:
+			queries = append(queries, "phraseto_tsquery('simple', "+arg(term.Text)+")")
+		case pb.SearchTermKind_Prefix:
+			queries = append(queries, "to_tsquery('simple', "+arg(prefixQuery(term.Text))+")")
+		default:
+			return MakeSearchTasksErrorResponse(ErrCodeDatabase, fmt.Sprintf("Unknown search term kind: %v", term.Kind))
+		}
+	}
+	conditions := []string{"deleted_at IS NULL", visibleTasksCondition}
+	conditions = append(conditions, filterConditions(req.Filter, arg)...)
+	offset := arg(req.Offset)
+	// One extra task is selected to find out if there are more of them
+	limit := arg(req.PageSize + 1)
+	// Search vector includes words of comments, so matching tasks are found
+	// by its index and comments are read only for snippets of the page
+	comments := `COALESCE((SELECT string_agg(text, ' ') FROM comments WHERE task_id=tasks.id), '')`
+	conditions = append(conditions, "search_vector @@ query")
+	result, err := s.dbConn.Query(ctx, fmt.Sprintf(`
+		SELECT
+			%s,
+			ts_rank(search_vector, query),
+			ts_headline('simple', %s, query, 'HighlightAll=true, %s'),
+			ts_headline('simple', %s, query, '%s')
+		FROM
+			tasks,
+			(SELECT %s) AS q(query)
+		WHERE
+			%s
+		ORDER BY
+			ts_rank(search_vector, query) DESC, created_at, id
+		OFFSET %s LIMIT %s`,
+		taskColumns, escapeHTML("title"), headlineOptions, escapeHTML("COALESCE(description, '') || ' ' || "+comments), headlineOptions,
+		strings.Join(queries, " && "), strings.Join(conditions, " AND "), offset, limit),
+		args...)
+	if err != nil {
+		return MakeSearchTasksErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	defer result.Close()
+	results := pb.SearchResults{}
+	for result.Next() {
+		hit := pb.SearchHit{}
+		hit.Task, err = scanTask(result, &hit.Rank, &hit.TitleHighlight, &hit.Snippet)
+		if err != nil {
+			return MakeSearchTasksErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		results.Hits = append(results.Hits, &hit)
+	}
+	if err := result.Err(); err != nil {
+		return MakeSearchTasksErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	if len(results.Hits) > int(req.PageSize) {
+		results.Hits = results.Hits[:req.PageSize]
+		results.HasMore = true
+	}
+	return &pb.SearchTasksResponse{
+		Response: &pb.SearchTasksResponse_Results{
+			Results: &results,
+		},
+	}, nil
+}
+
+// Checks if task `to` is reachable from task `from` by following edges
+// selected as (source, target) pairs, i.e. if adding edge from `to` to
+// `from` would close a cycle.
+func isReachable(ctx context.Context, db querier, edges string, from string, to string) (bool, error) {
+	var reachable bool
+	err := db.QueryRow(ctx, `
+		WITH RECURSIVE reachable(id) AS (
+			SELECT $1::TEXT
+			UNION
+			SELECT e.target FROM (`+edges+`) e JOIN reachable r ON e.source=r.id
+		)
+		SELECT EXISTS (SELECT 1 FROM reachable WHERE id=$2)`,
+		from, to).Scan(&reachable)
+	return reachable, err
+}
+
+// Serializes changes of the graph with given name until the end of the
+// transaction, concurrent changes could close a cycle together otherwise.
+func lockGraph(ctx context.Context, db querier, name string) error {
+	_, err := db.Exec(ctx, `SELECT pg_advisory_xact_lock(hashtext($1))`, name)
+	return err
+}
+
+func (s *server) SetTaskParent(ctx context.Context, req *pb.SetTaskParentRequest) (*pb.TaskResponse, error) {
+	log.Printf("Handling SetTaskParent")
+	return inTransaction(ctx, s.dbConn, MakeErrorResponse, func(db querier) (*pb.TaskResponse, error) {
+		return setTaskParent(ctx, db, req)
+	})
+}
+
+// Makes the task a subtask of ParentId, or a top-level task if it isn't set.
+// Task can't become a subtask of itself or of any of its subtasks.
+func setTaskParent(ctx context.Context, db querier, req *pb.SetTaskParentRequest) (*pb.TaskResponse, error) {
+	if code, msg := checkTaskRole(ctx, db, req.TaskId, req.UserId, pb.TaskRole_Editor); code != 0 {
+		return MakeErrorResponse(code, msg)
+	}
+	if req.ParentId != nil {
+		if code, msg := checkTaskRole(ctx, db, *req.ParentId, req.UserId, pb.TaskRole_Editor); code != 0 {
+			return MakeErrorResponse(code, msg)
+		}
+	}
+	old, err := scanTask(db.QueryRow(ctx, `SELECT `+taskColumns+` FROM tasks WHERE id=$1 FOR UPDATE`, req.TaskId))
+	if err != nil {
+		return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	if req.ExpectedVersion != nil && old.Version != *req.ExpectedVersion {
+		return MakeErrorResponse(ErrCodeVersion, "Task doesn't exist or its version has changed")
+	}
+	if req.ParentId != nil {
+		if err := lockGraph(ctx, db, "task_parents"); err != nil {
+			return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		cycle, err := isReachable(ctx, db, `SELECT id AS source, parent_id AS target FROM tasks`, *req.ParentId, req.TaskId)
+		if err != nil {
+			return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		if cycle {
+			return MakeErrorResponse(ErrCodeCycle, "Task can't become a subtask of itself or of its subtask")
+		}
+	}
+	if req.GetParentId() == old.GetParentId() {
+		return MakeTaskResponse(old)
+	}
+	// Both the old and the new parent count the task
+	bumpDependents, err := lockDependentTasks(ctx, db, req.UserId, dependentTasksCondition+` OR id=$2`, req.TaskId, req.ParentId)
+	if err != nil {
+		return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	task, err := scanTask(db.QueryRow(ctx, `UPDATE tasks SET parent_id=$2, version=version+1 WHERE id=$1 RETURNING `+taskColumns, req.TaskId, req.ParentId))
+	if err == nil {
+		err = recordChange(ctx, db, req.UserId, pb.TaskChangeAction_Updated, old, task)
+	}
+	if err == nil {
+		err = bumpDependents()
+	}
+	if err != nil {
+		return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return MakeTaskResponse(task)
+}
+
+func MakeTaskDependenciesErrorResponse(id int32, msg string) (*pb.TaskDependenciesResponse, error) {
+	return &pb.TaskDependenciesResponse{
+		Response: &pb.TaskDependenciesResponse_Error{
+			Error: &pb.Error{
+				Code:    id,
+				Message: msg,
+			},
+		},
+	}, nil
+}
+
+// Lists tasks blocking the task and tasks blocked by it, only tasks visible
+// to the user are listed.
+func listTaskDependencies(ctx context.Context, db querier, userId string, taskId string) (*pb.TaskDependenciesResponse, error) {
+	dependencies := pb.TaskDependencies{}
+	list := func(ids string) ([]*pb.Task, error) {
+		result, err := db.Query(ctx, `
+			SELECT
+				`+taskColumns+`
+			FROM
+				tasks
+			WHERE
+				deleted_at IS NULL AND id IN (`+ids+`) AND `+visibleTasksCondition+`
+			ORDER BY
+				created_at, id`,
+			userId, taskId)
+		if err != nil {
+			return nil, err
+		}
+		defer result.Close()
+		var tasks []*pb.Task
+		for result.Next() {
+			task, err := scanTask(result)
+			if err != nil {
+				return nil, err
+			}
+			tasks = append(tasks, task)
+		}
+		return tasks, result.Err()
+	}
+	var err error
+	if dependencies.BlockedBy, err = list(`SELECT blocker_id FROM task_dependencies WHERE task_id=$2`); err != nil {
+		return MakeTaskDependenciesErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	if dependencies.Blocks, err = list(`SELECT task_id FROM task_dependencies WHERE blocker_id=$2`); err != nil {
+		return MakeTaskDependenciesErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return &pb.TaskDependenciesResponse{
+		Response: &pb.TaskDependenciesResponse_Dependencies{
+			Dependencies: &dependencies,
+		},
+	}, nil
+}
+
+func (s *server) GetTaskDependencies(ctx context.Context, req *pb.GetTaskRequest) (*pb.TaskDependenciesResponse, error) {
+	log.Printf("Handling GetTaskDependencies")
+	if code, msg := checkTaskRole(ctx, s.dbConn, req.TaskId, req.UserId, pb.TaskRole_Viewer); code != 0 {
+		return MakeTaskDependenciesErrorResponse(code, msg)
+	}
+	return listTaskDependencies(ctx, s.dbConn, req.UserId, req.TaskId)
+}
+
+// Marks the task as blocked by BlockerId. Task can't be blocked by itself or
+// by a task which it blocks directly or transitively.
+func (s *server) AddTaskDependency(ctx context.Context, req *pb.TaskDependencyRequest) (*pb.TaskDependenciesResponse, error) {
+	log.Printf("Handling AddTaskDependency")
+	if code, msg := checkTaskRole(ctx, s.dbConn, req.TaskId, req.UserId, pb.TaskRole_Editor); code != 0 {
+		return MakeTaskDependenciesErrorResponse(code, msg)
+	}
+	if code, msg := checkTaskRole(ctx, s.dbConn, req.BlockerId, req.UserId, pb.TaskRole_Viewer); code != 0 {
+		return MakeTaskDependenciesErrorResponse(code, msg)
+	}
+	return inTransaction(ctx, s.dbConn, MakeTaskDependenciesErrorResponse, func(db querier) (*pb.TaskDependenciesResponse, error) {
+		if err := lockGraph(ctx, db, "task_dependencies"); err != nil {
+			return MakeTaskDependenciesErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		cycle, err := isReachable(ctx, db, `SELECT task_id AS source, blocker_id AS target FROM task_dependencies`, req.BlockerId, req.TaskId)
+		if err != nil {
+			return MakeTaskDependenciesErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		if cycle {
+			return MakeTaskDependenciesErrorResponse(ErrCodeCycle, "Task can't be blocked by itself or by a task it blocks")
+		}
+		// Open blockers are counted by the task
+		bumpDependents, err := lockDependentTasks(ctx, db, req.UserId, `id=$1`, req.TaskId)
+		if err == nil {
+			_, err = db.Exec(ctx, `
+				INSERT INTO
+					task_dependencies (task_id, blocker_id, creator_id, created_at)
+				VALUES
+					($1, $2, $3, $4)
+				ON CONFLICT DO NOTHING`,
+				req.TaskId, req.BlockerId, req.UserId, time.Now())
+		}
+		if err == nil {
+			err = bumpDependents()
+		}
+		if err != nil {
+			return MakeTaskDependenciesErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		return listTaskDependencies(ctx, db, req.UserId, req.TaskId)
+	})
+}
+
+func (s *server) RemoveTaskDependency(ctx context.Context, req *pb.TaskDependencyRequest) (*pb.TaskDependenciesResponse, error) {
+	log.Printf("Handling RemoveTaskDependency")
+	if code, msg := checkTaskRole(ctx, s.dbConn, req.TaskId, req.UserId, pb.TaskRole_Editor); code != 0 {
+		return MakeTaskDependenciesErrorResponse(code, msg)
+	}
+	return inTransaction(ctx, s.dbConn, MakeTaskDependenciesErrorResponse, func(db querier) (*pb.TaskDependenciesResponse, error) {
+		bumpDependents, err := lockDependentTasks(ctx, db, req.UserId, `id=$1`, req.TaskId)
+		if err == nil {
+			_, err = db.Exec(ctx, `DELETE FROM task_dependencies WHERE task_id=$1 AND blocker_id=$2`, req.TaskId, req.BlockerId)
+		}
+		if err == nil {
+			err = bumpDependents()
+		}
+		if err != nil {
+			return MakeTaskDependenciesErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		return listTaskDependencies(ctx, db, req.UserId, req.TaskId)
+	})
+}
+
+const checklistItemColumns = `id, text, done, assigned_to, created_at`
+
+func MakeChecklistErrorResponse(id int32, msg string) (*pb.ChecklistResponse, error) {
+	return &pb.ChecklistResponse{
+		Response: &pb.ChecklistResponse_Error{
+			Error: &pb.Error{
+				Code:    id,
+				Message: msg,
+			},
+		},
+	}, nil
+}
+
+// Lists checklist items of the task in their order.
+func listChecklist(ctx context.Context, db querier, taskId string) (*pb.ChecklistResponse, error) {
+	result, err := db.Query(ctx, `SELECT `+checklistItemColumns+` FROM checklist_items WHERE task_id=$1 ORDER BY position, id`, taskId)
+	if err != nil {
+		return MakeChecklistErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	defer result.Close()
+	checklist := pb.Checklist{}
+	for result.Next() {
+		item := pb.ChecklistItem{}
+		var createdAt time.Time
+		if err := result.Scan(&item.Id, &item.Text, &item.Done, &item.AssignedTo, &createdAt); err != nil {
+			return MakeChecklistErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		item.CreatedAt = timestamppb.New(createdAt)
+		checklist.Items = append(checklist.Items, &item)
+	}
+	if err := result.Err(); err != nil {
+		return MakeChecklistErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return &pb.ChecklistResponse{
+		Response: &pb.ChecklistResponse_Checklist{
+			Checklist: &checklist,
+		},
+	}, nil
+}
+
+// Changes checklist of the task by op in a transaction and returns the new
+// checklist. op returns error code and message if the change can't be made.
+func (s *server) changeChecklist(ctx context.Context, userId string, taskId string, op func(db querier) (int32, string)) (*pb.ChecklistResponse, error) {
+	return inTransaction(ctx, s.dbConn, MakeChecklistErrorResponse, func(db querier) (*pb.ChecklistResponse, error) {
+		if code, msg := checkTaskRole(ctx, db, taskId, userId, pb.TaskRole_Editor); code != 0 {
+			return MakeChecklistErrorResponse(code, msg)
+		}
+		old, err := scanTask(db.QueryRow(ctx, `SELECT `+taskColumns+` FROM tasks WHERE id=$1 FOR UPDATE`, taskId))
+		if err != nil {
+			return MakeChecklistErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		if code, msg := op(db); code != 0 {
+			return MakeChecklistErrorResponse(code, msg)
+		}
+		if _, err := bumpVersion(ctx, db, userId, old); err != nil {
+			return MakeChecklistErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		return listChecklist(ctx, db, taskId)
+	})
+}
+
+func (s *server) GetChecklist(ctx context.Context, req *pb.GetTaskRequest) (*pb.ChecklistResponse, error) {
+	log.Printf("Handling GetChecklist")
+	if code, msg := checkTaskRole(ctx, s.dbConn, req.TaskId, req.UserId, pb.TaskRole_Viewer); code != 0 {
+		return MakeChecklistErrorResponse(code, msg)
+	}
+	return listChecklist(ctx, s.dbConn, req.TaskId)
+}
+
+// Adds item to the end of the checklist.
+func (s *server) AddChecklistItem(ctx context.Context, req *pb.AddChecklistItemRequest) (*pb.ChecklistResponse, error) {
+	log.Printf("Handling AddChecklistItem")
+	return s.changeChecklist(ctx, req.UserId, req.TaskId, func(db querier) (int32, string) {
+		_, err := db.Exec(ctx, `
+			INSERT INTO
+				checklist_items (task_id, text, assigned_to, position, created_at)
+			SELECT
+				$1, $2, $3, COALESCE(MAX(position), 0) + 1, $4
+			FROM
+				checklist_items
+			WHERE
+				task_id=$1`,
+			req.TaskId, req.Text, req.AssignedTo, time.Now())
+		if err != nil {
+			return ErrCodeDatabase, fmt.Sprintf("Database error: %v", err)
+		}
+		return 0, ""
+	})
+}
+
+// Changes text, done flag or assignee of the item, empty assignee removes it.
+func (s *server) UpdateChecklistItem(ctx context.Context, req *pb.UpdateChecklistItemRequest) (*pb.ChecklistResponse, error) {
+	log.Printf("Handling UpdateChecklistItem")
+	return s.changeChecklist(ctx, req.UserId, req.TaskId, func(db querier) (int32, string) {
+		tag, err := db.Exec(ctx, `
+			UPDATE
+				checklist_items
+			SET
+				text=COALESCE($3, text),
+				done=COALESCE($4, done),
+				assigned_to=NULLIF(COALESCE($5, assigned_to, ''), '')
+			WHERE
+				id=$2 AND task_id=$1`,
+			req.TaskId, req.ItemId, req.NewText, req.NewDone, req.NewAssignedTo)
+		if err != nil {
+			return ErrCodeDatabase, fmt.Sprintf("Database error: %v", err)
+		}
+		if tag.RowsAffected() == 0 {
+			return ErrCodeItemNotFound, "Checklist item doesn't exist"
+		}
+		return 0, ""
+	})
+}
+
+// Moves the item to given index of the checklist, indexes past the end move
+// it to the end.
+func (s *server) MoveChecklistItem(ctx context.Context, req *pb.MoveChecklistItemRequest) (*pb.ChecklistResponse, error) {
+	log.Printf("Handling MoveChecklistItem")
+	return s.changeChecklist(ctx, req.UserId, req.TaskId, func(db querier) (int32, string) {
+		result, err := db.Query(ctx, `SELECT id FROM checklist_items WHERE task_id=$1 ORDER BY position, id`, req.TaskId)
+		if err != nil {
+			return ErrCodeDatabase, fmt.Sprintf("Database error: %v", err)
+		}
+		ids, err := pgx.CollectRows(result, pgx.RowTo[string])
+		if err != nil {
+			return ErrCodeDatabase, fmt.Sprintf("Database error: %v", err)
+		}
+		from := slices.Index(ids, req.ItemId)
+		if from < 0 {
+			return ErrCodeItemNotFound, "Checklist item doesn't exist"
+		}
+		ids = slices.Delete(ids, from, from+1)
+		ids = slices.Insert(ids, min(max(int(req.Index), 0), len(ids)), req.ItemId)
+		_, err = db.Exec(ctx, `
+			UPDATE
+				checklist_items c
+			SET
+				position=x.n
+			FROM
+				unnest($1::TEXT[]) WITH ORDINALITY x(id, n)
+			WHERE
+				c.id=x.id`,
+			ids)
+		if err != nil {
+			return ErrCodeDatabase, fmt.Sprintf("Database error: %v", err)
+		}
+		return 0, ""
+	})
+}
+
+func (s *server) DeleteChecklistItem(ctx context.Context, req *pb.ChecklistItemRequest) (*pb.ChecklistResponse, error) {
+	log.Printf("Handling DeleteChecklistItem")
+	return s.changeChecklist(ctx, req.UserId, req.TaskId, func(db querier) (int32, string) {
+		tag, err := db.Exec(ctx, `DELETE FROM checklist_items WHERE id=$2 AND task_id=$1`, req.TaskId, req.ItemId)
+		if err != nil {
+			return ErrCodeDatabase, fmt.Sprintf("Database error: %v", err)
+		}
+		if tag.RowsAffected() == 0 {
+			return ErrCodeItemNotFound, "Checklist item doesn't exist"
+		}
+		return 0, ""
+	})
+}
+
+const attachmentColumns = `id, filename, content_type, size, uploader_id, created_at, storage_key`
+
+func scanAttachment(row pgx.Row) (*pb.Attachment, error) {
+	attachment := pb.Attachment{}
+	var createdAt time.Time
+	if err := row.Scan(&attachment.Id, &attachment.Filename, &attachment.ContentType, &attachment.Size, &attachment.UploaderId, &createdAt, &attachment.StorageKey); err != nil {
+		return nil, err
+	}
+	attachment.CreatedAt = timestamppb.New(createdAt)
+	return &attachment, nil
+}
+
+func MakeAttachmentErrorResponse(id int32, msg string) (*pb.AttachmentResponse, error) {
+	return &pb.AttachmentResponse{
+		Response: &pb.AttachmentResponse_Error{
+			Error: &pb.Error{
+				Code:    id,
+				Message: msg,
+			},
+		},
+	}, nil
+}
+
+func MakeAttachmentResponse(attachment *pb.Attachment) (*pb.AttachmentResponse, error) {
+	return &pb.AttachmentResponse{
+		Response: &pb.AttachmentResponse_Attachment{
+			Attachment: attachment,
+		},
+	}, nil
+}
+
+// Adds or removes attachment of the task by op in a transaction.
+func (s *server) changeAttachments(ctx context.Context, userId string, taskId string, op func(db querier) (*pb.Attachment, error)) (*pb.AttachmentResponse, error) {
+	return inTransaction(ctx, s.dbConn, MakeAttachmentErrorResponse, func(db querier) (*pb.AttachmentResponse, error) {
+		if code, msg := checkTaskRole(ctx, db, taskId, userId, pb.TaskRole_Editor); code != 0 {
+			return MakeAttachmentErrorResponse(code, msg)
+		}
+		old, err := scanTask(db.QueryRow(ctx, `SELECT `+taskColumns+` FROM tasks WHERE id=$1 FOR UPDATE`, taskId))
+		if err != nil {
+			return MakeAttachmentErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		attachment, err := op(db)
+		if errors.Is(err, pgx.ErrNoRows) {
+			return MakeAttachmentErrorResponse(ErrCodeAttachmentNotFound, "Attachment doesn't exist")
+		}
+		if err == nil {
+			_, err = bumpVersion(ctx, db, userId, old)
+		}
+		if err != nil {
+			return MakeAttachmentErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		return MakeAttachmentResponse(attachment)
+	})
+}
+
+// Records attachment which content is already stored by user service.
+func (s *server) AddAttachment(ctx context.Context, req *pb.AddAttachmentRequest) (*pb.AttachmentResponse, error) {
+	log.Printf("Handling AddAttachment")
+	return s.changeAttachments(ctx, req.UserId, req.TaskId, func(db querier) (*pb.Attachment, error) {
+		return scanAttachment(db.QueryRow(ctx, `
+			INSERT INTO
+				attachments (task_id, uploader_id, filename, content_type, size, storage_key, created_at)
+			VALUES
+				($1, $2, $3, $4, $5, $6, $7)
+			RETURNING
+				`+attachmentColumns,
+			req.TaskId, req.UserId, req.Filename, req.ContentType, req.Size, req.StorageKey, time.Now()))
+	})
+}
+
+func (s *server) ListAttachments(ctx context.Context, req *pb.GetTaskRequest) (*pb.AttachmentListResponse, error) {
+	log.Printf("Handling ListAttachments")
+	makeError := func(code int32, msg string) (*pb.AttachmentListResponse, error) {
+		return &pb.AttachmentListResponse{
+			Response: &pb.AttachmentListResponse_Error{
+				Error: &pb.Error{
+					Code:    code,
+					Message: msg,
+				},
+			},
+		}, nil
+	}
+	if code, msg := checkTaskRole(ctx, s.dbConn, req.TaskId, req.UserId, pb.TaskRole_Viewer); code != 0 {
+		return makeError(code, msg)
+	}
+	result, err := s.dbConn.Query(ctx, `SELECT `+attachmentColumns+` FROM attachments WHERE task_id=$1 ORDER BY created_at, id`, req.TaskId)
+	if err != nil {
+		return makeError(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	defer result.Close()
+	attachments := pb.AttachmentList{}
+	for result.Next() {
+		attachment, err := scanAttachment(result)
+		if err != nil {
+			return makeError(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		attachments.Attachments = append(attachments.Attachments, attachment)
+	}
+	if err := result.Err(); err != nil {
+		return makeError(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return &pb.AttachmentListResponse{
+		Response: &pb.AttachmentListResponse_Attachments{
+			Attachments: &attachments,
+		},
+	}, nil
+}
+
+func (s *server) GetAttachment(ctx context.Context, req *pb.AttachmentRequest) (*pb.AttachmentResponse, error) {
+	log.Printf("Handling GetAttachment")
+	if code, msg := checkTaskRole(ctx, s.dbConn, req.TaskId, req.UserId, pb.TaskRole_Viewer); code != 0 {
+		return MakeAttachmentErrorResponse(code, msg)
+	}
+	attachment, err := scanAttachment(s.dbConn.QueryRow(ctx, `SELECT `+attachmentColumns+` FROM attachments WHERE id=$2 AND task_id=$1`, req.TaskId, req.AttachmentId))
+	if errors.Is(err, pgx.ErrNoRows) {
+		return MakeAttachmentErrorResponse(ErrCodeAttachmentNotFound, "Attachment doesn't exist")
+	}
+	if err != nil {
+		return MakeAttachmentErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return MakeAttachmentResponse(attachment)
+}
+
+// Deletes the attachment, its content is removed from storage later through
+// ListDeletedAttachments.
+func (s *server) DeleteAttachment(ctx context.Context, req *pb.AttachmentRequest) (*pb.AttachmentResponse, error) {
+	log.Printf("Handling DeleteAttachment")
+	return s.changeAttachments(ctx, req.UserId, req.TaskId, func(db querier) (*pb.Attachment, error) {
+		return scanAttachment(db.QueryRow(ctx, `DELETE FROM attachments WHERE id=$2 AND task_id=$1 RETURNING `+attachmentColumns, req.TaskId, req.AttachmentId))
+	})
+}
+
+func MakeDeletedAttachmentsErrorResponse(id int32, msg string) (*pb.DeletedAttachmentsResponse, error) {
+	return &pb.DeletedAttachmentsResponse{
+		Response: &pb.DeletedAttachmentsResponse_Error{
+			Error: &pb.Error{
+				Code:    id,
+				Message: msg,
+			},
+		},
+	}, nil
+}
+
+// Lists storage keys of deleted attachments, oldest first.
+func (s *server) ListDeletedAttachments(ctx context.Context, req *pb.ListDeletedAttachmentsRequest) (*pb.DeletedAttachmentsResponse, error) {
+	log.Printf("Handling ListDeletedAttachments")
+	result, err := s.dbConn.Query(ctx, `SELECT storage_key FROM deleted_attachments ORDER BY deleted_at LIMIT $1`, req.Limit)
+	if err != nil {
+		return MakeDeletedAttachmentsErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	keys, err := pgx.CollectRows(result, pgx.RowTo[string])
+	if err != nil {
+		return MakeDeletedAttachmentsErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return &pb.DeletedAttachmentsResponse{
+		Response: &pb.DeletedAttachmentsResponse_Deleted{
+			Deleted: &pb.DeletedAttachments{StorageKeys: keys},
+		},
+	}, nil
+}
+
+// Forgets deleted attachments which content is removed from storage. Returns
+// the keys which were forgotten.
+func (s *server) ForgetDeletedAttachments(ctx context.Context, req *pb.ForgetDeletedAttachmentsRequest) (*pb.DeletedAttachmentsResponse, error) {
+	log.Printf("Handling ForgetDeletedAttachments")
+	result, err := s.dbConn.Query(ctx, `DELETE FROM deleted_attachments WHERE storage_key=ANY($1) RETURNING storage_key`, req.StorageKeys)
+	if err != nil {
+		return MakeDeletedAttachmentsErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	keys, err := pgx.CollectRows(result, pgx.RowTo[string])
+	if err != nil {
+		return MakeDeletedAttachmentsErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return &pb.DeletedAttachmentsResponse{
+		Response: &pb.DeletedAttachmentsResponse_Deleted{
+			Deleted: &pb.DeletedAttachments{StorageKeys: keys},
+		},
+	}, nil
+}
+
+const timeEntryColumns = `id, task_id, user_id, spent_on, minutes, started_at, note, created_at`
+
+// Time entry can't be longer than a day
+const maxEntryMinutes = 24 * 60
+
+func scanTimeEntry(row pgx.Row) (*pb.TimeEntry, error) {
+	entry := pb.TimeEntry{}
+	var spentOn, createdAt time.Time
+	var startedAt *time.Time
+	if err := row.Scan(&entry.Id, &entry.TaskId, &entry.UserId, &spentOn, &entry.Minutes, &startedAt, &entry.Note, &createdAt); err != nil {
+		return nil, err
+	}
+	entry.SpentOn = spentOn.Format(dateLayout)
+	if startedAt != nil {
+		entry.StartedAt = timestamppb.New(*startedAt)
+	}
+	entry.CreatedAt = timestamppb.New(createdAt)
+	return &entry, nil
+}
+
+func MakeTimeEntryErrorResponse(id int32, msg string) (*pb.TimeEntryResponse, error) {
+	return &pb.TimeEntryResponse{
+		Response: &pb.TimeEntryResponse_Error{
+			Error: &pb.Error{
+				Code:    id,
+				Message: msg,
+			},
+		},
+	}, nil
+}
+
+func MakeTimeEntryResponse(entry *pb.TimeEntry) (*pb.TimeEntryResponse, error) {
+	return &pb.TimeEntryResponse{
+		Response: &pb.TimeEntryResponse_Entry{
+			Entry: entry,
+		},
+	}, nil
+}
+
+// Makes response with the entry which op returned, missing entry is reported
+// with given error code and message.
+func makeTimeEntryResponse(entry *pb.TimeEntry, err error, code int32, msg string) (*pb.TimeEntryResponse, error) {
+	var pgErr *pgconn.PgError
+	if errors.Is(err, pgx.ErrNoRows) {
+		return MakeTimeEntryErrorResponse(code, msg)
+	}
+	if errors.As(err, &pgErr) && pgErr.Code == pgUniqueViolation {
+		return MakeTimeEntryErrorResponse(ErrCodeConflict, "Timer was started by another request")
+	}
+	if err != nil {
+		return MakeTimeEntryErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return MakeTimeEntryResponse(entry)
+}
+
+// Changes time entries of the task by op and returns the changed entry.
+func changeLoggedTime(ctx context.Context, db querier, userId string, taskId string, op func() (*pb.TimeEntry, error)) (*pb.TimeEntry, error) {
+	old, err := scanTask(db.QueryRow(ctx, `SELECT `+taskColumns+` FROM tasks WHERE id=$1 FOR UPDATE`, taskId))
+	if err != nil {
+		return nil, err
+	}
+	entry, err := op()
+	if err == nil {
+		_, err = bumpVersion(ctx, db, userId, old)
+	}
+	return entry, err
+}
+
+// Stops running timer of the user, which has to run on the task unless taskId
+// is nil. Elapsed time is rounded to minutes and capped at maxEntryMinutes.
+// Returns pgx.ErrNoRows if there is no such timer.
+func stopTimer(ctx context.Context, db querier, userId string, taskId *string) (*pb.TimeEntry, error) {
+	var entryTaskId string
+	err := db.QueryRow(ctx, `SELECT task_id FROM time_entries WHERE user_id=$1 AND minutes IS NULL AND ($2::TEXT IS NULL OR task_id=$2)`, userId, taskId).Scan(&entryTaskId)
+	if err != nil {
+		return nil, err
+	}
+	return changeLoggedTime(ctx, db, userId, entryTaskId, func() (*pb.TimeEntry, error) {
+		return scanTimeEntry(db.QueryRow(ctx, `
+			UPDATE
+				time_entries
+			SET
+				minutes=LEAST(ROUND(EXTRACT(EPOCH FROM $2::TIMESTAMP - started_at) / 60), $3)::INT
+			WHERE
+				user_id=$1 AND minutes IS NULL
+			RETURNING
+				`+timeEntryColumns,
+			userId, time.Now(), maxEntryMinutes))
+	})
+}
+
+// Starts timer of the user on the task, running timer of the user is stopped
+// first.
+func (s *server) StartTimer(ctx context.Context, req *pb.GetTaskRequest) (*pb.TimeEntryResponse, error) {
+	log.Printf("Handling StartTimer")
+	return inTransaction(ctx, s.dbConn, MakeTimeEntryErrorResponse, func(db querier) (*pb.TimeEntryResponse, error) {
+		if code, msg := checkTaskRole(ctx, db, req.TaskId, req.UserId, pb.TaskRole_Editor); code != 0 {
+			return MakeTimeEntryErrorResponse(code, msg)
+		}
+		if _, err := stopTimer(ctx, db, req.UserId, nil); err != nil && !errors.Is(err, pgx.ErrNoRows) {
+			return MakeTimeEntryErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		now := time.Now()
+		entry, err := scanTimeEntry(db.QueryRow(ctx, `
+			INSERT INTO
+				time_entries (task_id, user_id, spent_on, started_at, created_at)
+			VALUES
+				($1, $2, $3::TEXT::DATE, $4, $4)
+			RETURNING
+				`+timeEntryColumns,
+			req.TaskId, req.UserId, now.Format(dateLayout), now))
+		return makeTimeEntryResponse(entry, err, ErrCodeDatabase, "Timer wasn't started")
+	})
+}
+
+// Stops timer of the user which runs on the task. Timer can be stopped even
+// if user lost access to the task meanwhile.
+func (s *server) StopTimer(ctx context.Context, req *pb.GetTaskRequest) (*pb.TimeEntryResponse, error) {
+	log.Printf("Handling StopTimer")
+	return inTransaction(ctx, s.dbConn, MakeTimeEntryErrorResponse, func(db querier) (*pb.TimeEntryResponse, error) {
+		entry, err := stopTimer(ctx, db, req.UserId, &req.TaskId)
+		return makeTimeEntryResponse(entry, err, ErrCodeTimerNotRunning, "No timer of the user is running on this task")
+	})
+}
+
+func (s *server) GetRunningTimer(ctx context.Context, req *pb.GetRunningTimerRequest) (*pb.TimeEntryResponse, error) {
+	log.Printf("Handling GetRunningTimer")
+	entry, err := scanTimeEntry(s.dbConn.QueryRow(ctx, `SELECT `+timeEntryColumns+` FROM time_entries WHERE user_id=$1 AND minutes IS NULL`, req.UserId))
+	return makeTimeEntryResponse(entry, err, ErrCodeTimerNotRunning, "No timer of the user is running")
+}
+
+// Logs time spent on the task by hand.
+func (s *server) AddTimeEntry(ctx context.Context, req *pb.AddTimeEntryRequest) (*pb.TimeEntryResponse, error) {
+	log.Printf("Handling AddTimeEntry")
+	return inTransaction(ctx, s.dbConn, MakeTimeEntryErrorResponse, func(db querier) (*pb.TimeEntryResponse, error) {
+		if code, msg := checkTaskRole(ctx, db, req.TaskId, req.UserId, pb.TaskRole_Editor); code != 0 {
+			return MakeTimeEntryErrorResponse(code, msg)
+		}
+		entry, err := changeLoggedTime(ctx, db, req.UserId, req.TaskId, func() (*pb.TimeEntry, error) {
+			return scanTimeEntry(db.QueryRow(ctx, `
+				INSERT INTO
+					time_entries (task_id, user_id, spent_on, minutes, note, created_at)
+				VALUES
+					($1, $2, $3::TEXT::DATE, $4, $5, $6)
+				RETURNING
+					`+timeEntryColumns,
+				req.TaskId, req.UserId, req.SpentOn, req.Minutes, req.Note, time.Now()))
+		})
+		return makeTimeEntryResponse(entry, err, ErrCodeDatabase, "Time entry wasn't added")
+	})
+}
+
+// Changes date, minutes or note of finished entry of the user.
+func (s *server) UpdateTimeEntry(ctx context.Context, req *pb.UpdateTimeEntryRequest) (*pb.TimeEntryResponse, error) {
+	log.Printf("Handling UpdateTimeEntry")
+	return inTransaction(ctx, s.dbConn, MakeTimeEntryErrorResponse, func(db querier) (*pb.TimeEntryResponse, error) {
+		if code, msg := checkTaskRole(ctx, db, req.TaskId, req.UserId, pb.TaskRole_Editor); code != 0 {
+			return MakeTimeEntryErrorResponse(code, msg)
+		}
+		entry, err := changeLoggedTime(ctx, db, req.UserId, req.TaskId, func() (*pb.TimeEntry, error) {
+			return scanTimeEntry(db.QueryRow(ctx, `
+				UPDATE
+					time_entries
+				SET
+					spent_on=COALESCE($4::TEXT::DATE, spent_on),
+					minutes=COALESCE($5, minutes),
+					note=COALESCE($6, note)
+				WHERE
+					id=$2 AND task_id=$1 AND user_id=$3 AND minutes IS NOT NULL
+				RETURNING
+					`+timeEntryColumns,
+				req.TaskId, req.EntryId, req.UserId, req.NewSpentOn, req.NewMinutes, req.NewNote))
+		})
+		return makeTimeEntryResponse(entry, err, ErrCodeTimeEntryNotFound, "Time entry doesn't exist")
+	})
+}
+
+// Deletes entry of the user, deleting entry of running timer discards it.
+func (s *server) DeleteTimeEntry(ctx context.Context, req *pb.TimeEntryRequest) (*pb.TimeEntryResponse, error) {
+	log.Printf("Handling DeleteTimeEntry")
+	return inTransaction(ctx, s.dbConn, MakeTimeEntryErrorResponse, func(db querier) (*pb.TimeEntryResponse, error) {
+		if code, msg := checkTaskRole(ctx, db, req.TaskId, req.UserId, pb.TaskRole_Editor); code != 0 {
+			return MakeTimeEntryErrorResponse(code, msg)
+		}
+		entry, err := changeLoggedTime(ctx, db, req.UserId, req.TaskId, func() (*pb.TimeEntry, error) {
+			return scanTimeEntry(db.QueryRow(ctx, `DELETE FROM time_entries WHERE id=$2 AND task_id=$1 AND user_id=$3 RETURNING `+timeEntryColumns, req.TaskId, req.EntryId, req.UserId))
+		})
+		return makeTimeEntryResponse(entry, err, ErrCodeTimeEntryNotFound, "Time entry doesn't exist")
+	})
+}
+
+// Lists time entries of all users on the task, oldest first.
+func (s *server) ListTimeEntries(ctx context.Context, req *pb.GetTaskRequest) (*pb.TimeEntryListResponse, error) {
+	log.Printf("Handling ListTimeEntries")
+	makeError := func(code int32, msg string) (*pb.TimeEntryListResponse, error) {
+		return &pb.TimeEntryListResponse{
+			Response: &pb.TimeEntryListResponse_Error{
+				Error: &pb.Error{
+					Code:    code,
+					Message: msg,
+				},
+			},
+		}, nil
+	}
+	if code, msg := checkTaskRole(ctx, s.dbConn, req.TaskId, req.UserId, pb.TaskRole_Viewer); code != 0 {
+		return makeError(code, msg)
+	}
+	result, err := s.dbConn.Query(ctx, `SELECT `+timeEntryColumns+` FROM time_entries WHERE task_id=$1 ORDER BY spent_on, created_at, id`, req.TaskId)
+	if err != nil {
+		return makeError(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	defer result.Close()
+	entries := pb.TimeEntryList{}
+	for result.Next() {
+		entry, err := scanTimeEntry(result)
+		if err != nil {
+			return makeError(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		entries.Entries = append(entries.Entries, entry)
+	}
+	if err := result.Err(); err != nil {
+		return makeError(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return &pb.TimeEntryListResponse{
+		Response: &pb.TimeEntryListResponse_Entries{
+			Entries: &entries,
+		},
+	}, nil
+}
+
+func MakeTimeReportErrorResponse(id int32, msg string) (*pb.TimeReportResponse, error) {
+	return &pb.TimeReportResponse{
+		Response: &pb.TimeReportResponse_Error{
+			Error: &pb.Error{
+				Code:    id,
+				Message: msg,
+			},
+		},
+	}, nil
+}
+
+// Sums minutes of finished time entries on tasks visible to the user, grouped
+// by user, task or day. Report without groups has a single row with the total.
+func (s *server) GetTimeReport(ctx context.Context, req *pb.GetTimeReportRequest) (*pb.TimeReportResponse, error) {
+	log.Printf("Handling GetTimeReport")
+	args := []any{req.UserId}
+	arg := func(x any) string {
+		args = append(args, x)
+		return fmt.Sprintf("$%d", len(args))
+	}
+	conditions := []string{"e.minutes IS NOT NULL", `e.task_id IN (SELECT id FROM tasks WHERE deleted_at IS NULL AND ` + visibleTasksCondition + `)`}
+	if req.From != nil {
+		conditions = append(conditions, "e.spent_on>="+arg(*req.From)+"::TEXT::DATE")
+	}
+	if req.To != nil {
+		conditions = append(conditions, "e.spent_on<="+arg(*req.To)+"::TEXT::DATE")
+	}
+	if req.MemberId != nil {
+		conditions = append(conditions, "e.user_id="+arg(*req.MemberId))
+	}
+	if req.TaskId != nil {
+		conditions = append(conditions, "e.task_id="+arg(*req.TaskId))
+	}
+	if req.ProjectId != nil {
+		conditions = append(conditions, "t.project_id="+arg(*req.ProjectId))
+	}
+	columns := []string{"NULL::TEXT", "NULL::TEXT", "NULL::TEXT", "NULL::TEXT"}
+	for _, group := range req.GroupBy {
+		switch group {
+		case pb.TimeReportGroup_ByUser:
+			columns[0] = "e.user_id"
+		case pb.TimeReportGroup_ByTask:
+			columns[1], columns[2] = "e.task_id", "t.title"
+		case pb.TimeReportGroup_ByDay:
+			columns[3] = "to_char(e.spent_on, 'YYYY-MM-DD')"
+		}
+	}
+	groups := []string{}
+	for _, x := range columns {
+		if x != "NULL::TEXT" {
+			groups = append(groups, x)
+		}
+	}
+	query := `
+		SELECT
+			` + strings.Join(columns, ", ") + `, COALESCE(SUM(e.minutes), 0)
+		FROM
+			time_entries e JOIN tasks t ON t.id=e.task_id
+		WHERE
+			` + strings.Join(conditions, " AND ")
+	if len(groups) > 0 {
+		query += `
+		GROUP BY
+			` + strings.Join(groups, ", ")
+	}
+	query += `
+		ORDER BY
+			1, 4, 3, 2`
+	result, err := s.dbConn.Query(ctx, query, args...)
+	if err != nil {
+		return MakeTimeReportErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	defer result.Close()
+	report := pb.TimeReport{}
+	for result.Next() {
+		row := pb.TimeReportRow{}
+		if err := result.Scan(&row.UserId, &row.TaskId, &row.TaskTitle, &row.Day, &row.Minutes); err != nil {
+			return MakeTimeReportErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+		report.Rows = append(report.Rows, &row)
+		report.TotalMinutes += row.Minutes
+	}
+	if err := result.Err(); err != nil {
+		return MakeTimeReportErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return &pb.TimeReportResponse{
+		Response: &pb.TimeReportResponse_Report{
+			Report: &report,
+		},
+	}, nil
+}
+
+// Gap between positions of neighbouring tasks in a renumbered board column.
+const positionStep = 1024
+
+func (s *server) MoveTask(ctx context.Context, req *pb.MoveTaskRequest) (*pb.TaskResponse, error) {
+	log.Printf("Handling MoveTask")
+	return inTransaction(ctx, s.dbConn, MakeErrorResponse, func(db querier) (*pb.TaskResponse, error) {
+		return moveTask(ctx, db, req)
+	})
+}
+
+// Changes status of the task and places it in the board column of the new
+// status right after task AfterId, or on top of the column if it isn't set.
+// Status change is resolved from the workflow by user service.
+func moveTask(ctx context.Context, db querier, req *pb.MoveTaskRequest) (*pb.TaskResponse, error) {
+	if code, msg := checkTaskRole(ctx, db, req.TaskId, req.UserId, pb.TaskRole_Editor); code != 0 {
+		return MakeErrorResponse(code, msg)
+	}
+	old, err := scanTask(db.QueryRow(ctx, `SELECT `+taskColumns+` FROM tasks WHERE id=$1 FOR UPDATE`, req.TaskId))
+	if err != nil {
+		return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	if req.ExpectedVersion != nil && old.Version != *req.ExpectedVersion {
+		return MakeErrorResponse(ErrCodeVersion, "Task doesn't exist or its version has changed")
+	}
+	if req.ExpectedStatus != nil && old.Status != *req.ExpectedStatus {
+		return MakeErrorResponse(ErrCodeConflict, "Task doesn't exist or its status has changed")
+	}
+	status := old.Status
+	if req.NewStatus != nil {
+		status = *req.NewStatus
+	}
+	if req.CheckBlockers && status == pb.TaskStatus_Completed && old.Status != status && old.OpenBlockers > 0 {
+		return MakeErrorResponse(ErrCodeBlocked, "Task is blocked by unfinished tasks")
+	}
+	bumpDependents := func() error { return nil }
+	if status != old.Status {
+		if bumpDependents, err = lockDependentTasks(ctx, db, req.UserId, dependentTasksCondition, req.TaskId); err != nil {
+			return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+		}
+	}
+	position, code, msg := columnPosition(ctx, db, req.UserId, old, status, req.AfterId)
+	if code != 0 {
+		return MakeErrorResponse(code, msg)
+	}
+	task, err := scanTask(db.QueryRow(ctx, `
+		UPDATE
+			tasks
+		SET
+			status=$2,
+			position=$3,
+			version=version+1
+		WHERE
+			id=$1
+		RETURNING `+taskColumns,
+		req.TaskId, int32(status), position))
+	if err == nil {
+		err = recordChange(ctx, db, req.UserId, pb.TaskChangeAction_Updated, old, task)
+	}
+	if err == nil {
+		err = bumpDependents()
+	}
+	if err != nil {
+		return MakeErrorResponse(ErrCodeDatabase, fmt.Sprintf("Database error: %v", err))
+	}
+	return MakeTaskResponse(task)
+}
+
+// Tasks of the board column of status $1 in project $2, which is NULL for
+// tasks without project.
+const columnCondition = `status=$1 AND project_id IS NOT DISTINCT FROM $2 AND deleted_at IS NULL`
+
+// Finds position for the task in the board column of given status right after
+// task afterId, or on top of the column if afterId is nil. Columns are kept
+// per project of the task. Column is renumbered when there is no gap left
+// between the neighbours, renumbered tasks other than the task get new
+// versions. Returns error code and message if the position can't be found.
+func columnPosition(ctx context.Context, db querier, userId string, task *pb.Task, status pb.TaskStatus, afterId *string) (int64, int32, string) {
+	taskId := task.Id
+	if afterId == nil {
+		var top *int64
+		err := db.QueryRow(ctx, `SELECT MIN(position) FROM tasks WHERE `+columnCondition+` AND id<>$3`, int32(status), task.ProjectId, taskId).Scan(&top)
+		if err != nil {
+			return 0, ErrCodeDatabase, fmt.Sprintf("Database error: %v", err)
+		}
+		if top == nil {
+			return 0, 0, ""
+		}
+		return *top - positionStep, 0, ""
+	}
+	if *afterId == taskId {
+		return 0, ErrCodePosition, "Task can't be placed after itself"
+	}
+	for renumbered := false; ; renumbered = true {
+		var after int64
+		err := db.QueryRow(ctx, `SELECT position FROM tasks WHERE `+columnCondition+` AND id=$3`, int32(status), task.ProjectId, *afterId).Scan(&after)
+		if errors.Is(err, pgx.ErrNoRows) {
+			return 0, ErrCodePosition, "Task to place after isn't in the target column of the project"
+		}
+		if err != nil {
+			return 0, ErrCodeDatabase, fmt.Sprintf("Database error: %v", err)
+		}
+		var next *int64
+		err = db.QueryRow(ctx, `
+			SELECT
+				MIN(position)
+			FROM
+				tasks
+			WHERE
+				`+columnCondition+` AND id<>$3 AND position>$4`,
+			int32(status), task.ProjectId, taskId, after).Scan(&next)
+		if err != nil {
+			return 0, ErrCodeDatabase, fmt.Sprintf("Database error: %v", err)
+		}
+		// Bottom of the column is taken from the sequence like for new tasks
+		if next == nil {
+			var position int64
+			if err := db.QueryRow(ctx, `SELECT nextval('task_positions') * $1`, positionStep).Scan(&position); err != nil {
+				return 0, ErrCodeDatabase, fmt.Sprintf("Database error: %v", err)
+			}
+			return position, 0, ""
+		}
+		if *next-after > 1 || renumbered {
+			return after + (*next-after)/2, 0, ""
+		}
+		result, err := db.Query(ctx, `
+			UPDATE
+				tasks t
+			SET
+				position=c.n*$3,
+				version=version+1
+			FROM
+				(SELECT id AS task_id, ROW_NUMBER() OVER (ORDER BY position, id) AS n FROM tasks WHERE `+columnCondition+`) c
+			WHERE
+				t.id=c.task_id AND t.id<>$4 AND t.position<>c.n*$3
+			RETURNING `+taskColumns,
+			int32(status), task.ProjectId, positionStep, taskId)
+		if err != nil {
+			return 0, ErrCodeDatabase, fmt.Sprintf("Database error: %v", err)
+		}
+		var tasks []*pb.Task
+		for result.Next() {
+			x, err := scanTask(result)
+			if err != nil {
+				result.Close()
+				return 0, ErrCodeDatabase, fmt.Sprintf("Database error: %v", err)
+			}
+			tasks = append(tasks, x)
+		}
+		result.Close()
+		if err := result.Err(); err != nil {
+			return 0, ErrCodeDatabase, fmt.Sprintf("Database error: %v", err)
+		}
+		// Positions aren't part of history, so only new versions are recorded
+		for _, x := range tasks {
+			if err := recordChange(ctx, db, userId, pb.TaskChangeAction_Updated, x, x); err != nil {
+				return 0, ErrCodeDatabase, fmt.Sprintf("Database error: %v", err)
+			}
+		}
+	}
+}
+
 func main() {
 	var (
 		host     = flag.String("host", "", "gRPC host")
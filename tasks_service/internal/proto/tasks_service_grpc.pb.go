@@ -27,6 +27,60 @@ type TasksServiceClient interface {
 	UpdateTask(ctx context.Context, in *UpdateTaskRequest, opts ...grpc.CallOption) (*TaskResponse, error)
 	DeleteTask(ctx context.Context, in *DeleteTaskRequest, opts ...grpc.CallOption) (*TaskResponse, error)
 	GetTaskPage(ctx context.Context, in *GetTaskPageRequest, opts ...grpc.CallOption) (*TaskPageResponse, error)
+	GetTaskAccess(ctx context.Context, in *GetTaskAccessRequest, opts ...grpc.CallOption) (*TaskAccessResponse, error)
+	GrantTaskAccess(ctx context.Context, in *GrantTaskAccessRequest, opts ...grpc.CallOption) (*TaskAccessResponse, error)
+	RevokeTaskAccess(ctx context.Context, in *RevokeTaskAccessRequest, opts ...grpc.CallOption) (*TaskAccessResponse, error)
+	CreateComment(ctx context.Context, in *CreateCommentRequest, opts ...grpc.CallOption) (*CommentResponse, error)
+	UpdateComment(ctx context.Context, in *UpdateCommentRequest, opts ...grpc.CallOption) (*CommentResponse, error)
+	DeleteComment(ctx context.Context, in *DeleteCommentRequest, opts ...grpc.CallOption) (*CommentResponse, error)
+	GetCommentPage(ctx context.Context, in *GetCommentPageRequest, opts ...grpc.CallOption) (*CommentPageResponse, error)
+	SearchTasks(ctx context.Context, in *SearchTasksRequest, opts ...grpc.CallOption) (*SearchTasksResponse, error)
+	BatchTasks(ctx context.Context, in *BatchTasksRequest, opts ...grpc.CallOption) (*BatchTasksResponse, error)
+	GetTaskHistory(ctx context.Context, in *GetTaskHistoryRequest, opts ...grpc.CallOption) (*TaskHistoryResponse, error)
+	RestoreTaskVersion(ctx context.Context, in *RestoreTaskVersionRequest, opts ...grpc.CallOption) (*TaskResponse, error)
+	ListTrash(ctx context.Context, in *ListTrashRequest, opts ...grpc.CallOption) (*TrashResponse, error)
+	RestoreTask(ctx context.Context, in *RestoreTaskRequest, opts ...grpc.CallOption) (*TaskResponse, error)
+	PurgeTask(ctx context.Context, in *PurgeTaskRequest, opts ...grpc.CallOption) (*TaskResponse, error)
+	PurgeExpiredTasks(ctx context.Context, in *PurgeExpiredTasksRequest, opts ...grpc.CallOption) (*PurgeExpiredTasksResponse, error)
+	GetDueTasks(ctx context.Context, in *GetDueTasksRequest, opts ...grpc.CallOption) (*TaskPageResponse, error)
+	CreateLabel(ctx context.Context, in *CreateLabelRequest, opts ...grpc.CallOption) (*LabelResponse, error)
+	UpdateLabel(ctx context.Context, in *UpdateLabelRequest, opts ...grpc.CallOption) (*LabelResponse, error)
+	DeleteLabel(ctx context.Context, in *DeleteLabelRequest, opts ...grpc.CallOption) (*LabelResponse, error)
+	ListLabels(ctx context.Context, in *ListLabelsRequest, opts ...grpc.CallOption) (*LabelListResponse, error)
+	AddTaskLabel(ctx context.Context, in *TaskLabelRequest, opts ...grpc.CallOption) (*TaskResponse, error)
+	RemoveTaskLabel(ctx context.Context, in *TaskLabelRequest, opts ...grpc.CallOption) (*TaskResponse, error)
+	CreateProject(ctx context.Context, in *CreateProjectRequest, opts ...grpc.CallOption) (*ProjectResponse, error)
+	GetProject(ctx context.Context, in *GetProjectRequest, opts ...grpc.CallOption) (*ProjectResponse, error)
+	UpdateProject(ctx context.Context, in *UpdateProjectRequest, opts ...grpc.CallOption) (*ProjectResponse, error)
+	DeleteProject(ctx context.Context, in *DeleteProjectRequest, opts ...grpc.CallOption) (*ProjectResponse, error)
+	ListProjects(ctx context.Context, in *ListProjectsRequest, opts ...grpc.CallOption) (*ProjectListResponse, error)
+	GetProjectMembers(ctx context.Context, in *GetProjectRequest, opts ...grpc.CallOption) (*ProjectMembersResponse, error)
+	SetProjectMember(ctx context.Context, in *SetProjectMemberRequest, opts ...grpc.CallOption) (*ProjectMembersResponse, error)
+	RemoveProjectMember(ctx context.Context, in *RemoveProjectMemberRequest, opts ...grpc.CallOption) (*ProjectMembersResponse, error)
+	MoveTask(ctx context.Context, in *MoveTaskRequest, opts ...grpc.CallOption) (*TaskResponse, error)
+	SetTaskParent(ctx context.Context, in *SetTaskParentRequest, opts ...grpc.CallOption) (*TaskResponse, error)
+	GetTaskDependencies(ctx context.Context, in *GetTaskRequest, opts ...grpc.CallOption) (*TaskDependenciesResponse, error)
+	AddTaskDependency(ctx context.Context, in *TaskDependencyRequest, opts ...grpc.CallOption) (*TaskDependenciesResponse, error)
+	RemoveTaskDependency(ctx context.Context, in *TaskDependencyRequest, opts ...grpc.CallOption) (*TaskDependenciesResponse, error)
+	GetChecklist(ctx context.Context, in *GetTaskRequest, opts ...grpc.CallOption) (*ChecklistResponse, error)
+	AddChecklistItem(ctx context.Context, in *AddChecklistItemRequest, opts ...grpc.CallOption) (*ChecklistResponse, error)
+	UpdateChecklistItem(ctx context.Context, in *UpdateChecklistItemRequest, opts ...grpc.CallOption) (*ChecklistResponse, error)
+	MoveChecklistItem(ctx context.Context, in *MoveChecklistItemRequest, opts ...grpc.CallOption) (*ChecklistResponse, error)
+	DeleteChecklistItem(ctx context.Context, in *ChecklistItemRequest, opts ...grpc.CallOption) (*ChecklistResponse, error)
+	AddAttachment(ctx context.Context, in *AddAttachmentRequest, opts ...grpc.CallOption) (*AttachmentResponse, error)
+	ListAttachments(ctx context.Context, in *GetTaskRequest, opts ...grpc.CallOption) (*AttachmentListResponse, error)
+	GetAttachment(ctx context.Context, in *AttachmentRequest, opts ...grpc.CallOption) (*AttachmentResponse, error)
+	DeleteAttachment(ctx context.Context, in *AttachmentRequest, opts ...grpc.CallOption) (*AttachmentResponse, error)
+	ListDeletedAttachments(ctx context.Context, in *ListDeletedAttachmentsRequest, opts ...grpc.CallOption) (*DeletedAttachmentsResponse, error)
+	ForgetDeletedAttachments(ctx context.Context, in *ForgetDeletedAttachmentsRequest, opts ...grpc.CallOption) (*DeletedAttachmentsResponse, error)
+	StartTimer(ctx context.Context, in *GetTaskRequest, opts ...grpc.CallOption) (*TimeEntryResponse, error)
+	StopTimer(ctx context.Context, in *GetTaskRequest, opts ...grpc.CallOption) (*TimeEntryResponse, error)
+	GetRunningTimer(ctx context.Context, in *GetRunningTimerRequest, opts ...grpc.CallOption) (*TimeEntryResponse, error)
+	AddTimeEntry(ctx context.Context, in *AddTimeEntryRequest, opts ...grpc.CallOption) (*TimeEntryResponse, error)
+	UpdateTimeEntry(ctx context.Context, in *UpdateTimeEntryRequest, opts ...grpc.CallOption) (*TimeEntryResponse, error)
+	DeleteTimeEntry(ctx context.Context, in *TimeEntryRequest, opts ...grpc.CallOption) (*TimeEntryResponse, error)
+	ListTimeEntries(ctx context.Context, in *GetTaskRequest, opts ...grpc.CallOption) (*TimeEntryListResponse, error)
+	GetTimeReport(ctx context.Context, in *GetTimeReportRequest, opts ...grpc.CallOption) (*TimeReportResponse, error)
 }
 
 type tasksServiceClient struct {
@@ -82,6 +136,492 @@ func (c *tasksServiceClient) GetTaskPage(ctx context.Context, in *GetTaskPageReq
 	return out, nil
 }
 
+func (c *tasksServiceClient) GetTaskAccess(ctx context.Context, in *GetTaskAccessRequest, opts ...grpc.CallOption) (*TaskAccessResponse, error) {
+	out := new(TaskAccessResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/GetTaskAccess", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) GrantTaskAccess(ctx context.Context, in *GrantTaskAccessRequest, opts ...grpc.CallOption) (*TaskAccessResponse, error) {
+	out := new(TaskAccessResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/GrantTaskAccess", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) RevokeTaskAccess(ctx context.Context, in *RevokeTaskAccessRequest, opts ...grpc.CallOption) (*TaskAccessResponse, error) {
+	out := new(TaskAccessResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/RevokeTaskAccess", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) CreateComment(ctx context.Context, in *CreateCommentRequest, opts ...grpc.CallOption) (*CommentResponse, error) {
+	out := new(CommentResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/CreateComment", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) UpdateComment(ctx context.Context, in *UpdateCommentRequest, opts ...grpc.CallOption) (*CommentResponse, error) {
+	out := new(CommentResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/UpdateComment", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) DeleteComment(ctx context.Context, in *DeleteCommentRequest, opts ...grpc.CallOption) (*CommentResponse, error) {
+	out := new(CommentResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/DeleteComment", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) GetCommentPage(ctx context.Context, in *GetCommentPageRequest, opts ...grpc.CallOption) (*CommentPageResponse, error) {
+	out := new(CommentPageResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/GetCommentPage", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) SearchTasks(ctx context.Context, in *SearchTasksRequest, opts ...grpc.CallOption) (*SearchTasksResponse, error) {
+	out := new(SearchTasksResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/SearchTasks", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) BatchTasks(ctx context.Context, in *BatchTasksRequest, opts ...grpc.CallOption) (*BatchTasksResponse, error) {
+	out := new(BatchTasksResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/BatchTasks", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) GetTaskHistory(ctx context.Context, in *GetTaskHistoryRequest, opts ...grpc.CallOption) (*TaskHistoryResponse, error) {
+	out := new(TaskHistoryResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/GetTaskHistory", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) RestoreTaskVersion(ctx context.Context, in *RestoreTaskVersionRequest, opts ...grpc.CallOption) (*TaskResponse, error) {
+	out := new(TaskResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/RestoreTaskVersion", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) ListTrash(ctx context.Context, in *ListTrashRequest, opts ...grpc.CallOption) (*TrashResponse, error) {
+	out := new(TrashResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/ListTrash", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) RestoreTask(ctx context.Context, in *RestoreTaskRequest, opts ...grpc.CallOption) (*TaskResponse, error) {
+	out := new(TaskResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/RestoreTask", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) PurgeTask(ctx context.Context, in *PurgeTaskRequest, opts ...grpc.CallOption) (*TaskResponse, error) {
+	out := new(TaskResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/PurgeTask", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) PurgeExpiredTasks(ctx context.Context, in *PurgeExpiredTasksRequest, opts ...grpc.CallOption) (*PurgeExpiredTasksResponse, error) {
+	out := new(PurgeExpiredTasksResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/PurgeExpiredTasks", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) GetDueTasks(ctx context.Context, in *GetDueTasksRequest, opts ...grpc.CallOption) (*TaskPageResponse, error) {
+	out := new(TaskPageResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/GetDueTasks", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) CreateLabel(ctx context.Context, in *CreateLabelRequest, opts ...grpc.CallOption) (*LabelResponse, error) {
+	out := new(LabelResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/CreateLabel", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) UpdateLabel(ctx context.Context, in *UpdateLabelRequest, opts ...grpc.CallOption) (*LabelResponse, error) {
+	out := new(LabelResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/UpdateLabel", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) DeleteLabel(ctx context.Context, in *DeleteLabelRequest, opts ...grpc.CallOption) (*LabelResponse, error) {
+	out := new(LabelResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/DeleteLabel", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) ListLabels(ctx context.Context, in *ListLabelsRequest, opts ...grpc.CallOption) (*LabelListResponse, error) {
+	out := new(LabelListResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/ListLabels", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) AddTaskLabel(ctx context.Context, in *TaskLabelRequest, opts ...grpc.CallOption) (*TaskResponse, error) {
+	out := new(TaskResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/AddTaskLabel", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) RemoveTaskLabel(ctx context.Context, in *TaskLabelRequest, opts ...grpc.CallOption) (*TaskResponse, error) {
+	out := new(TaskResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/RemoveTaskLabel", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) CreateProject(ctx context.Context, in *CreateProjectRequest, opts ...grpc.CallOption) (*ProjectResponse, error) {
+	out := new(ProjectResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/CreateProject", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) GetProject(ctx context.Context, in *GetProjectRequest, opts ...grpc.CallOption) (*ProjectResponse, error) {
+	out := new(ProjectResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/GetProject", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) UpdateProject(ctx context.Context, in *UpdateProjectRequest, opts ...grpc.CallOption) (*ProjectResponse, error) {
+	out := new(ProjectResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/UpdateProject", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) DeleteProject(ctx context.Context, in *DeleteProjectRequest, opts ...grpc.CallOption) (*ProjectResponse, error) {
+	out := new(ProjectResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/DeleteProject", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) ListProjects(ctx context.Context, in *ListProjectsRequest, opts ...grpc.CallOption) (*ProjectListResponse, error) {
+	out := new(ProjectListResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/ListProjects", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) GetProjectMembers(ctx context.Context, in *GetProjectRequest, opts ...grpc.CallOption) (*ProjectMembersResponse, error) {
+	out := new(ProjectMembersResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/GetProjectMembers", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) SetProjectMember(ctx context.Context, in *SetProjectMemberRequest, opts ...grpc.CallOption) (*ProjectMembersResponse, error) {
+	out := new(ProjectMembersResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/SetProjectMember", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) RemoveProjectMember(ctx context.Context, in *RemoveProjectMemberRequest, opts ...grpc.CallOption) (*ProjectMembersResponse, error) {
+	out := new(ProjectMembersResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/RemoveProjectMember", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) MoveTask(ctx context.Context, in *MoveTaskRequest, opts ...grpc.CallOption) (*TaskResponse, error) {
+	out := new(TaskResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/MoveTask", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) SetTaskParent(ctx context.Context, in *SetTaskParentRequest, opts ...grpc.CallOption) (*TaskResponse, error) {
+	out := new(TaskResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/SetTaskParent", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) GetTaskDependencies(ctx context.Context, in *GetTaskRequest, opts ...grpc.CallOption) (*TaskDependenciesResponse, error) {
+	out := new(TaskDependenciesResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/GetTaskDependencies", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) AddTaskDependency(ctx context.Context, in *TaskDependencyRequest, opts ...grpc.CallOption) (*TaskDependenciesResponse, error) {
+	out := new(TaskDependenciesResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/AddTaskDependency", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) RemoveTaskDependency(ctx context.Context, in *TaskDependencyRequest, opts ...grpc.CallOption) (*TaskDependenciesResponse, error) {
+	out := new(TaskDependenciesResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/RemoveTaskDependency", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) GetChecklist(ctx context.Context, in *GetTaskRequest, opts ...grpc.CallOption) (*ChecklistResponse, error) {
+	out := new(ChecklistResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/GetChecklist", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) AddChecklistItem(ctx context.Context, in *AddChecklistItemRequest, opts ...grpc.CallOption) (*ChecklistResponse, error) {
+	out := new(ChecklistResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/AddChecklistItem", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) UpdateChecklistItem(ctx context.Context, in *UpdateChecklistItemRequest, opts ...grpc.CallOption) (*ChecklistResponse, error) {
+	out := new(ChecklistResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/UpdateChecklistItem", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) MoveChecklistItem(ctx context.Context, in *MoveChecklistItemRequest, opts ...grpc.CallOption) (*ChecklistResponse, error) {
+	out := new(ChecklistResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/MoveChecklistItem", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) DeleteChecklistItem(ctx context.Context, in *ChecklistItemRequest, opts ...grpc.CallOption) (*ChecklistResponse, error) {
+	out := new(ChecklistResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/DeleteChecklistItem", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) AddAttachment(ctx context.Context, in *AddAttachmentRequest, opts ...grpc.CallOption) (*AttachmentResponse, error) {
+	out := new(AttachmentResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/AddAttachment", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) ListAttachments(ctx context.Context, in *GetTaskRequest, opts ...grpc.CallOption) (*AttachmentListResponse, error) {
+	out := new(AttachmentListResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/ListAttachments", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) GetAttachment(ctx context.Context, in *AttachmentRequest, opts ...grpc.CallOption) (*AttachmentResponse, error) {
+	out := new(AttachmentResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/GetAttachment", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) DeleteAttachment(ctx context.Context, in *AttachmentRequest, opts ...grpc.CallOption) (*AttachmentResponse, error) {
+	out := new(AttachmentResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/DeleteAttachment", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) ListDeletedAttachments(ctx context.Context, in *ListDeletedAttachmentsRequest, opts ...grpc.CallOption) (*DeletedAttachmentsResponse, error) {
+	out := new(DeletedAttachmentsResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/ListDeletedAttachments", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) ForgetDeletedAttachments(ctx context.Context, in *ForgetDeletedAttachmentsRequest, opts ...grpc.CallOption) (*DeletedAttachmentsResponse, error) {
+	out := new(DeletedAttachmentsResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/ForgetDeletedAttachments", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) StartTimer(ctx context.Context, in *GetTaskRequest, opts ...grpc.CallOption) (*TimeEntryResponse, error) {
+	out := new(TimeEntryResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/StartTimer", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) StopTimer(ctx context.Context, in *GetTaskRequest, opts ...grpc.CallOption) (*TimeEntryResponse, error) {
+	out := new(TimeEntryResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/StopTimer", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) GetRunningTimer(ctx context.Context, in *GetRunningTimerRequest, opts ...grpc.CallOption) (*TimeEntryResponse, error) {
+	out := new(TimeEntryResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/GetRunningTimer", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) AddTimeEntry(ctx context.Context, in *AddTimeEntryRequest, opts ...grpc.CallOption) (*TimeEntryResponse, error) {
+	out := new(TimeEntryResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/AddTimeEntry", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) UpdateTimeEntry(ctx context.Context, in *UpdateTimeEntryRequest, opts ...grpc.CallOption) (*TimeEntryResponse, error) {
+	out := new(TimeEntryResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/UpdateTimeEntry", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) DeleteTimeEntry(ctx context.Context, in *TimeEntryRequest, opts ...grpc.CallOption) (*TimeEntryResponse, error) {
+	out := new(TimeEntryResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/DeleteTimeEntry", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) ListTimeEntries(ctx context.Context, in *GetTaskRequest, opts ...grpc.CallOption) (*TimeEntryListResponse, error) {
+	out := new(TimeEntryListResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/ListTimeEntries", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *tasksServiceClient) GetTimeReport(ctx context.Context, in *GetTimeReportRequest, opts ...grpc.CallOption) (*TimeReportResponse, error) {
+	out := new(TimeReportResponse)
+	err := c.cc.Invoke(ctx, "/tasks_service.TasksService/GetTimeReport", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 // TasksServiceServer is the server API for TasksService service.
 // All implementations must embed UnimplementedTasksServiceServer
 // for forward compatibility
@@ -91,127 +631,1315 @@ type TasksServiceServer interface {
 	UpdateTask(context.Context, *UpdateTaskRequest) (*TaskResponse, error)
 	DeleteTask(context.Context, *DeleteTaskRequest) (*TaskResponse, error)
 	GetTaskPage(context.Context, *GetTaskPageRequest) (*TaskPageResponse, error)
+	GetTaskAccess(context.Context, *GetTaskAccessRequest) (*TaskAccessResponse, error)
+	GrantTaskAccess(context.Context, *GrantTaskAccessRequest) (*TaskAccessResponse, error)
+	RevokeTaskAccess(context.Context, *RevokeTaskAccessRequest) (*TaskAccessResponse, error)
+	CreateComment(context.Context, *CreateCommentRequest) (*CommentResponse, error)
+	UpdateComment(context.Context, *UpdateCommentRequest) (*CommentResponse, error)
+	DeleteComment(context.Context, *DeleteCommentRequest) (*CommentResponse, error)
+	GetCommentPage(context.Context, *GetCommentPageRequest) (*CommentPageResponse, error)
+	SearchTasks(context.Context, *SearchTasksRequest) (*SearchTasksResponse, error)
+	BatchTasks(context.Context, *BatchTasksRequest) (*BatchTasksResponse, error)
+	GetTaskHistory(context.Context, *GetTaskHistoryRequest) (*TaskHistoryResponse, error)
+	RestoreTaskVersion(context.Context, *RestoreTaskVersionRequest) (*TaskResponse, error)
+	ListTrash(context.Context, *ListTrashRequest) (*TrashResponse, error)
+	RestoreTask(context.Context, *RestoreTaskRequest) (*TaskResponse, error)
+	PurgeTask(context.Context, *PurgeTaskRequest) (*TaskResponse, error)
+	PurgeExpiredTasks(context.Context, *PurgeExpiredTasksRequest) (*PurgeExpiredTasksResponse, error)
+	GetDueTasks(context.Context, *GetDueTasksRequest) (*TaskPageResponse, error)
+	CreateLabel(context.Context, *CreateLabelRequest) (*LabelResponse, error)
+	UpdateLabel(context.Context, *UpdateLabelRequest) (*LabelResponse, error)
+	DeleteLabel(context.Context, *DeleteLabelRequest) (*LabelResponse, error)
+	ListLabels(context.Context, *ListLabelsRequest) (*LabelListResponse, error)
+	AddTaskLabel(context.Context, *TaskLabelRequest) (*TaskResponse, error)
+	RemoveTaskLabel(context.Context, *TaskLabelRequest) (*TaskResponse, error)
+	CreateProject(context.Context, *CreateProjectRequest) (*ProjectResponse, error)
+	GetProject(context.Context, *GetProjectRequest) (*ProjectResponse, error)
+	UpdateProject(context.Context, *UpdateProjectRequest) (*ProjectResponse, error)
+	DeleteProject(context.Context, *DeleteProjectRequest) (*ProjectResponse, error)
+	ListProjects(context.Context, *ListProjectsRequest) (*ProjectListResponse, error)
+	GetProjectMembers(context.Context, *GetProjectRequest) (*ProjectMembersResponse, error)
+	SetProjectMember(context.Context, *SetProjectMemberRequest) (*ProjectMembersResponse, error)
+	RemoveProjectMember(context.Context, *RemoveProjectMemberRequest) (*ProjectMembersResponse, error)
+	MoveTask(context.Context, *MoveTaskRequest) (*TaskResponse, error)
+	SetTaskParent(context.Context, *SetTaskParentRequest) (*TaskResponse, error)
+	GetTaskDependencies(context.Context, *GetTaskRequest) (*TaskDependenciesResponse, error)
+	AddTaskDependency(context.Context, *TaskDependencyRequest) (*TaskDependenciesResponse, error)
+	RemoveTaskDependency(context.Context, *TaskDependencyRequest) (*TaskDependenciesResponse, error)
+	GetChecklist(context.Context, *GetTaskRequest) (*ChecklistResponse, error)
+	AddChecklistItem(context.Context, *AddChecklistItemRequest) (*ChecklistResponse, error)
+	UpdateChecklistItem(context.Context, *UpdateChecklistItemRequest) (*ChecklistResponse, error)
+	MoveChecklistItem(context.Context, *MoveChecklistItemRequest) (*ChecklistResponse, error)
+	DeleteChecklistItem(context.Context, *ChecklistItemRequest) (*ChecklistResponse, error)
+	AddAttachment(context.Context, *AddAttachmentRequest) (*AttachmentResponse, error)
+	ListAttachments(context.Context, *GetTaskRequest) (*AttachmentListResponse, error)
+	GetAttachment(context.Context, *AttachmentRequest) (*AttachmentResponse, error)
+	DeleteAttachment(context.Context, *AttachmentRequest) (*AttachmentResponse, error)
+	ListDeletedAttachments(context.Context, *ListDeletedAttachmentsRequest) (*DeletedAttachmentsResponse, error)
+	ForgetDeletedAttachments(context.Context, *ForgetDeletedAttachmentsRequest) (*DeletedAttachmentsResponse, error)
+	StartTimer(context.Context, *GetTaskRequest) (*TimeEntryResponse, error)
+	StopTimer(context.Context, *GetTaskRequest) (*TimeEntryResponse, error)
+	GetRunningTimer(context.Context, *GetRunningTimerRequest) (*TimeEntryResponse, error)
+	AddTimeEntry(context.Context, *AddTimeEntryRequest) (*TimeEntryResponse, error)
+	UpdateTimeEntry(context.Context, *UpdateTimeEntryRequest) (*TimeEntryResponse, error)
+	DeleteTimeEntry(context.Context, *TimeEntryRequest) (*TimeEntryResponse, error)
+	ListTimeEntries(context.Context, *GetTaskRequest) (*TimeEntryListResponse, error)
+	GetTimeReport(context.Context, *GetTimeReportRequest) (*TimeReportResponse, error)
+	mustEmbedUnimplementedTasksServiceServer()
+}
+
+// UnimplementedTasksServiceServer must be embedded to have forward compatible implementations.
+type UnimplementedTasksServiceServer struct {
+}
+
+func (UnimplementedTasksServiceServer) CreateTask(context.Context, *CreateTaskRequest) (*TaskResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method CreateTask not implemented")
+}
+func (UnimplementedTasksServiceServer) GetTask(context.Context, *GetTaskRequest) (*TaskResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetTask not implemented")
+}
+func (UnimplementedTasksServiceServer) UpdateTask(context.Context, *UpdateTaskRequest) (*TaskResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method UpdateTask not implemented")
+}
+func (UnimplementedTasksServiceServer) DeleteTask(context.Context, *DeleteTaskRequest) (*TaskResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method DeleteTask not implemented")
+}
+func (UnimplementedTasksServiceServer) GetTaskPage(context.Context, *GetTaskPageRequest) (*TaskPageResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetTaskPage not implemented")
+}
+func (UnimplementedTasksServiceServer) GetTaskAccess(context.Context, *GetTaskAccessRequest) (*TaskAccessResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetTaskAccess not implemented")
+}
+func (UnimplementedTasksServiceServer) GrantTaskAccess(context.Context, *GrantTaskAccessRequest) (*TaskAccessResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GrantTaskAccess not implemented")
+}
+func (UnimplementedTasksServiceServer) RevokeTaskAccess(context.Context, *RevokeTaskAccessRequest) (*TaskAccessResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method RevokeTaskAccess not implemented")
+}
+func (UnimplementedTasksServiceServer) CreateComment(context.Context, *CreateCommentRequest) (*CommentResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method CreateComment not implemented")
+}
+func (UnimplementedTasksServiceServer) UpdateComment(context.Context, *UpdateCommentRequest) (*CommentResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method UpdateComment not implemented")
+}
+func (UnimplementedTasksServiceServer) DeleteComment(context.Context, *DeleteCommentRequest) (*CommentResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method DeleteComment not implemented")
+}
+func (UnimplementedTasksServiceServer) GetCommentPage(context.Context, *GetCommentPageRequest) (*CommentPageResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetCommentPage not implemented")
+}
+func (UnimplementedTasksServiceServer) SearchTasks(context.Context, *SearchTasksRequest) (*SearchTasksResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method SearchTasks not implemented")
+}
+func (UnimplementedTasksServiceServer) BatchTasks(context.Context, *BatchTasksRequest) (*BatchTasksResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method BatchTasks not implemented")
+}
+func (UnimplementedTasksServiceServer) GetTaskHistory(context.Context, *GetTaskHistoryRequest) (*TaskHistoryResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetTaskHistory not implemented")
+}
+func (UnimplementedTasksServiceServer) RestoreTaskVersion(context.Context, *RestoreTaskVersionRequest) (*TaskResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method RestoreTaskVersion not implemented")
+}
+func (UnimplementedTasksServiceServer) ListTrash(context.Context, *ListTrashRequest) (*TrashResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListTrash not implemented")
+}
+func (UnimplementedTasksServiceServer) RestoreTask(context.Context, *RestoreTaskRequest) (*TaskResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method RestoreTask not implemented")
+}
+func (UnimplementedTasksServiceServer) PurgeTask(context.Context, *PurgeTaskRequest) (*TaskResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method PurgeTask not implemented")
+}
+func (UnimplementedTasksServiceServer) PurgeExpiredTasks(context.Context, *PurgeExpiredTasksRequest) (*PurgeExpiredTasksResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method PurgeExpiredTasks not implemented")
+}
+func (UnimplementedTasksServiceServer) GetDueTasks(context.Context, *GetDueTasksRequest) (*TaskPageResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetDueTasks not implemented")
+}
+func (UnimplementedTasksServiceServer) CreateLabel(context.Context, *CreateLabelRequest) (*LabelResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method CreateLabel not implemented")
+}
+func (UnimplementedTasksServiceServer) UpdateLabel(context.Context, *UpdateLabelRequest) (*LabelResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method UpdateLabel not implemented")
+}
+func (UnimplementedTasksServiceServer) DeleteLabel(context.Context, *DeleteLabelRequest) (*LabelResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method DeleteLabel not implemented")
+}
+func (UnimplementedTasksServiceServer) ListLabels(context.Context, *ListLabelsRequest) (*LabelListResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListLabels not implemented")
+}
+func (UnimplementedTasksServiceServer) AddTaskLabel(context.Context, *TaskLabelRequest) (*TaskResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method AddTaskLabel not implemented")
+}
+func (UnimplementedTasksServiceServer) RemoveTaskLabel(context.Context, *TaskLabelRequest) (*TaskResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method RemoveTaskLabel not implemented")
+}
+func (UnimplementedTasksServiceServer) CreateProject(context.Context, *CreateProjectRequest) (*ProjectResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method CreateProject not implemented")
+}
+func (UnimplementedTasksServiceServer) GetProject(context.Context, *GetProjectRequest) (*ProjectResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetProject not implemented")
+}
+func (UnimplementedTasksServiceServer) UpdateProject(context.Context, *UpdateProjectRequest) (*ProjectResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method UpdateProject not implemented")
+}
+func (UnimplementedTasksServiceServer) DeleteProject(context.Context, *DeleteProjectRequest) (*ProjectResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method DeleteProject not implemented")
+}
+func (UnimplementedTasksServiceServer) ListProjects(context.Context, *ListProjectsRequest) (*ProjectListResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListProjects not implemented")
+}
+func (UnimplementedTasksServiceServer) GetProjectMembers(context.Context, *GetProjectRequest) (*ProjectMembersResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetProjectMembers not implemented")
+}
+func (UnimplementedTasksServiceServer) SetProjectMember(context.Context, *SetProjectMemberRequest) (*ProjectMembersResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method SetProjectMember not implemented")
+}
+func (UnimplementedTasksServiceServer) RemoveProjectMember(context.Context, *RemoveProjectMemberRequest) (*ProjectMembersResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method RemoveProjectMember not implemented")
+}
+func (UnimplementedTasksServiceServer) MoveTask(context.Context, *MoveTaskRequest) (*TaskResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method MoveTask not implemented")
+}
+func (UnimplementedTasksServiceServer) SetTaskParent(context.Context, *SetTaskParentRequest) (*TaskResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method SetTaskParent not implemented")
+}
+func (UnimplementedTasksServiceServer) GetTaskDependencies(context.Context, *GetTaskRequest) (*TaskDependenciesResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetTaskDependencies not implemented")
+}
+func (UnimplementedTasksServiceServer) AddTaskDependency(context.Context, *TaskDependencyRequest) (*TaskDependenciesResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method AddTaskDependency not implemented")
+}
+func (UnimplementedTasksServiceServer) RemoveTaskDependency(context.Context, *TaskDependencyRequest) (*TaskDependenciesResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method RemoveTaskDependency not implemented")
+}
+func (UnimplementedTasksServiceServer) GetChecklist(context.Context, *GetTaskRequest) (*ChecklistResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetChecklist not implemented")
+}
+func (UnimplementedTasksServiceServer) AddChecklistItem(context.Context, *AddChecklistItemRequest) (*ChecklistResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method AddChecklistItem not implemented")
+}
+func (UnimplementedTasksServiceServer) UpdateChecklistItem(context.Context, *UpdateChecklistItemRequest) (*ChecklistResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method UpdateChecklistItem not implemented")
+}
+func (UnimplementedTasksServiceServer) MoveChecklistItem(context.Context, *MoveChecklistItemRequest) (*ChecklistResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method MoveChecklistItem not implemented")
+}
+func (UnimplementedTasksServiceServer) DeleteChecklistItem(context.Context, *ChecklistItemRequest) (*ChecklistResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method DeleteChecklistItem not implemented")
+}
+func (UnimplementedTasksServiceServer) AddAttachment(context.Context, *AddAttachmentRequest) (*AttachmentResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method AddAttachment not implemented")
+}
+func (UnimplementedTasksServiceServer) ListAttachments(context.Context, *GetTaskRequest) (*AttachmentListResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListAttachments not implemented")
+}
+func (UnimplementedTasksServiceServer) GetAttachment(context.Context, *AttachmentRequest) (*AttachmentResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetAttachment not implemented")
+}
+func (UnimplementedTasksServiceServer) DeleteAttachment(context.Context, *AttachmentRequest) (*AttachmentResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method DeleteAttachment not implemented")
+}
+func (UnimplementedTasksServiceServer) ListDeletedAttachments(context.Context, *ListDeletedAttachmentsRequest) (*DeletedAttachmentsResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListDeletedAttachments not implemented")
+}
+func (UnimplementedTasksServiceServer) ForgetDeletedAttachments(context.Context, *ForgetDeletedAttachmentsRequest) (*DeletedAttachmentsResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ForgetDeletedAttachments not implemented")
+}
+func (UnimplementedTasksServiceServer) StartTimer(context.Context, *GetTaskRequest) (*TimeEntryResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method StartTimer not implemented")
+}
+func (UnimplementedTasksServiceServer) StopTimer(context.Context, *GetTaskRequest) (*TimeEntryResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method StopTimer not implemented")
+}
+func (UnimplementedTasksServiceServer) GetRunningTimer(context.Context, *GetRunningTimerRequest) (*TimeEntryResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetRunningTimer not implemented")
+}
+func (UnimplementedTasksServiceServer) AddTimeEntry(context.Context, *AddTimeEntryRequest) (*TimeEntryResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method AddTimeEntry not implemented")
+}
+func (UnimplementedTasksServiceServer) UpdateTimeEntry(context.Context, *UpdateTimeEntryRequest) (*TimeEntryResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method UpdateTimeEntry not implemented")
+}
+func (UnimplementedTasksServiceServer) DeleteTimeEntry(context.Context, *TimeEntryRequest) (*TimeEntryResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method DeleteTimeEntry not implemented")
+}
+func (UnimplementedTasksServiceServer) ListTimeEntries(context.Context, *GetTaskRequest) (*TimeEntryListResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListTimeEntries not implemented")
+}
+func (UnimplementedTasksServiceServer) GetTimeReport(context.Context, *GetTimeReportRequest) (*TimeReportResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetTimeReport not implemented")
+}
+func (UnimplementedTasksServiceServer) mustEmbedUnimplementedTasksServiceServer() {}
+
+// UnsafeTasksServiceServer may be embedded to opt out of forward compatibility for this service.
+// Use of this interface is not recommended, as added methods to TasksServiceServer will
+// result in compilation errors.
+type UnsafeTasksServiceServer interface {
 	mustEmbedUnimplementedTasksServiceServer()
 }
 
-// UnimplementedTasksServiceServer must be embedded to have forward compatible implementations.
-type UnimplementedTasksServiceServer struct {
+func RegisterTasksServiceServer(s grpc.ServiceRegistrar, srv TasksServiceServer) {
+	s.RegisterService(&TasksService_ServiceDesc, srv)
+}
+
+func _TasksService_CreateTask_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(CreateTaskRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).CreateTask(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/CreateTask",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).CreateTask(ctx, req.(*CreateTaskRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_GetTask_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetTaskRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).GetTask(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/GetTask",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).GetTask(ctx, req.(*GetTaskRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_UpdateTask_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(UpdateTaskRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).UpdateTask(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/UpdateTask",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).UpdateTask(ctx, req.(*UpdateTaskRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_DeleteTask_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(DeleteTaskRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).DeleteTask(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/DeleteTask",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).DeleteTask(ctx, req.(*DeleteTaskRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_GetTaskPage_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetTaskPageRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).GetTaskPage(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/GetTaskPage",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).GetTaskPage(ctx, req.(*GetTaskPageRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_GetTaskAccess_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetTaskAccessRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).GetTaskAccess(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/GetTaskAccess",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).GetTaskAccess(ctx, req.(*GetTaskAccessRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_GrantTaskAccess_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GrantTaskAccessRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).GrantTaskAccess(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/GrantTaskAccess",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).GrantTaskAccess(ctx, req.(*GrantTaskAccessRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_RevokeTaskAccess_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(RevokeTaskAccessRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).RevokeTaskAccess(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/RevokeTaskAccess",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).RevokeTaskAccess(ctx, req.(*RevokeTaskAccessRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_CreateComment_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(CreateCommentRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).CreateComment(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/CreateComment",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).CreateComment(ctx, req.(*CreateCommentRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_UpdateComment_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(UpdateCommentRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).UpdateComment(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/UpdateComment",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).UpdateComment(ctx, req.(*UpdateCommentRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_DeleteComment_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(DeleteCommentRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).DeleteComment(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/DeleteComment",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).DeleteComment(ctx, req.(*DeleteCommentRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_GetCommentPage_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetCommentPageRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).GetCommentPage(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/GetCommentPage",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).GetCommentPage(ctx, req.(*GetCommentPageRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_SearchTasks_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(SearchTasksRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).SearchTasks(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/SearchTasks",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).SearchTasks(ctx, req.(*SearchTasksRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_BatchTasks_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(BatchTasksRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).BatchTasks(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/BatchTasks",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).BatchTasks(ctx, req.(*BatchTasksRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_GetTaskHistory_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetTaskHistoryRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).GetTaskHistory(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/GetTaskHistory",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).GetTaskHistory(ctx, req.(*GetTaskHistoryRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_RestoreTaskVersion_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(RestoreTaskVersionRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).RestoreTaskVersion(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/RestoreTaskVersion",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).RestoreTaskVersion(ctx, req.(*RestoreTaskVersionRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_ListTrash_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListTrashRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).ListTrash(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/ListTrash",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).ListTrash(ctx, req.(*ListTrashRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_RestoreTask_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(RestoreTaskRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).RestoreTask(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/RestoreTask",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).RestoreTask(ctx, req.(*RestoreTaskRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_PurgeTask_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(PurgeTaskRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).PurgeTask(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/PurgeTask",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).PurgeTask(ctx, req.(*PurgeTaskRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_PurgeExpiredTasks_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(PurgeExpiredTasksRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).PurgeExpiredTasks(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/PurgeExpiredTasks",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).PurgeExpiredTasks(ctx, req.(*PurgeExpiredTasksRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_GetDueTasks_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetDueTasksRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).GetDueTasks(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/GetDueTasks",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).GetDueTasks(ctx, req.(*GetDueTasksRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_CreateLabel_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(CreateLabelRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).CreateLabel(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/CreateLabel",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).CreateLabel(ctx, req.(*CreateLabelRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_UpdateLabel_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(UpdateLabelRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).UpdateLabel(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/UpdateLabel",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).UpdateLabel(ctx, req.(*UpdateLabelRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_DeleteLabel_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(DeleteLabelRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).DeleteLabel(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/DeleteLabel",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).DeleteLabel(ctx, req.(*DeleteLabelRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_ListLabels_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListLabelsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).ListLabels(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/ListLabels",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).ListLabels(ctx, req.(*ListLabelsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_AddTaskLabel_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(TaskLabelRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).AddTaskLabel(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/AddTaskLabel",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).AddTaskLabel(ctx, req.(*TaskLabelRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_RemoveTaskLabel_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(TaskLabelRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).RemoveTaskLabel(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/RemoveTaskLabel",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).RemoveTaskLabel(ctx, req.(*TaskLabelRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_CreateProject_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(CreateProjectRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).CreateProject(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/CreateProject",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).CreateProject(ctx, req.(*CreateProjectRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_GetProject_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetProjectRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).GetProject(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/GetProject",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).GetProject(ctx, req.(*GetProjectRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_UpdateProject_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(UpdateProjectRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).UpdateProject(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/UpdateProject",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).UpdateProject(ctx, req.(*UpdateProjectRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_DeleteProject_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(DeleteProjectRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).DeleteProject(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/DeleteProject",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).DeleteProject(ctx, req.(*DeleteProjectRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_ListProjects_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListProjectsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).ListProjects(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/ListProjects",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).ListProjects(ctx, req.(*ListProjectsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_GetProjectMembers_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetProjectRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).GetProjectMembers(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/GetProjectMembers",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).GetProjectMembers(ctx, req.(*GetProjectRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_SetProjectMember_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(SetProjectMemberRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).SetProjectMember(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/SetProjectMember",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).SetProjectMember(ctx, req.(*SetProjectMemberRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_RemoveProjectMember_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(RemoveProjectMemberRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).RemoveProjectMember(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/RemoveProjectMember",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).RemoveProjectMember(ctx, req.(*RemoveProjectMemberRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_MoveTask_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(MoveTaskRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).MoveTask(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/MoveTask",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).MoveTask(ctx, req.(*MoveTaskRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_SetTaskParent_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(SetTaskParentRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).SetTaskParent(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/SetTaskParent",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).SetTaskParent(ctx, req.(*SetTaskParentRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_GetTaskDependencies_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetTaskRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).GetTaskDependencies(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/GetTaskDependencies",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).GetTaskDependencies(ctx, req.(*GetTaskRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_AddTaskDependency_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(TaskDependencyRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).AddTaskDependency(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/AddTaskDependency",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).AddTaskDependency(ctx, req.(*TaskDependencyRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_RemoveTaskDependency_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(TaskDependencyRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).RemoveTaskDependency(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/RemoveTaskDependency",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).RemoveTaskDependency(ctx, req.(*TaskDependencyRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_GetChecklist_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetTaskRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).GetChecklist(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/GetChecklist",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).GetChecklist(ctx, req.(*GetTaskRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_AddChecklistItem_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AddChecklistItemRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).AddChecklistItem(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/AddChecklistItem",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).AddChecklistItem(ctx, req.(*AddChecklistItemRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_UpdateChecklistItem_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(UpdateChecklistItemRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).UpdateChecklistItem(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/UpdateChecklistItem",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).UpdateChecklistItem(ctx, req.(*UpdateChecklistItemRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_MoveChecklistItem_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(MoveChecklistItemRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).MoveChecklistItem(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/MoveChecklistItem",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).MoveChecklistItem(ctx, req.(*MoveChecklistItemRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_DeleteChecklistItem_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ChecklistItemRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).DeleteChecklistItem(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/DeleteChecklistItem",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).DeleteChecklistItem(ctx, req.(*ChecklistItemRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_AddAttachment_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AddAttachmentRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).AddAttachment(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/AddAttachment",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).AddAttachment(ctx, req.(*AddAttachmentRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _TasksService_ListAttachments_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetTaskRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).ListAttachments(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/ListAttachments",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).ListAttachments(ctx, req.(*GetTaskRequest))
+	}
+	return interceptor(ctx, in, info, handler)
 }
 
-func (UnimplementedTasksServiceServer) CreateTask(context.Context, *CreateTaskRequest) (*TaskResponse, error) {
-	return nil, status.Errorf(codes.Unimplemented, "method CreateTask not implemented")
+func _TasksService_GetAttachment_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AttachmentRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).GetAttachment(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/GetAttachment",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).GetAttachment(ctx, req.(*AttachmentRequest))
+	}
+	return interceptor(ctx, in, info, handler)
 }
-func (UnimplementedTasksServiceServer) GetTask(context.Context, *GetTaskRequest) (*TaskResponse, error) {
-	return nil, status.Errorf(codes.Unimplemented, "method GetTask not implemented")
+
+func _TasksService_DeleteAttachment_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AttachmentRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).DeleteAttachment(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/DeleteAttachment",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).DeleteAttachment(ctx, req.(*AttachmentRequest))
+	}
+	return interceptor(ctx, in, info, handler)
 }
-func (UnimplementedTasksServiceServer) UpdateTask(context.Context, *UpdateTaskRequest) (*TaskResponse, error) {
-	return nil, status.Errorf(codes.Unimplemented, "method UpdateTask not implemented")
+
+func _TasksService_ListDeletedAttachments_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListDeletedAttachmentsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).ListDeletedAttachments(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/ListDeletedAttachments",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).ListDeletedAttachments(ctx, req.(*ListDeletedAttachmentsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
 }
-func (UnimplementedTasksServiceServer) DeleteTask(context.Context, *DeleteTaskRequest) (*TaskResponse, error) {
-	return nil, status.Errorf(codes.Unimplemented, "method DeleteTask not implemented")
+
+func _TasksService_ForgetDeletedAttachments_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ForgetDeletedAttachmentsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).ForgetDeletedAttachments(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/ForgetDeletedAttachments",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).ForgetDeletedAttachments(ctx, req.(*ForgetDeletedAttachmentsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
 }
-func (UnimplementedTasksServiceServer) GetTaskPage(context.Context, *GetTaskPageRequest) (*TaskPageResponse, error) {
-	return nil, status.Errorf(codes.Unimplemented, "method GetTaskPage not implemented")
+
+func _TasksService_StartTimer_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetTaskRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).StartTimer(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/StartTimer",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).StartTimer(ctx, req.(*GetTaskRequest))
+	}
+	return interceptor(ctx, in, info, handler)
 }
-func (UnimplementedTasksServiceServer) mustEmbedUnimplementedTasksServiceServer() {}
 
-// UnsafeTasksServiceServer may be embedded to opt out of forward compatibility for this service.
-// Use of this interface is not recommended, as added methods to TasksServiceServer will
-// result in compilation errors.
-type UnsafeTasksServiceServer interface {
-	mustEmbedUnimplementedTasksServiceServer()
+func _TasksService_StopTimer_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetTaskRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).StopTimer(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/StopTimer",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).StopTimer(ctx, req.(*GetTaskRequest))
+	}
+	return interceptor(ctx, in, info, handler)
 }
 
-func RegisterTasksServiceServer(s grpc.ServiceRegistrar, srv TasksServiceServer) {
-	s.RegisterService(&TasksService_ServiceDesc, srv)
+func _TasksService_GetRunningTimer_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetRunningTimerRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(TasksServiceServer).GetRunningTimer(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/tasks_service.TasksService/GetRunningTimer",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(TasksServiceServer).GetRunningTimer(ctx, req.(*GetRunningTimerRequest))
+	}
+	return interceptor(ctx, in, info, handler)
 }
 
-func _TasksService_CreateTask_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
-	in := new(CreateTaskRequest)
+func _TasksService_AddTimeEntry_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AddTimeEntryRequest)
 	if err := dec(in); err != nil {
 		return nil, err
 	}
 	if interceptor == nil {
-		return srv.(TasksServiceServer).CreateTask(ctx, in)
+		return srv.(TasksServiceServer).AddTimeEntry(ctx, in)
 	}
 	info := &grpc.UnaryServerInfo{
 		Server:     srv,
-		FullMethod: "/tasks_service.TasksService/CreateTask",
+		FullMethod: "/tasks_service.TasksService/AddTimeEntry",
 	}
 	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
-		return srv.(TasksServiceServer).CreateTask(ctx, req.(*CreateTaskRequest))
+		return srv.(TasksServiceServer).AddTimeEntry(ctx, req.(*AddTimeEntryRequest))
 	}
 	return interceptor(ctx, in, info, handler)
 }
 
-func _TasksService_GetTask_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
-	in := new(GetTaskRequest)
+func _TasksService_UpdateTimeEntry_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(UpdateTimeEntryRequest)
 	if err := dec(in); err != nil {
 		return nil, err
 	}
 	if interceptor == nil {
-		return srv.(TasksServiceServer).GetTask(ctx, in)
+		return srv.(TasksServiceServer).UpdateTimeEntry(ctx, in)
 	}
 	info := &grpc.UnaryServerInfo{
 		Server:     srv,
-		FullMethod: "/tasks_service.TasksService/GetTask",
+		FullMethod: "/tasks_service.TasksService/UpdateTimeEntry",
 	}
 	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
-		return srv.(TasksServiceServer).GetTask(ctx, req.(*GetTaskRequest))
+		return srv.(TasksServiceServer).UpdateTimeEntry(ctx, req.(*UpdateTimeEntryRequest))
 	}
 	return interceptor(ctx, in, info, handler)
 }
 
-func _TasksService_UpdateTask_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
-	in := new(UpdateTaskRequest)
+func _TasksService_DeleteTimeEntry_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(TimeEntryRequest)
 	if err := dec(in); err != nil {
 		return nil, err
 	}
 	if interceptor == nil {
-		return srv.(TasksServiceServer).UpdateTask(ctx, in)
+		return srv.(TasksServiceServer).DeleteTimeEntry(ctx, in)
 	}
 	info := &grpc.UnaryServerInfo{
 		Server:     srv,
-		FullMethod: "/tasks_service.TasksService/UpdateTask",
+		FullMethod: "/tasks_service.TasksService/DeleteTimeEntry",
 	}
 	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
-		return srv.(TasksServiceServer).UpdateTask(ctx, req.(*UpdateTaskRequest))
+		return srv.(TasksServiceServer).DeleteTimeEntry(ctx, req.(*TimeEntryRequest))
 	}
 	return interceptor(ctx, in, info, handler)
 }
 
-func _TasksService_DeleteTask_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
-	in := new(DeleteTaskRequest)
+func _TasksService_ListTimeEntries_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetTaskRequest)
 	if err := dec(in); err != nil {
 		return nil, err
 	}
 	if interceptor == nil {
-		return srv.(TasksServiceServer).DeleteTask(ctx, in)
+		return srv.(TasksServiceServer).ListTimeEntries(ctx, in)
 	}
 	info := &grpc.UnaryServerInfo{
 		Server:     srv,
-		FullMethod: "/tasks_service.TasksService/DeleteTask",
+		FullMethod: "/tasks_service.TasksService/ListTimeEntries",
 	}
 	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
-		return srv.(TasksServiceServer).DeleteTask(ctx, req.(*DeleteTaskRequest))
+		return srv.(TasksServiceServer).ListTimeEntries(ctx, req.(*GetTaskRequest))
 	}
 	return interceptor(ctx, in, info, handler)
 }
 
-func _TasksService_GetTaskPage_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
-	in := new(GetTaskPageRequest)
+func _TasksService_GetTimeReport_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetTimeReportRequest)
 	if err := dec(in); err != nil {
 		return nil, err
 	}
 	if interceptor == nil {
-		return srv.(TasksServiceServer).GetTaskPage(ctx, in)
+		return srv.(TasksServiceServer).GetTimeReport(ctx, in)
 	}
 	info := &grpc.UnaryServerInfo{
 		Server:     srv,
-		FullMethod: "/tasks_service.TasksService/GetTaskPage",
+		FullMethod: "/tasks_service.TasksService/GetTimeReport",
 	}
 	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
-		return srv.(TasksServiceServer).GetTaskPage(ctx, req.(*GetTaskPageRequest))
+		return srv.(TasksServiceServer).GetTimeReport(ctx, req.(*GetTimeReportRequest))
 	}
 	return interceptor(ctx, in, info, handler)
 }
@@ -243,6 +1971,222 @@ var TasksService_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "GetTaskPage",
 			Handler:    _TasksService_GetTaskPage_Handler,
 		},
+		{
+			MethodName: "GetTaskAccess",
+			Handler:    _TasksService_GetTaskAccess_Handler,
+		},
+		{
+			MethodName: "GrantTaskAccess",
+			Handler:    _TasksService_GrantTaskAccess_Handler,
+		},
+		{
+			MethodName: "RevokeTaskAccess",
+			Handler:    _TasksService_RevokeTaskAccess_Handler,
+		},
+		{
+			MethodName: "CreateComment",
+			Handler:    _TasksService_CreateComment_Handler,
+		},
+		{
+			MethodName: "UpdateComment",
+			Handler:    _TasksService_UpdateComment_Handler,
+		},
+		{
+			MethodName: "DeleteComment",
+			Handler:    _TasksService_DeleteComment_Handler,
+		},
+		{
+			MethodName: "GetCommentPage",
+			Handler:    _TasksService_GetCommentPage_Handler,
+		},
+		{
+			MethodName: "SearchTasks",
+			Handler:    _TasksService_SearchTasks_Handler,
+		},
+		{
+			MethodName: "BatchTasks",
+			Handler:    _TasksService_BatchTasks_Handler,
+		},
+		{
+			MethodName: "GetTaskHistory",
+			Handler:    _TasksService_GetTaskHistory_Handler,
+		},
+		{
+			MethodName: "RestoreTaskVersion",
+			Handler:    _TasksService_RestoreTaskVersion_Handler,
+		},
+		{
+			MethodName: "ListTrash",
+			Handler:    _TasksService_ListTrash_Handler,
+		},
+		{
+			MethodName: "RestoreTask",
+			Handler:    _TasksService_RestoreTask_Handler,
+		},
+		{
+			MethodName: "PurgeTask",
+			Handler:    _TasksService_PurgeTask_Handler,
+		},
+		{
+			MethodName: "PurgeExpiredTasks",
+			Handler:    _TasksService_PurgeExpiredTasks_Handler,
+		},
+		{
+			MethodName: "GetDueTasks",
+			Handler:    _TasksService_GetDueTasks_Handler,
+		},
+		{
+			MethodName: "CreateLabel",
+			Handler:    _TasksService_CreateLabel_Handler,
+		},
+		{
+			MethodName: "UpdateLabel",
+			Handler:    _TasksService_UpdateLabel_Handler,
+		},
+		{
+			MethodName: "DeleteLabel",
+			Handler:    _TasksService_DeleteLabel_Handler,
+		},
+		{
+			MethodName: "ListLabels",
+			Handler:    _TasksService_ListLabels_Handler,
+		},
+		{
+			MethodName: "AddTaskLabel",
+			Handler:    _TasksService_AddTaskLabel_Handler,
+		},
+		{
+			MethodName: "RemoveTaskLabel",
+			Handler:    _TasksService_RemoveTaskLabel_Handler,
+		},
+		{
+			MethodName: "CreateProject",
+			Handler:    _TasksService_CreateProject_Handler,
+		},
+		{
+			MethodName: "GetProject",
+			Handler:    _TasksService_GetProject_Handler,
+		},
+		{
+			MethodName: "UpdateProject",
+			Handler:    _TasksService_UpdateProject_Handler,
+		},
+		{
+			MethodName: "DeleteProject",
+			Handler:    _TasksService_DeleteProject_Handler,
+		},
+		{
+			MethodName: "ListProjects",
+			Handler:    _TasksService_ListProjects_Handler,
+		},
+		{
+			MethodName: "GetProjectMembers",
+			Handler:    _TasksService_GetProjectMembers_Handler,
+		},
+		{
+			MethodName: "SetProjectMember",
+			Handler:    _TasksService_SetProjectMember_Handler,
+		},
+		{
+			MethodName: "RemoveProjectMember",
+			Handler:    _TasksService_RemoveProjectMember_Handler,
+		},
+		{
+			MethodName: "MoveTask",
+			Handler:    _TasksService_MoveTask_Handler,
+		},
+		{
+			MethodName: "SetTaskParent",
+			Handler:    _TasksService_SetTaskParent_Handler,
+		},
+		{
+			MethodName: "GetTaskDependencies",
+			Handler:    _TasksService_GetTaskDependencies_Handler,
+		},
+		{
+			MethodName: "AddTaskDependency",
+			Handler:    _TasksService_AddTaskDependency_Handler,
+		},
+		{
+			MethodName: "RemoveTaskDependency",
+			Handler:    _TasksService_RemoveTaskDependency_Handler,
+		},
+		{
+			MethodName: "GetChecklist",
+			Handler:    _TasksService_GetChecklist_Handler,
+		},
+		{
+			MethodName: "AddChecklistItem",
+			Handler:    _TasksService_AddChecklistItem_Handler,
+		},
+		{
+			MethodName: "UpdateChecklistItem",
+			Handler:    _TasksService_UpdateChecklistItem_Handler,
+		},
+		{
+			MethodName: "MoveChecklistItem",
+			Handler:    _TasksService_MoveChecklistItem_Handler,
+		},
+		{
+			MethodName: "DeleteChecklistItem",
+			Handler:    _TasksService_DeleteChecklistItem_Handler,
+		},
+		{
+			MethodName: "AddAttachment",
+			Handler:    _TasksService_AddAttachment_Handler,
+		},
+		{
+			MethodName: "ListAttachments",
+			Handler:    _TasksService_ListAttachments_Handler,
+		},
+		{
+			MethodName: "GetAttachment",
+			Handler:    _TasksService_GetAttachment_Handler,
+		},
+		{
+			MethodName: "DeleteAttachment",
+			Handler:    _TasksService_DeleteAttachment_Handler,
+		},
+		{
+			MethodName: "ListDeletedAttachments",
+			Handler:    _TasksService_ListDeletedAttachments_Handler,
+		},
+		{
+			MethodName: "ForgetDeletedAttachments",
+			Handler:    _TasksService_ForgetDeletedAttachments_Handler,
+		},
+		{
+			MethodName: "StartTimer",
+			Handler:    _TasksService_StartTimer_Handler,
+		},
+		{
+			MethodName: "StopTimer",
+			Handler:    _TasksService_StopTimer_Handler,
+		},
+		{
+			MethodName: "GetRunningTimer",
+			Handler:    _TasksService_GetRunningTimer_Handler,
+		},
+		{
+			MethodName: "AddTimeEntry",
+			Handler:    _TasksService_AddTimeEntry_Handler,
+		},
+		{
+			MethodName: "UpdateTimeEntry",
+			Handler:    _TasksService_UpdateTimeEntry_Handler,
+		},
+		{
+			MethodName: "DeleteTimeEntry",
+			Handler:    _TasksService_DeleteTimeEntry_Handler,
+		},
+		{
+			MethodName: "ListTimeEntries",
+			Handler:    _TasksService_ListTimeEntries_Handler,
+		},
+		{
+			MethodName: "GetTimeReport",
+			Handler:    _TasksService_GetTimeReport_Handler,
+		},
 	},
 	Streams:  []grpc.StreamDesc{},
 	Metadata: "api/tasks_service.proto",
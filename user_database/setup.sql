@@ -8,3 +8,69 @@ CREATE TABLE users (
     email VARCHAR (320),
     phone_number VARCHAR (50)
 );
+
+CREATE TABLE event_outbox (
+    id BIGSERIAL PRIMARY KEY,
+    topic VARCHAR (100) NOT NULL,
+    key VARCHAR (100) NOT NULL,
+    payload BYTEA NOT NULL
+);
+
+CREATE TABLE idempotency_keys (
+    username VARCHAR (50) NOT NULL,
+    key VARCHAR (255) NOT NULL,
+    fingerprint BYTEA NOT NULL,
+    status SMALLINT,
+    -- Headers of the response as array of name and value pairs
+    headers JSONB,
+    body BYTEA,
+    created_at TIMESTAMPTZ NOT NULL,
+    -- Request in progress holds the key until then
+    locked_until TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (username, key)
+);
+
+CREATE INDEX idempotency_keys_created_at_idx ON idempotency_keys (created_at);
+
+CREATE TABLE task_reminders (
+    task_id VARCHAR (50) NOT NULL,
+    due_date DATE NOT NULL,
+    assignee VARCHAR (50) NOT NULL,
+    sent_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (task_id, due_date, assignee)
+);
+
+CREATE INDEX task_reminders_due_date_idx ON task_reminders (due_date);
+
+CREATE TABLE task_series (
+    id SERIAL PRIMARY KEY,
+    owner VARCHAR (50) NOT NULL,
+    template JSONB NOT NULL,
+    rule VARCHAR (200) NOT NULL,
+    starts_on DATE NOT NULL,
+    last_on DATE,
+    next_on DATE,
+    last_task_id VARCHAR (50),
+    created_at TIMESTAMPTZ NOT NULL,
+    stopped_at TIMESTAMPTZ
+);
+
+CREATE INDEX task_series_owner_idx ON task_series (owner);
+CREATE INDEX task_series_next_on_idx ON task_series (next_on) WHERE stopped_at IS NULL;
+
+CREATE TABLE task_templates (
+    id SERIAL PRIMARY KEY,
+    owner VARCHAR (50) NOT NULL,
+    name VARCHAR (100) NOT NULL,
+    title VARCHAR (500) NOT NULL,
+    description TEXT NOT NULL,
+    status VARCHAR (50) NOT NULL,
+    label_ids TEXT[] NOT NULL,
+    checklist TEXT[] NOT NULL,
+    shared BOOLEAN NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ,
+    UNIQUE (owner, name)
+);
+
+CREATE INDEX task_templates_shared_idx ON task_templates (shared) WHERE shared;
@@ -3,8 +3,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .build_server(false)
         .out_dir("src/proto")
         .compile(
-            &["../tasks_service/api/tasks_service.proto"],
-            &["../tasks_service/api"],
+            &["../tasks_service/api/tasks_service.proto", "api/events.proto"],
+            &["../tasks_service/api", "api"],
         )?;
     Ok(())
 }
\ No newline at end of file
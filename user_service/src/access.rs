@@ -0,0 +1,148 @@
+use crate::common::{AppClaims, AppError, AppState, AppStateRef};
+use crate::proto::tasks_service as ts;
+use crate::users::{ensure_user_exists, Profiles, UserProfile};
+use axum::{
+    extract::{Path, State},
+    response::Result,
+    Json,
+};
+use log::info;
+use serde::{Deserialize, Serialize};
+
+pub async fn get_task_access_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+) -> Result<Json<Vec<TaskAccess>>, AppError> {
+    info!("get_task_access_handler: handling get task access request");
+    let request = tonic::Request::new(ts::GetTaskAccessRequest {
+        user_id: claims.username,
+        task_id,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .get_task_access(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_access_list(&state, response.into_inner()).await
+}
+
+pub async fn grant_task_access_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+    Json(req): Json<GrantTaskAccessRequest>,
+) -> Result<Json<Vec<TaskAccess>>, AppError> {
+    info!("grant_task_access_handler: handling grant task access request");
+    let role = ts::TaskRole::from_str_name(&req.role).ok_or(AppError::IncorrectRequest)?;
+    ensure_user_exists(&state.user_database, &req.username).await?;
+    let request = tonic::Request::new(ts::GrantTaskAccessRequest {
+        user_id: claims.username,
+        task_id,
+        grantee_id: req.username,
+        role: role.into(),
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .grant_task_access(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_access_list(&state, response.into_inner()).await
+}
+
+pub async fn revoke_task_access_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path((task_id, username)): Path<(String, String)>,
+) -> Result<Json<Vec<TaskAccess>>, AppError> {
+    info!("revoke_task_access_handler: handling revoke task access request");
+    let request = tonic::Request::new(ts::RevokeTaskAccessRequest {
+        user_id: claims.username,
+        task_id,
+        grantee_id: username,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .revoke_task_access(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_access_list(&state, response.into_inner()).await
+}
+
+async fn make_access_list(
+    state: &AppState,
+    response: ts::TaskAccessResponse,
+) -> Result<Json<Vec<TaskAccess>>, AppError> {
+    let entries = match response.response.unwrap() {
+        ts::task_access_response::Response::AccessList(x) => x.entries,
+        ts::task_access_response::Response::Error(e) => return Err(e.into()),
+    };
+    let usernames: Vec<&str> = entries.iter().map(|x| x.user_id.as_str()).collect();
+    let profiles = Profiles::fetch(&state.user_database, &usernames).await?;
+    Ok(Json(
+        entries
+            .iter()
+            .map(|x| TaskAccess {
+                user: profiles.get(&x.user_id),
+                role: ts::TaskRole::try_from(x.role)
+                    .unwrap()
+                    .as_str_name()
+                    .to_string(),
+            })
+            .collect(),
+    ))
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GrantTaskAccessRequest {
+    username: String,
+    role: String,
+}
+
+#[derive(Serialize)]
+pub struct TaskAccess {
+    user: UserProfile,
+    role: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::ERR_CODE_ACCESS;
+    use axum::{http::StatusCode, response::IntoResponse};
+
+    #[test]
+    fn grant_request_has_role_name() {
+        let req: GrantTaskAccessRequest =
+            serde_json::from_str(r#"{"username": "jane", "role": "Editor"}"#).unwrap();
+        assert_eq!(req.username, "jane");
+        assert_eq!(
+            ts::TaskRole::from_str_name(&req.role),
+            Some(ts::TaskRole::Editor)
+        );
+        for role in ["editor", "Admin", ""] {
+            assert_eq!(ts::TaskRole::from_str_name(role), None);
+        }
+        assert!(serde_json::from_str::<GrantTaskAccessRequest>(
+            r#"{"username": "jane", "role": "Editor", "task_id": "x"}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn denied_access_is_forbidden() {
+        let e = AppError::from(ts::Error {
+            code: ERR_CODE_ACCESS,
+            message: "User doesn't have Owner access to this task".to_string(),
+        });
+        assert!(matches!(e, AppError::TaskAccessDenied));
+        assert_eq!(e.into_response().status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,535 @@
+use crate::common::{AppClaims, AppError, AppState, AppStateRef};
+use crate::events;
+use crate::proto::events::{self as ev, event::Payload};
+use crate::proto::tasks_service as ts;
+use crate::tasks::{fetch_task, status_name};
+use crate::users::{Profiles, UserProfile};
+use axum::{
+    body::Body,
+    extract::{Multipart, Path, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response, Result},
+    Json,
+};
+use futures::{stream, StreamExt};
+use log::{error, info};
+use serde::Serialize;
+use std::{io, ops::Range, time::Duration};
+use tokio::time::sleep;
+
+const MAX_FILENAME_LENGTH: usize = 255;
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(10 * 60);
+const CLEANUP_BATCH_SIZE: i32 = 100;
+
+/// Uploads file from `file` part of multipart form and attaches it to the
+/// task. Content is stored first and removed again if it can't be attached.
+pub async fn upload_attachment_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+    mut multipart: Multipart,
+) -> Result<Json<Attachment>, AppError> {
+    info!("upload_attachment_handler: handling upload attachment request");
+    // Users who can't see the task don't get to store anything
+    let task = fetch_task(&state, &claims.username, &task_id).await?;
+    let field = loop {
+        match multipart.next_field().await {
+            Ok(Some(x)) if x.name() == Some("file") => break x,
+            Ok(Some(_)) => continue,
+            _ => return Err(AppError::IncorrectRequest),
+        }
+    };
+    let filename = field
+        .file_name()
+        .and_then(|x| x.rsplit(['/', '\\']).next())
+        .map(str::trim)
+        .filter(|x| !x.is_empty() && x.chars().count() <= MAX_FILENAME_LENGTH)
+        .ok_or(AppError::IncorrectRequest)?
+        .to_string();
+    let content_type = field
+        .content_type()
+        .unwrap_or("application/octet-stream")
+        .to_ascii_lowercase();
+    if !is_type_allowed(&state, &content_type) {
+        return Err(AppError::AttachmentTypeNotAllowed);
+    }
+
+    let storage_key = uuid::Uuid::new_v4().simple().to_string();
+    let limit = state.max_attachment_size;
+    let mut size = 0;
+    let mut incomplete = false;
+    let content = field.map(|chunk| {
+        let chunk = chunk.map_err(|e| {
+            incomplete = true;
+            io::Error::other(e)
+        })?;
+        size += chunk.len() as u64;
+        if size > limit {
+            return Err(io::Error::other("attachment is too large"));
+        }
+        Ok(chunk)
+    });
+    if let Err(e) = state.storage.put(&storage_key, content.boxed()).await {
+        if size > limit {
+            return Err(AppError::AttachmentTooLarge);
+        }
+        if incomplete {
+            return Err(AppError::IncorrectRequest);
+        }
+        error!(
+            "upload_attachment_handler: couldn't store attachment: {}",
+            e
+        );
+        return Err(AppError::StorageUnavailable);
+    }
+
+    let request = tonic::Request::new(ts::AddAttachmentRequest {
+        user_id: claims.username.clone(),
+        task_id,
+        filename,
+        content_type,
+        size: size as i64,
+        storage_key: storage_key.clone(),
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .add_attachment(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest);
+    let attachment = match response.map(|x| x.into_inner().response.unwrap()) {
+        Ok(ts::attachment_response::Response::Attachment(x)) => x,
+        result => {
+            if let Err(e) = state.storage.delete(&storage_key).await {
+                error!(
+                    "upload_attachment_handler: couldn't remove stored attachment: {}",
+                    e
+                );
+            }
+            return Err(match result {
+                Ok(ts::attachment_response::Response::Error(e)) => e.into(),
+                _ => AppError::IncorrectRequest,
+            });
+        }
+    };
+    events::emit(
+        &state,
+        Payload::TaskUpdated(ev::TaskUpdated {
+            task_id: task.id,
+            user_id: claims.username,
+            title: task.title,
+            status: status_name(task.status),
+        }),
+    )
+    .await?;
+    Ok(Json(
+        make_attachments(&state, vec![attachment])
+            .await?
+            .pop()
+            .unwrap(),
+    ))
+}
+
+pub async fn list_attachments_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+) -> Result<Json<Vec<Attachment>>, AppError> {
+    info!("list_attachments_handler: handling list attachments request");
+    let request = tonic::Request::new(ts::GetTaskRequest {
+        user_id: claims.username,
+        task_id,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .list_attachments(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    let attachments = match response.into_inner().response.unwrap() {
+        ts::attachment_list_response::Response::Attachments(x) => x.attachments,
+        ts::attachment_list_response::Response::Error(e) => return Err(e.into()),
+    };
+    make_attachments(&state, attachments).await.map(Json)
+}
+
+/// Streams content of the attachment. Single byte range of `Range` header is
+/// served as partial content, other ranges get the whole content.
+pub async fn download_attachment_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path((task_id, attachment_id)): Path<(String, String)>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    info!("download_attachment_handler: handling download attachment request");
+    let request = tonic::Request::new(ts::AttachmentRequest {
+        user_id: claims.username,
+        task_id,
+        attachment_id,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .get_attachment(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    let attachment = match response.into_inner().response.unwrap() {
+        ts::attachment_response::Response::Attachment(x) => x,
+        ts::attachment_response::Response::Error(e) => return Err(e.into()),
+    };
+    let size = attachment.size as u64;
+    let (status, range) = match requested_range(&headers, size) {
+        RequestedRange::Full => (StatusCode::OK, 0..size),
+        RequestedRange::Partial(x) => (StatusCode::PARTIAL_CONTENT, x),
+        RequestedRange::Unsatisfiable => {
+            return Ok((
+                StatusCode::RANGE_NOT_SATISFIABLE,
+                [(header::CONTENT_RANGE, format!("bytes */{}", size))],
+            )
+                .into_response());
+        }
+    };
+    let content = if range.is_empty() {
+        stream::empty().boxed()
+    } else {
+        state
+            .storage
+            .get(&attachment.storage_key, range.clone())
+            .await
+            .map_err(|e| {
+                error!(
+                    "download_attachment_handler: couldn't read attachment: {}",
+                    e
+                );
+                AppError::StorageUnavailable
+            })?
+    };
+    let mut response = Response::new(Body::from_stream(content));
+    *response.status_mut() = status;
+    let headers = response.headers_mut();
+    headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_str(&attachment.content_type)
+            .unwrap_or(HeaderValue::from_static("application/octet-stream")),
+    );
+    headers.insert(
+        header::CONTENT_LENGTH,
+        HeaderValue::from(range.end - range.start),
+    );
+    headers.insert(header::ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+    headers.insert(
+        header::CONTENT_DISPOSITION,
+        content_disposition(&attachment.filename),
+    );
+    headers.insert(
+        header::X_CONTENT_TYPE_OPTIONS,
+        HeaderValue::from_static("nosniff"),
+    );
+    if status == StatusCode::PARTIAL_CONTENT {
+        headers.insert(
+            header::CONTENT_RANGE,
+            HeaderValue::from_str(&format!("bytes {}-{}/{}", range.start, range.end - 1, size))
+                .unwrap(),
+        );
+    }
+    Ok(response)
+}
+
+/// Detaches the attachment from the task, its content is removed from storage
+/// by the cleanup task.
+pub async fn delete_attachment_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path((task_id, attachment_id)): Path<(String, String)>,
+) -> Result<Json<Attachment>, AppError> {
+    info!("delete_attachment_handler: handling delete attachment request");
+    let task = fetch_task(&state, &claims.username, &task_id).await?;
+    let request = tonic::Request::new(ts::AttachmentRequest {
+        user_id: claims.username.clone(),
+        task_id,
+        attachment_id,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .delete_attachment(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    let attachment = match response.into_inner().response.unwrap() {
+        ts::attachment_response::Response::Attachment(x) => x,
+        ts::attachment_response::Response::Error(e) => return Err(e.into()),
+    };
+    events::emit(
+        &state,
+        Payload::TaskUpdated(ev::TaskUpdated {
+            task_id: task.id,
+            user_id: claims.username,
+            title: task.title,
+            status: status_name(task.status),
+        }),
+    )
+    .await?;
+    Ok(Json(
+        make_attachments(&state, vec![attachment])
+            .await?
+            .pop()
+            .unwrap(),
+    ))
+}
+
+/// Periodically removes content of deleted attachments from storage,
+/// including attachments of purged tasks.
+pub async fn run_deleted_attachments_cleanup(state: AppStateRef) {
+    loop {
+        while let Some(n) = cleanup_deleted_attachments(&state).await {
+            info!("run_deleted_attachments_cleanup: removed {} attachments", n);
+            if n < CLEANUP_BATCH_SIZE as usize {
+                break;
+            }
+        }
+        sleep(CLEANUP_INTERVAL).await;
+    }
+}
+
+/// Removes a batch of deleted attachments, returns their number or `None` if
+/// there are none or they can't be listed.
+async fn cleanup_deleted_attachments(state: &AppState) -> Option<usize> {
+    let request = tonic::Request::new(ts::ListDeletedAttachmentsRequest {
+        limit: CLEANUP_BATCH_SIZE,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .list_deleted_attachments(request)
+        .await;
+    let keys = match response.map(|x| x.into_inner().response) {
+        Ok(Some(ts::deleted_attachments_response::Response::Deleted(x))) => x.storage_keys,
+        Ok(Some(ts::deleted_attachments_response::Response::Error(e))) => {
+            error!(
+                "run_deleted_attachments_cleanup: couldn't list attachments: {}",
+                e.message
+            );
+            return None;
+        }
+        Ok(None) => {
+            error!("run_deleted_attachments_cleanup: empty response");
+            return None;
+        }
+        Err(e) => {
+            error!(
+                "run_deleted_attachments_cleanup: couldn't list attachments: {}",
+                e
+            );
+            return None;
+        }
+    };
+    let mut removed = Vec::with_capacity(keys.len());
+    for key in keys {
+        match state.storage.delete(&key).await {
+            Ok(()) => removed.push(key),
+            Err(e) => error!(
+                "run_deleted_attachments_cleanup: couldn't remove {}: {}",
+                key, e
+            ),
+        }
+    }
+    if removed.is_empty() {
+        return None;
+    }
+    let request = tonic::Request::new(ts::ForgetDeletedAttachmentsRequest {
+        storage_keys: removed,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .forget_deleted_attachments(request)
+        .await;
+    match response.map(|x| x.into_inner().response) {
+        Ok(Some(ts::deleted_attachments_response::Response::Deleted(x))) => {
+            Some(x.storage_keys.len())
+        }
+        _ => {
+            error!("run_deleted_attachments_cleanup: couldn't forget removed attachments");
+            None
+        }
+    }
+}
+
+/// Checks content type against allowed types, `type/*` allows all subtypes.
+fn is_type_allowed(state: &AppState, content_type: &str) -> bool {
+    let essence = content_type.split(';').next().unwrap_or_default().trim();
+    state
+        .attachment_types
+        .iter()
+        .any(|x| match x.strip_suffix("/*") {
+            Some(prefix) => essence
+                .strip_prefix(prefix)
+                .is_some_and(|x| x.starts_with('/')),
+            None => x == essence,
+        })
+}
+
+#[derive(PartialEq, Debug)]
+enum RequestedRange {
+    Full,
+    Partial(Range<u64>),
+    Unsatisfiable,
+}
+
+/// Byte range of the content requested by `Range` header. Missing, invalid and
+/// multiple ranges fall back to the whole content.
+fn requested_range(headers: &HeaderMap, size: u64) -> RequestedRange {
+    let Some((start, end)) = headers
+        .get(header::RANGE)
+        .and_then(|x| x.to_str().ok())
+        .and_then(|x| x.trim().strip_prefix("bytes="))
+        .filter(|x| !x.contains(','))
+        .and_then(|x| x.split_once('-'))
+    else {
+        return RequestedRange::Full;
+    };
+    let (start, end) = (start.trim(), end.trim());
+    if start.is_empty() {
+        // Suffix of given length
+        return match end.parse::<u64>() {
+            Ok(0) => RequestedRange::Unsatisfiable,
+            Ok(_) if size == 0 => RequestedRange::Unsatisfiable,
+            Ok(n) => RequestedRange::Partial(size.saturating_sub(n)..size),
+            Err(_) => RequestedRange::Full,
+        };
+    }
+    let Ok(start) = start.parse::<u64>() else {
+        return RequestedRange::Full;
+    };
+    let end = if end.is_empty() {
+        size
+    } else {
+        match end.parse::<u64>() {
+            Ok(x) if x >= start => x.saturating_add(1).min(size),
+            _ => return RequestedRange::Full,
+        }
+    };
+    if start >= size {
+        return RequestedRange::Unsatisfiable;
+    }
+    RequestedRange::Partial(start..end)
+}
+
+/// Makes browsers download the file under its name. Name is percent-encoded
+/// for `filename*`, non-ASCII characters are replaced in the fallback.
+fn content_disposition(filename: &str) -> HeaderValue {
+    let fallback: String = filename
+        .chars()
+        .map(|x| match x {
+            ' '..='~' if x != '"' && x != '\\' => x,
+            _ => '_',
+        })
+        .collect();
+    let encoded: String = filename
+        .bytes()
+        .map(|x| match x {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'.' | b'-' | b'_' | b'~' => {
+                (x as char).to_string()
+            }
+            _ => format!("%{:02X}", x),
+        })
+        .collect();
+    HeaderValue::from_str(&format!(
+        "attachment; filename=\"{}\"; filename*=UTF-8''{}",
+        fallback, encoded
+    ))
+    .unwrap()
+}
+
+/// Converts attachments from tasks_service resolving their uploaders.
+async fn make_attachments(
+    state: &AppState,
+    attachments: Vec<ts::Attachment>,
+) -> Result<Vec<Attachment>, AppError> {
+    let usernames: Vec<&str> = attachments.iter().map(|x| x.uploader_id.as_str()).collect();
+    let profiles = Profiles::fetch(&state.user_database, &usernames).await?;
+    Ok(attachments
+        .into_iter()
+        .map(|x| Attachment {
+            uploaded_by: profiles.get(&x.uploader_id),
+            id: x.id,
+            filename: x.filename,
+            content_type: x.content_type,
+            size: x.size,
+            created_at: x.created_at.unwrap().to_string(),
+        })
+        .collect())
+}
+
+#[derive(Serialize)]
+pub struct Attachment {
+    id: String,
+    filename: String,
+    content_type: String,
+    /// Size in bytes
+    size: i64,
+    uploaded_by: UserProfile,
+    created_at: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use RequestedRange::*;
+
+    fn range(value: &str, size: u64) -> RequestedRange {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::RANGE, HeaderValue::from_str(value).unwrap());
+        requested_range(&headers, size)
+    }
+
+    #[test]
+    fn ranges_are_clamped_to_content() {
+        assert_eq!(requested_range(&HeaderMap::new(), 100), Full);
+        assert_eq!(range("bytes=0-9", 100), Partial(0..10));
+        assert_eq!(range("bytes=90-", 100), Partial(90..100));
+        assert_eq!(range("bytes=90-200", 100), Partial(90..100));
+        assert_eq!(range("bytes=-10", 100), Partial(90..100));
+        assert_eq!(range("bytes=-200", 100), Partial(0..100));
+    }
+
+    #[test]
+    fn unsatisfiable_ranges_are_detected() {
+        assert_eq!(range("bytes=100-", 100), Unsatisfiable);
+        assert_eq!(range("bytes=-0", 100), Unsatisfiable);
+        assert_eq!(range("bytes=-10", 0), Unsatisfiable);
+    }
+
+    #[test]
+    fn invalid_and_multiple_ranges_fall_back_to_full_content() {
+        for value in [
+            "items=0-9",
+            "bytes=0-9,20-29",
+            "bytes=9-0",
+            "bytes=a-9",
+            "bytes=0-b",
+            "bytes=-x",
+            "bytes=10",
+        ] {
+            assert_eq!(range(value, 100), Full, "{}", value);
+        }
+    }
+
+    #[test]
+    fn content_disposition_escapes_filename() {
+        assert_eq!(
+            content_disposition("report 1.pdf"),
+            "attachment; filename=\"report 1.pdf\"; filename*=UTF-8''report%201.pdf"
+        );
+        assert_eq!(
+            content_disposition("a\"b\\c\u{e9}.txt"),
+            "attachment; filename=\"a_b_c_.txt\"; filename*=UTF-8''a%22b%5Cc%C3%A9.txt"
+        );
+    }
+}
@@ -1,4 +1,6 @@
 use crate::common::{AppClaims, AppError, AppStateRef};
+use crate::events;
+use crate::proto::events::{event::Payload, UserRegistered, UserUpdated};
 use axum::{extract::State, http::StatusCode, response::Result, Json};
 use chrono::NaiveDate;
 use jwt_simple::prelude::*;
@@ -12,11 +14,28 @@ pub async fn register_handler(
     info!("register_handler: handling register request");
 
     let password_hash = bcrypt::hash(auth_info.password, 10).unwrap();
+    let event = events::outbox_event(Payload::UserRegistered(UserRegistered {
+        username: auth_info.username.clone(),
+    }));
+    // Event is stored by the same statement, so it exists only if user does
     state
         .user_database
         .query(
-            "INSERT INTO users (username, password) VALUES ($1, $2)",
-            &[&auth_info.username, &password_hash],
+            "WITH registered AS (
+                INSERT INTO users (username, password) VALUES ($1, $2) RETURNING username
+            )
+            INSERT INTO
+                event_outbox (topic, key, payload)
+            SELECT
+                $3::VARCHAR, username, $4::BYTEA
+            FROM
+                registered",
+            &[
+                &auth_info.username,
+                &password_hash,
+                &event.topic,
+                &event.payload,
+            ],
         )
         .await
         .map_err(|_| AppError::IncorrectRequest)?;
@@ -63,19 +82,31 @@ pub async fn update_handler(
         None => None,
     };
 
+    let event = events::outbox_event(Payload::UserUpdated(UserUpdated {
+        username: username.clone(),
+    }));
     state
         .user_database
         .query(
-            "UPDATE 
-                users 
-            SET 
-                first_name=COALESCE($1, first_name),
-                last_name=COALESCE($2, last_name),
-                date_of_birth=COALESCE($3, date_of_birth),
-                email=COALESCE($4, email),
-                phone_number=COALESCE($5, phone_number)
-            WHERE
-                username=$6",
+            "WITH updated AS (
+                UPDATE 
+                    users 
+                SET 
+                    first_name=COALESCE($1, first_name),
+                    last_name=COALESCE($2, last_name),
+                    date_of_birth=COALESCE($3, date_of_birth),
+                    email=COALESCE($4, email),
+                    phone_number=COALESCE($5, phone_number)
+                WHERE
+                    username=$6
+                RETURNING username
+            )
+            INSERT INTO
+                event_outbox (topic, key, payload)
+            SELECT
+                $7::VARCHAR, username, $8::BYTEA
+            FROM
+                updated",
             &[
                 &user_info.first_name,
                 &user_info.last_name,
@@ -83,6 +114,8 @@ pub async fn update_handler(
                 &user_info.email,
                 &user_info.phone_number,
                 &username,
+                &event.topic,
+                &event.payload,
             ],
         )
         .await
@@ -0,0 +1,207 @@
+use crate::common::{AppClaims, AppError, AppStateRef};
+use crate::etag::if_match_version;
+use crate::events;
+use crate::proto::events::{self as ev, event::Payload};
+use crate::proto::tasks_service as ts;
+use crate::tasks::{
+    fetch_task, fetch_task_page, make_task, status_name, tagged, GetTaskPageRequest, TaskPage,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::HeaderMap,
+    response::{Response, Result},
+    Json,
+};
+use log::info;
+use serde::{Deserialize, Serialize};
+
+/// Columns of the board from left to right.
+const COLUMNS: [ts::TaskStatus; 6] = [
+    ts::TaskStatus::Open,
+    ts::TaskStatus::InProgress,
+    ts::TaskStatus::PendingReview,
+    ts::TaskStatus::Testing,
+    ts::TaskStatus::Completed,
+    ts::TaskStatus::Cancelled,
+];
+
+/// Lists tasks visible to the user grouped into a column per status. Every
+/// column has its first page, further pages are fetched by
+/// `GET /board/{status}` with `next_cursor` of the column.
+pub async fn get_board_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Query(req): Query<GetTaskPageRequest>,
+) -> Result<Json<Board>, AppError> {
+    info!("get_board_handler: handling get board request");
+    if !req.is_first_page() {
+        return Err(AppError::IncorrectRequest);
+    }
+    let mut columns = Vec::with_capacity(COLUMNS.len());
+    for status in COLUMNS {
+        let page = fetch_task_page(
+            &state,
+            claims.username.clone(),
+            ts::TaskRelation::Visible,
+            req.board_column(status)?,
+        )
+        .await?;
+        columns.push(BoardColumn {
+            status: status.as_str_name().to_string(),
+            page: page.0,
+        });
+    }
+    Ok(Json(Board { columns }))
+}
+
+pub async fn get_board_column_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(status): Path<String>,
+    Query(req): Query<GetTaskPageRequest>,
+) -> Result<Json<TaskPage>, AppError> {
+    info!("get_board_column_handler: handling get board column request");
+    let status = ts::TaskStatus::from_str_name(&status).ok_or(AppError::IncorrectRequest)?;
+    fetch_task_page(
+        &state,
+        claims.username,
+        ts::TaskRelation::Visible,
+        req.board_column(status)?,
+    )
+    .await
+}
+
+/// Moves the task to the column of given status right after another task of
+/// the column and of the same project, or on top of it. Moving to another
+/// column has to follow one of the workflow transitions.
+pub async fn move_task_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+    headers: HeaderMap,
+    Json(req): Json<MoveTaskRequest>,
+) -> Result<Response, AppError> {
+    info!("move_task_handler: handling move task request");
+    if req.after_id.as_ref() == Some(&task_id) {
+        return Err(AppError::IncorrectRequest);
+    }
+    let to = ts::TaskStatus::from_str_name(&req.status).ok_or(AppError::IncorrectRequest)?;
+    let task = fetch_task(&state, &claims.username, &task_id).await?;
+    let from = ts::TaskStatus::try_from(task.status).map_err(|_| AppError::IncorrectRequest)?;
+    let new_status = if to == from {
+        None
+    } else {
+        state
+            .workflow
+            .available(from)
+            .find(|t| t.to == to)
+            .ok_or(AppError::TransitionNotAllowed)?;
+        Some(to.into())
+    };
+    let request = tonic::Request::new(ts::MoveTaskRequest {
+        user_id: claims.username.clone(),
+        task_id,
+        new_status,
+        expected_status: Some(from.into()),
+        after_id: req.after_id,
+        expected_version: if_match_version(&headers)?,
+        check_blockers: state.check_blockers,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .move_task(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    match response.into_inner().response.unwrap() {
+        ts::task_response::Response::Task(x) => {
+            events::emit(
+                &state,
+                Payload::TaskUpdated(ev::TaskUpdated {
+                    task_id: x.id.clone(),
+                    user_id: claims.username,
+                    title: x.title.clone(),
+                    status: status_name(x.status),
+                }),
+            )
+            .await?;
+            Ok(tagged(make_task(&state, x).await?))
+        }
+        ts::task_response::Response::Error(e) => Err(e.into()),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MoveTaskRequest {
+    /// Status of the target column
+    status: String,
+    /// Task of the target column to place the task after, top of the column
+    /// if not set
+    after_id: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct Board {
+    columns: Vec<BoardColumn>,
+}
+
+#[derive(Serialize)]
+pub struct BoardColumn {
+    status: String,
+    #[serde(flatten)]
+    page: TaskPage,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::ERR_CODE_POSITION;
+
+    fn page_request(query: serde_json::Value) -> GetTaskPageRequest {
+        serde_json::from_value(query).unwrap()
+    }
+
+    #[test]
+    fn board_has_column_per_status() {
+        let statuses: Vec<_> = (0..)
+            .map_while(|x| ts::TaskStatus::try_from(x).ok())
+            .collect();
+        assert_eq!(COLUMNS.len(), statuses.len());
+        assert!(statuses.iter().all(|x| COLUMNS.contains(x)));
+    }
+
+    #[test]
+    fn columns_have_their_own_status_and_order() {
+        let req = page_request(serde_json::json!({"page_size": 10, "assigned_to": "jane"}));
+        assert!(req.is_first_page());
+        assert!(req.board_column(ts::TaskStatus::Open).is_ok());
+        for query in [
+            serde_json::json!({"status": "Open"}),
+            serde_json::json!({"sort": "title"}),
+            serde_json::json!({"order": "desc"}),
+            serde_json::json!({"include_total": false}),
+        ] {
+            assert!(
+                page_request(query.clone())
+                    .board_column(ts::TaskStatus::Open)
+                    .is_err(),
+                "{}",
+                query
+            );
+        }
+    }
+
+    #[test]
+    fn position_outside_of_column_conflicts() {
+        let req: MoveTaskRequest = serde_json::from_str(r#"{"status": "Testing"}"#).unwrap();
+        assert_eq!(req.status, "Testing");
+        assert_eq!(req.after_id, None);
+        let e = AppError::from(ts::Error {
+            code: ERR_CODE_POSITION,
+            message: "Task to place after isn't in the target column".to_string(),
+        });
+        assert!(matches!(e, AppError::InvalidBoardPosition));
+    }
+}
@@ -0,0 +1,238 @@
+use crate::common::{AppClaims, AppError, AppState, AppStateRef};
+use crate::proto::tasks_service as ts;
+use crate::users::{ensure_user_exists, Profiles, UserProfile};
+use axum::{
+    extract::{Path, State},
+    response::Result,
+    Json,
+};
+use log::info;
+use serde::{Deserialize, Serialize};
+
+const MAX_TEXT_LENGTH: usize = 500;
+
+pub async fn get_checklist_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+) -> Result<Json<Vec<ChecklistItem>>, AppError> {
+    info!("get_checklist_handler: handling get checklist request");
+    let request = tonic::Request::new(ts::GetTaskRequest {
+        user_id: claims.username,
+        task_id,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .get_checklist(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_checklist(&state, response.into_inner()).await
+}
+
+/// Adds item to the end of the checklist.
+pub async fn add_checklist_item_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+    Json(req): Json<AddChecklistItemRequest>,
+) -> Result<Json<Vec<ChecklistItem>>, AppError> {
+    info!("add_checklist_item_handler: handling add checklist item request");
+    check_text(&req.text)?;
+    if let Some(x) = &req.assigned_to {
+        ensure_user_exists(&state.user_database, x).await?;
+    }
+    let request = tonic::Request::new(ts::AddChecklistItemRequest {
+        user_id: claims.username,
+        task_id,
+        text: req.text,
+        assigned_to: req.assigned_to,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .add_checklist_item(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_checklist(&state, response.into_inner()).await
+}
+
+/// Changes text of the item, marks it as done or not done, or changes its
+/// assignee. Empty assignee removes it.
+pub async fn update_checklist_item_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path((task_id, item_id)): Path<(String, String)>,
+    Json(req): Json<UpdateChecklistItemRequest>,
+) -> Result<Json<Vec<ChecklistItem>>, AppError> {
+    info!("update_checklist_item_handler: handling update checklist item request");
+    if let Some(x) = &req.new_text {
+        check_text(x)?;
+    }
+    if let Some(x) = req.new_assigned_to.as_deref().filter(|x| !x.is_empty()) {
+        ensure_user_exists(&state.user_database, x).await?;
+    }
+    let request = tonic::Request::new(ts::UpdateChecklistItemRequest {
+        user_id: claims.username,
+        task_id,
+        item_id,
+        new_text: req.new_text,
+        new_done: req.new_done,
+        new_assigned_to: req.new_assigned_to,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .update_checklist_item(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_checklist(&state, response.into_inner()).await
+}
+
+pub async fn move_checklist_item_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path((task_id, item_id)): Path<(String, String)>,
+    Json(req): Json<MoveChecklistItemRequest>,
+) -> Result<Json<Vec<ChecklistItem>>, AppError> {
+    info!("move_checklist_item_handler: handling move checklist item request");
+    if req.index < 0 {
+        return Err(AppError::IncorrectRequest);
+    }
+    let request = tonic::Request::new(ts::MoveChecklistItemRequest {
+        user_id: claims.username,
+        task_id,
+        item_id,
+        index: req.index,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .move_checklist_item(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_checklist(&state, response.into_inner()).await
+}
+
+pub async fn delete_checklist_item_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path((task_id, item_id)): Path<(String, String)>,
+) -> Result<Json<Vec<ChecklistItem>>, AppError> {
+    info!("delete_checklist_item_handler: handling delete checklist item request");
+    let request = tonic::Request::new(ts::ChecklistItemRequest {
+        user_id: claims.username,
+        task_id,
+        item_id,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .delete_checklist_item(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_checklist(&state, response.into_inner()).await
+}
+
+async fn make_checklist(
+    state: &AppState,
+    response: ts::ChecklistResponse,
+) -> Result<Json<Vec<ChecklistItem>>, AppError> {
+    let items = match response.response.unwrap() {
+        ts::checklist_response::Response::Checklist(x) => x.items,
+        ts::checklist_response::Response::Error(e) => return Err(e.into()),
+    };
+    let usernames: Vec<&str> = items
+        .iter()
+        .filter_map(|x| x.assigned_to.as_deref())
+        .collect();
+    let profiles = Profiles::fetch(&state.user_database, &usernames).await?;
+    Ok(Json(
+        items
+            .into_iter()
+            .map(|x| ChecklistItem {
+                assigned_to: x.assigned_to.map(|u| profiles.get(&u)),
+                id: x.id,
+                text: x.text,
+                done: x.done,
+                created_at: x.created_at.unwrap().to_string(),
+            })
+            .collect(),
+    ))
+}
+
+pub fn check_text(text: &str) -> Result<(), AppError> {
+    if text.trim().is_empty() || text.chars().count() > MAX_TEXT_LENGTH {
+        return Err(AppError::IncorrectRequest);
+    }
+    Ok(())
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AddChecklistItemRequest {
+    text: String,
+    assigned_to: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateChecklistItemRequest {
+    new_text: Option<String>,
+    new_done: Option<bool>,
+    new_assigned_to: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MoveChecklistItemRequest {
+    /// New zero-based index of the item, items past the end go to the end
+    index: i32,
+}
+
+#[derive(Serialize)]
+pub struct ChecklistItem {
+    id: String,
+    text: String,
+    done: bool,
+    assigned_to: Option<UserProfile>,
+    created_at: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_is_not_blank_and_short() {
+        assert!(check_text("Write tests").is_ok());
+        assert!(check_text(&"é".repeat(MAX_TEXT_LENGTH)).is_ok());
+        for x in ["", " \n\t", &"a".repeat(MAX_TEXT_LENGTH + 1)] {
+            assert!(
+                matches!(check_text(x), Err(AppError::IncorrectRequest)),
+                "{:?}",
+                x
+            );
+        }
+    }
+
+    #[test]
+    fn requests_with_unknown_fields_are_rejected() {
+        let req: MoveChecklistItemRequest = serde_json::from_str(r#"{"index": 2}"#).unwrap();
+        assert_eq!(req.index, 2);
+        let req: UpdateChecklistItemRequest =
+            serde_json::from_str(r#"{"new_done": true}"#).unwrap();
+        assert_eq!(req.new_done, Some(true));
+        assert!(req.new_text.is_none() && req.new_assigned_to.is_none());
+        assert!(serde_json::from_str::<UpdateChecklistItemRequest>(r#"{"done": true}"#).is_err());
+        assert!(
+            serde_json::from_str::<AddChecklistItemRequest>(r#"{"text": "x", "position": 1}"#)
+                .is_err()
+        );
+    }
+}
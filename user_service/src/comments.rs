@@ -0,0 +1,216 @@
+use crate::common::{AppClaims, AppError, AppState, AppStateRef};
+use crate::proto::tasks_service as ts;
+use crate::tasks::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+use crate::users::{Profiles, UserProfile};
+use axum::{
+    extract::{Path, Query, State},
+    response::Result,
+    Json,
+};
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub async fn create_comment_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+    Json(req): Json<CreateCommentRequest>,
+) -> Result<Json<Comment>, AppError> {
+    info!("create_comment_handler: handling create comment request");
+    if req.text.trim().is_empty() {
+        return Err(AppError::IncorrectRequest);
+    }
+    let request = tonic::Request::new(ts::CreateCommentRequest {
+        user_id: claims.username,
+        task_id,
+        parent_id: req.parent_id,
+        text: req.text,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .create_comment(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_comment(&state, response.into_inner()).await
+}
+
+pub async fn update_comment_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path((task_id, comment_id)): Path<(String, String)>,
+    Json(req): Json<UpdateCommentRequest>,
+) -> Result<Json<Comment>, AppError> {
+    info!("update_comment_handler: handling update comment request");
+    if req.text.trim().is_empty() {
+        return Err(AppError::IncorrectRequest);
+    }
+    let request = tonic::Request::new(ts::UpdateCommentRequest {
+        user_id: claims.username,
+        task_id,
+        comment_id,
+        new_text: req.text,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .update_comment(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_comment(&state, response.into_inner()).await
+}
+
+pub async fn delete_comment_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path((task_id, comment_id)): Path<(String, String)>,
+) -> Result<Json<Comment>, AppError> {
+    info!("delete_comment_handler: handling delete comment request");
+    let request = tonic::Request::new(ts::DeleteCommentRequest {
+        user_id: claims.username,
+        task_id,
+        comment_id,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .delete_comment(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_comment(&state, response.into_inner()).await
+}
+
+pub async fn get_comment_page_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+    Query(req): Query<GetCommentPageRequest>,
+) -> Result<Json<CommentPage>, AppError> {
+    info!("get_comment_page_handler: handling get comment page request");
+    let page_size = req.page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+    let offset = req.offset.unwrap_or(0);
+    if !(1..=MAX_PAGE_SIZE).contains(&page_size) || offset < 0 {
+        return Err(AppError::IncorrectRequest);
+    }
+    let request = tonic::Request::new(ts::GetCommentPageRequest {
+        user_id: claims.username,
+        task_id,
+        offset,
+        page_size,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .get_comment_page(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    let page = match response.into_inner().response.unwrap() {
+        ts::comment_page_response::Response::CommentPage(x) => x,
+        ts::comment_page_response::Response::Error(e) => return Err(e.into()),
+    };
+    let profiles = fetch_authors(&state, &page.comments).await?;
+    let mut replies: HashMap<Option<String>, Vec<ts::Comment>> = HashMap::new();
+    for x in page.comments {
+        replies.entry(x.parent_id.clone()).or_default().push(x);
+    }
+    Ok(Json(CommentPage {
+        comments: make_thread(None, &mut replies, &profiles),
+        has_more: page.has_more,
+        replies_truncated: page.replies_truncated,
+    }))
+}
+
+async fn fetch_authors(state: &AppState, comments: &[ts::Comment]) -> Result<Profiles, AppError> {
+    let usernames: Vec<&str> = comments.iter().map(|x| x.author_id.as_str()).collect();
+    Profiles::fetch(&state.user_database, &usernames).await
+}
+
+async fn make_comment(
+    state: &AppState,
+    response: ts::CommentResponse,
+) -> Result<Json<Comment>, AppError> {
+    match response.response.unwrap() {
+        ts::comment_response::Response::Comment(x) => {
+            let profiles = fetch_authors(state, std::slice::from_ref(&x)).await?;
+            Ok(Json(Comment::new(x, &profiles)))
+        }
+        ts::comment_response::Response::Error(e) => Err(e.into()),
+    }
+}
+
+/// Builds tree of replies to `parent` from comments grouped by parent id.
+fn make_thread(
+    parent: Option<String>,
+    replies: &mut HashMap<Option<String>, Vec<ts::Comment>>,
+    profiles: &Profiles,
+) -> Vec<Comment> {
+    replies
+        .remove(&parent)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|x| {
+            let id = x.id.clone();
+            let mut comment = Comment::new(x, profiles);
+            comment.replies = make_thread(Some(id), replies, profiles);
+            comment
+        })
+        .collect()
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CreateCommentRequest {
+    text: String,
+    parent_id: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateCommentRequest {
+    text: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GetCommentPageRequest {
+    offset: Option<i32>,
+    page_size: Option<i32>,
+}
+
+#[derive(Serialize)]
+pub struct Comment {
+    id: String,
+    parent_id: Option<String>,
+    author: UserProfile,
+    text: String,
+    created_at: String,
+    updated_at: Option<String>,
+    replies: Vec<Comment>,
+}
+
+impl Comment {
+    fn new(x: ts::Comment, profiles: &Profiles) -> Self {
+        Comment {
+            id: x.id,
+            parent_id: x.parent_id,
+            author: profiles.get(&x.author_id),
+            text: x.text,
+            created_at: x.created_at.unwrap().to_string(),
+            updated_at: x.updated_at.map(|t| t.to_string()),
+            replies: Vec::new(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct CommentPage {
+    comments: Vec<Comment>,
+    has_more: bool,
+    /// Latest replies are left out since page has too many of them
+    replies_truncated: bool,
+}
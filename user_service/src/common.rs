@@ -1,4 +1,7 @@
-use crate::proto::tasks_service::tasks_service_client::TasksServiceClient;
+use crate::proto::tasks_service::{self as ts, tasks_service_client::TasksServiceClient};
+use crate::statistics::StatisticsClient;
+use crate::storage::Storage;
+use crate::workflow::Workflow;
 use axum::{
     async_trait,
     extract::FromRequestParts,
@@ -12,16 +15,73 @@ use axum_extra::TypedHeader;
 use jwt_simple::prelude::*;
 use serde_json::json;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
 use tonic::transport::Channel;
 
 pub struct AppState {
     pub user_database: tokio_postgres::Client,
     pub jwt_key: HS256Key,
+    /// Key for signing page cursors, it differs from `jwt_key` so cursors
+    /// can't be used as access tokens
+    pub cursor_key: HS256Key,
     pub tasks_service: RwLock<TasksServiceClient<Channel>>,
+    pub workflow: Workflow,
+    pub statistics: Box<dyn StatisticsClient>,
+    /// How long responses for idempotency keys are stored
+    pub idempotency_window: Duration,
+    /// How long deleted tasks are kept in trash
+    pub trash_retention: Duration,
+    /// Refuse to complete tasks which have unfinished blockers
+    pub check_blockers: bool,
+    /// Content of attachments
+    pub storage: Box<dyn Storage>,
+    /// Maximum size of an attachment in bytes
+    pub max_attachment_size: u64,
+    /// MIME types allowed for attachments, `type/*` allows all subtypes
+    pub attachment_types: Vec<String>,
 }
 pub type AppStateRef = Arc<AppState>;
 
+/// Error code returned by tasks_service when user lacks access to the task.
+pub const ERR_CODE_ACCESS: i32 = 2;
+/// Error code returned by tasks_service when task was changed concurrently.
+pub const ERR_CODE_CONFLICT: i32 = 3;
+/// Error code returned by tasks_service when no status change of transition
+/// applies to the task.
+pub const ERR_CODE_TRANSITION: i32 = 4;
+/// Error code returned by tasks_service when task version doesn't match the
+/// expected one.
+pub const ERR_CODE_VERSION: i32 = 5;
+/// Error code returned by tasks_service when label with the same name exists.
+pub const ERR_CODE_LABEL_EXISTS: i32 = 6;
+/// Error code returned by tasks_service when label doesn't exist or belongs to
+/// another user.
+pub const ERR_CODE_LABEL_NOT_FOUND: i32 = 7;
+/// Error code returned by tasks_service when user lacks access to the project.
+pub const ERR_CODE_PROJECT_ACCESS: i32 = 8;
+/// Error code returned by tasks_service when task can't be placed after the
+/// given task on the board.
+pub const ERR_CODE_POSITION: i32 = 9;
+/// Error code returned by tasks_service when subtask or dependency would make
+/// a cycle.
+pub const ERR_CODE_CYCLE: i32 = 10;
+/// Error code returned by tasks_service when task with unfinished blockers is
+/// being completed.
+pub const ERR_CODE_BLOCKED: i32 = 11;
+/// Error code returned by tasks_service when checklist item doesn't exist.
+pub const ERR_CODE_ITEM_NOT_FOUND: i32 = 12;
+/// Error code returned by tasks_service when attachment doesn't exist.
+pub const ERR_CODE_ATTACHMENT_NOT_FOUND: i32 = 13;
+/// Error code returned by tasks_service when time entry doesn't exist or
+/// belongs to another user.
+pub const ERR_CODE_TIME_ENTRY_NOT_FOUND: i32 = 14;
+/// Error code returned by tasks_service when user has no running timer.
+pub const ERR_CODE_TIMER_NOT_RUNNING: i32 = 15;
+/// Error code returned by tasks_service when changed task would start after
+/// it is due.
+pub const ERR_CODE_DATES: i32 = 16;
+
 pub struct AppClaims {
     pub username: String,
 }
@@ -56,11 +116,66 @@ pub enum AppError {
     WrongPassword,
     IncorrectRequest,
     IncorrectDateFormat,
+    StartAfterDue,
+    TransitionNotAllowed,
+    TaskStatusChanged,
+    TaskAccessDenied,
+    InvalidCursor,
+    IdempotencyKeyReused,
+    IdempotencyKeyInProgress,
+    VersionMismatch,
+    LabelAlreadyExists,
+    LabelNotFound,
+    ProjectAccessDenied,
+    InvalidBoardPosition,
+    DependencyCycle,
+    TaskBlocked,
+    ChecklistItemNotFound,
+    AttachmentNotFound,
+    AttachmentTooLarge,
+    AttachmentTypeNotAllowed,
+    StorageUnavailable,
+    InvalidRecurrenceRule,
+    SeriesNotFound,
+    SeriesStopped,
+    TimeEntryNotFound,
+    TimerNotRunning,
+    TemplateNotFound,
+    TemplateAlreadyExists,
+    InvalidTemplatePlaceholder,
+    TemplateVariableMissing,
 }
 
-impl IntoResponse for AppError {
-    fn into_response(self) -> Response {
-        let (status, error_message) = match self {
+impl From<ts::Error> for AppError {
+    fn from(e: ts::Error) -> Self {
+        match e.code {
+            ERR_CODE_ACCESS => AppError::TaskAccessDenied,
+            ERR_CODE_CONFLICT => AppError::TaskStatusChanged,
+            ERR_CODE_TRANSITION => AppError::TransitionNotAllowed,
+            ERR_CODE_VERSION => AppError::VersionMismatch,
+            ERR_CODE_LABEL_EXISTS => AppError::LabelAlreadyExists,
+            ERR_CODE_LABEL_NOT_FOUND => AppError::LabelNotFound,
+            ERR_CODE_PROJECT_ACCESS => AppError::ProjectAccessDenied,
+            ERR_CODE_POSITION => AppError::InvalidBoardPosition,
+            ERR_CODE_CYCLE => AppError::DependencyCycle,
+            ERR_CODE_BLOCKED => AppError::TaskBlocked,
+            ERR_CODE_ITEM_NOT_FOUND => AppError::ChecklistItemNotFound,
+            ERR_CODE_ATTACHMENT_NOT_FOUND => AppError::AttachmentNotFound,
+            ERR_CODE_TIME_ENTRY_NOT_FOUND => AppError::TimeEntryNotFound,
+            ERR_CODE_TIMER_NOT_RUNNING => AppError::TimerNotRunning,
+            ERR_CODE_DATES => AppError::StartAfterDue,
+            _ => AppError::IncorrectRequest,
+        }
+    }
+}
+
+impl AppError {
+    pub fn message(&self) -> &'static str {
+        self.status_and_message().1
+    }
+
+    fn status_and_message(&self) -> (StatusCode, &'static str) {
+        match self {
             AppError::InvalidToken => (StatusCode::FORBIDDEN, "Invalid token"),
             AppError::NonExistingUser => (StatusCode::BAD_REQUEST, "User doesn't exist"),
             AppError::WrongPassword => (StatusCode::FORBIDDEN, "Wrong password"),
@@ -69,7 +184,104 @@ impl IntoResponse for AppError {
                 StatusCode::BAD_REQUEST,
                 "Incorrect date format. Expected YYYY-MM-DD",
             ),
-        };
+            AppError::StartAfterDue => (StatusCode::BAD_REQUEST, "Task can't start after it is due"),
+            AppError::TransitionNotAllowed => (
+                StatusCode::CONFLICT,
+                "Transition is not allowed from current task status",
+            ),
+            AppError::TaskStatusChanged => (
+                StatusCode::CONFLICT,
+                "Task status was changed by another request",
+            ),
+            AppError::TaskAccessDenied => (
+                StatusCode::FORBIDDEN,
+                "Not enough permissions for this task",
+            ),
+            AppError::InvalidCursor => (StatusCode::BAD_REQUEST, "Invalid or expired page cursor"),
+            AppError::IdempotencyKeyReused => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "Idempotency key was already used for a different request",
+            ),
+            AppError::IdempotencyKeyInProgress => (
+                StatusCode::CONFLICT,
+                "Request with this idempotency key is still in progress",
+            ),
+            AppError::VersionMismatch => (
+                StatusCode::PRECONDITION_FAILED,
+                "Task was modified, its version doesn't match If-Match header",
+            ),
+            AppError::LabelAlreadyExists => {
+                (StatusCode::CONFLICT, "Label with this name already exists")
+            }
+            AppError::LabelNotFound => (
+                StatusCode::NOT_FOUND,
+                "Label doesn't exist or belongs to another user",
+            ),
+            AppError::ProjectAccessDenied => (
+                StatusCode::FORBIDDEN,
+                "Not enough permissions for this project",
+            ),
+            AppError::InvalidBoardPosition => (
+                StatusCode::CONFLICT,
+                "Task to place after isn't in the target column",
+            ),
+            AppError::DependencyCycle => (
+                StatusCode::CONFLICT,
+                "Tasks would depend on each other in a cycle",
+            ),
+            AppError::TaskBlocked => (StatusCode::CONFLICT, "Task is blocked by unfinished tasks"),
+            AppError::ChecklistItemNotFound => {
+                (StatusCode::NOT_FOUND, "Checklist item doesn't exist")
+            }
+            AppError::AttachmentNotFound => (StatusCode::NOT_FOUND, "Attachment doesn't exist"),
+            AppError::AttachmentTooLarge => {
+                (StatusCode::PAYLOAD_TOO_LARGE, "Attachment is too large")
+            }
+            AppError::AttachmentTypeNotAllowed => (
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                "Attachments of this type aren't allowed",
+            ),
+            AppError::StorageUnavailable => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Attachment storage is unavailable",
+            ),
+            AppError::InvalidRecurrenceRule => (
+                StatusCode::BAD_REQUEST,
+                "Incorrect recurrence rule. Expected FREQ=DAILY|WEEKLY|MONTHLY with optional INTERVAL, and UNTIL=YYYYMMDD or COUNT",
+            ),
+            AppError::SeriesNotFound => (StatusCode::NOT_FOUND, "Series doesn't exist"),
+            AppError::SeriesStopped => (StatusCode::CONFLICT, "Series is stopped"),
+            AppError::TimeEntryNotFound => (
+                StatusCode::NOT_FOUND,
+                "Time entry doesn't exist or belongs to another user",
+            ),
+            AppError::TimerNotRunning => (
+                StatusCode::CONFLICT,
+                "No timer is running on this task",
+            ),
+            AppError::TemplateNotFound => (
+                StatusCode::NOT_FOUND,
+                "Template doesn't exist or isn't available to the user",
+            ),
+            AppError::TemplateAlreadyExists => (
+                StatusCode::CONFLICT,
+                "Template with this name already exists",
+            ),
+            AppError::InvalidTemplatePlaceholder => (
+                StatusCode::BAD_REQUEST,
+                "Invalid placeholder. Expected {{ name }} with letters, digits and underscores",
+            ),
+            AppError::TemplateVariableMissing => (
+                StatusCode::BAD_REQUEST,
+                "Value of a template placeholder is missing",
+            ),
+        }
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let (status, error_message) = self.status_and_message();
         let body = Json(json!({
             "error": error_message,
         }));
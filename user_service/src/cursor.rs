@@ -0,0 +1,230 @@
+use crate::common::AppError;
+use crate::proto::tasks_service as ts;
+use jwt_simple::prelude::*;
+use prost::Message;
+
+/// Cursors stay valid for a day, after that listing has to start over.
+const CURSOR_LIFETIME_HOURS: u64 = 24;
+
+/// Position in a task list encoded into opaque signed token. Token is bound
+/// to the user and to the scope of the list it was issued for (relation,
+/// filter and sorting), see [`PageCursor::scope`].
+pub enum PageCursor {
+    After(ts::TaskCursor),
+    Before(ts::TaskCursor),
+}
+
+#[derive(Serialize, Deserialize)]
+struct CursorClaims {
+    scope: u32,
+    before: bool,
+    seconds: i64,
+    nanos: i32,
+    id: String,
+    title: String,
+    status: i32,
+    #[serde(default)]
+    due_date: Option<String>,
+    #[serde(default)]
+    priority: i32,
+    #[serde(default)]
+    position: i64,
+}
+
+impl PageCursor {
+    /// Creates cursor pointing to the given task.
+    pub fn after(task: &ts::Task) -> Self {
+        PageCursor::After(Self::task_cursor(task))
+    }
+
+    pub fn before(task: &ts::Task) -> Self {
+        PageCursor::Before(Self::task_cursor(task))
+    }
+
+    fn task_cursor(task: &ts::Task) -> ts::TaskCursor {
+        ts::TaskCursor {
+            created_at: task.created_at.clone(),
+            id: task.id.clone(),
+            title: task.title.clone(),
+            status: task.status,
+            due_date: task.due_date.clone(),
+            priority: task.priority,
+            position: task.position,
+        }
+    }
+
+    /// Checksum of listing parameters which cursor depends on. `request` must
+    /// not contain cursors and page size.
+    pub fn scope(request: &ts::GetTaskPageRequest) -> u32 {
+        crc32c::crc32c(&request.encode_to_vec())
+    }
+
+    pub fn encode(&self, key: &HS256Key, username: &str, scope: u32) -> String {
+        let (before, cursor) = match self {
+            PageCursor::After(x) => (false, x),
+            PageCursor::Before(x) => (true, x),
+        };
+        let created_at = cursor.created_at.clone().unwrap_or_default();
+        let claims = Claims::with_custom_claims(
+            CursorClaims {
+                scope,
+                before,
+                seconds: created_at.seconds,
+                nanos: created_at.nanos,
+                id: cursor.id.clone(),
+                title: cursor.title.clone(),
+                status: cursor.status,
+                due_date: cursor.due_date.clone(),
+                priority: cursor.priority,
+                position: cursor.position,
+            },
+            Duration::from_hours(CURSOR_LIFETIME_HOURS),
+        )
+        .with_subject(username);
+        key.authenticate(claims).unwrap()
+    }
+
+    pub fn decode(
+        token: &str,
+        key: &HS256Key,
+        username: &str,
+        scope: u32,
+    ) -> Result<Self, AppError> {
+        let options = VerificationOptions {
+            required_subject: Some(username.to_string()),
+            ..Default::default()
+        };
+        let claims = key
+            .verify_token::<CursorClaims>(token, Some(options))
+            .map_err(|_| AppError::InvalidCursor)?
+            .custom;
+        if claims.scope != scope {
+            return Err(AppError::InvalidCursor);
+        }
+        let cursor = ts::TaskCursor {
+            created_at: Some(prost_types::Timestamp {
+                seconds: claims.seconds,
+                nanos: claims.nanos,
+            }),
+            id: claims.id,
+            title: claims.title,
+            status: claims.status,
+            due_date: claims.due_date,
+            priority: claims.priority,
+            position: claims.position,
+        };
+        Ok(if claims.before {
+            PageCursor::Before(cursor)
+        } else {
+            PageCursor::After(cursor)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task() -> ts::Task {
+        ts::Task {
+            id: "abcdefghijk".to_string(),
+            created_at: Some(prost_types::Timestamp {
+                seconds: 1_700_000_000,
+                nanos: 500,
+            }),
+            title: "Title".to_string(),
+            status: ts::TaskStatus::Testing.into(),
+            due_date: Some("2024-01-31".to_string()),
+            priority: ts::TaskPriority::High.into(),
+            position: 2048,
+            ..Default::default()
+        }
+    }
+
+    fn scope(relation: ts::TaskRelation) -> u32 {
+        PageCursor::scope(&ts::GetTaskPageRequest {
+            relation: relation.into(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn cursor_is_decoded_as_encoded() {
+        let key = HS256Key::generate();
+        let scope = scope(ts::TaskRelation::CreatedOrShared);
+        let token = PageCursor::before(&task()).encode(&key, "alice", scope);
+        match PageCursor::decode(&token, &key, "alice", scope) {
+            Ok(PageCursor::Before(x)) => assert_eq!(x, PageCursor::task_cursor(&task())),
+            _ => panic!("cursor isn't decoded"),
+        }
+        let token = PageCursor::after(&task()).encode(&key, "alice", scope);
+        assert!(matches!(
+            PageCursor::decode(&token, &key, "alice", scope),
+            Ok(PageCursor::After(_))
+        ));
+    }
+
+    #[test]
+    fn cursor_of_other_user_is_rejected() {
+        let key = HS256Key::generate();
+        let scope = scope(ts::TaskRelation::CreatedOrShared);
+        let token = PageCursor::after(&task()).encode(&key, "alice", scope);
+        assert!(matches!(
+            PageCursor::decode(&token, &key, "bob", scope),
+            Err(AppError::InvalidCursor)
+        ));
+    }
+
+    #[test]
+    fn cursor_of_other_scope_is_rejected() {
+        let key = HS256Key::generate();
+        let token = PageCursor::after(&task()).encode(
+            &key,
+            "alice",
+            scope(ts::TaskRelation::CreatedOrShared),
+        );
+        assert!(matches!(
+            PageCursor::decode(&token, &key, "alice", scope(ts::TaskRelation::Assigned)),
+            Err(AppError::InvalidCursor)
+        ));
+    }
+
+    #[test]
+    fn cursor_signed_with_other_key_is_rejected() {
+        let scope = scope(ts::TaskRelation::CreatedOrShared);
+        let token = PageCursor::after(&task()).encode(&HS256Key::generate(), "alice", scope);
+        assert!(matches!(
+            PageCursor::decode(&token, &HS256Key::generate(), "alice", scope),
+            Err(AppError::InvalidCursor)
+        ));
+        assert!(matches!(
+            PageCursor::decode("garbage", &HS256Key::generate(), "alice", scope),
+            Err(AppError::InvalidCursor)
+        ));
+    }
+
+    #[test]
+    fn scope_depends_on_filter_and_sorting() {
+        let request = |text: &str, sort_field: ts::TaskSortField| ts::GetTaskPageRequest {
+            filter: Some(ts::TaskFilter {
+                text: Some(text.to_string()),
+                ..Default::default()
+            }),
+            sort_field: sort_field.into(),
+            ..Default::default()
+        };
+        let scope = |x| PageCursor::scope(&x);
+        assert_eq!(
+            scope(request("a", ts::TaskSortField::Title)),
+            scope(request("a", ts::TaskSortField::Title))
+        );
+        assert_ne!(
+            scope(request("a", ts::TaskSortField::Title)),
+            scope(request("b", ts::TaskSortField::Title))
+        );
+        assert_ne!(
+            scope(request("a", ts::TaskSortField::Title)),
+            scope(request("a", ts::TaskSortField::Status))
+        );
+    }
+}
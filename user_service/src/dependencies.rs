@@ -0,0 +1,188 @@
+use crate::common::{AppClaims, AppError, AppState, AppStateRef};
+use crate::etag::if_match_version;
+use crate::events;
+use crate::proto::events::{self as ev, event::Payload};
+use crate::proto::tasks_service as ts;
+use crate::tasks::{make_task, make_tasks, status_name, tagged, Task};
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+    response::{Response, Result},
+    Json,
+};
+use log::info;
+use serde::{Deserialize, Serialize};
+
+/// Makes the task a subtask of another task, or a top-level task if parent
+/// isn't set.
+pub async fn set_task_parent_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+    headers: HeaderMap,
+    Json(req): Json<SetTaskParentRequest>,
+) -> Result<Response, AppError> {
+    info!("set_task_parent_handler: handling set task parent request");
+    let request = tonic::Request::new(ts::SetTaskParentRequest {
+        user_id: claims.username.clone(),
+        task_id,
+        parent_id: req.parent_id,
+        expected_version: if_match_version(&headers)?,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .set_task_parent(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    match response.into_inner().response.unwrap() {
+        ts::task_response::Response::Task(x) => {
+            events::emit(
+                &state,
+                Payload::TaskUpdated(ev::TaskUpdated {
+                    task_id: x.id.clone(),
+                    user_id: claims.username,
+                    title: x.title.clone(),
+                    status: status_name(x.status),
+                }),
+            )
+            .await?;
+            Ok(tagged(make_task(&state, x).await?))
+        }
+        ts::task_response::Response::Error(e) => Err(e.into()),
+    }
+}
+
+pub async fn get_task_dependencies_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+) -> Result<Json<TaskDependencies>, AppError> {
+    info!("get_task_dependencies_handler: handling get task dependencies request");
+    let request = tonic::Request::new(ts::GetTaskRequest {
+        user_id: claims.username,
+        task_id,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .get_task_dependencies(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_dependencies(&state, response.into_inner()).await
+}
+
+pub async fn add_task_dependency_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+    Json(req): Json<AddTaskDependencyRequest>,
+) -> Result<Json<TaskDependencies>, AppError> {
+    info!("add_task_dependency_handler: handling add task dependency request");
+    let request = tonic::Request::new(ts::TaskDependencyRequest {
+        user_id: claims.username,
+        task_id,
+        blocker_id: req.blocker_id,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .add_task_dependency(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_dependencies(&state, response.into_inner()).await
+}
+
+pub async fn remove_task_dependency_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path((task_id, blocker_id)): Path<(String, String)>,
+) -> Result<Json<TaskDependencies>, AppError> {
+    info!("remove_task_dependency_handler: handling remove task dependency request");
+    let request = tonic::Request::new(ts::TaskDependencyRequest {
+        user_id: claims.username,
+        task_id,
+        blocker_id,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .remove_task_dependency(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_dependencies(&state, response.into_inner()).await
+}
+
+async fn make_dependencies(
+    state: &AppState,
+    response: ts::TaskDependenciesResponse,
+) -> Result<Json<TaskDependencies>, AppError> {
+    match response.response.unwrap() {
+        ts::task_dependencies_response::Response::Dependencies(x) => Ok(Json(TaskDependencies {
+            blocked_by: make_tasks(state, x.blocked_by).await?,
+            blocks: make_tasks(state, x.blocks).await?,
+        })),
+        ts::task_dependencies_response::Response::Error(e) => Err(e.into()),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SetTaskParentRequest {
+    parent_id: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AddTaskDependencyRequest {
+    blocker_id: String,
+}
+
+/// Dependencies of the task, only tasks visible to the user are listed.
+#[derive(Serialize)]
+pub struct TaskDependencies {
+    /// Tasks which have to be finished before the task can be completed
+    blocked_by: Vec<Task>,
+    /// Tasks which are blocked by the task
+    blocks: Vec<Task>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{ERR_CODE_BLOCKED, ERR_CODE_CYCLE};
+    use axum::{http::StatusCode, response::IntoResponse};
+
+    fn error(code: i32) -> AppError {
+        AppError::from(ts::Error {
+            code,
+            message: String::new(),
+        })
+    }
+
+    #[test]
+    fn cycles_and_blocked_completion_conflict() {
+        let e = error(ERR_CODE_CYCLE);
+        assert!(matches!(e, AppError::DependencyCycle));
+        assert_eq!(e.into_response().status(), StatusCode::CONFLICT);
+        let e = error(ERR_CODE_BLOCKED);
+        assert!(matches!(e, AppError::TaskBlocked));
+        assert_eq!(e.into_response().status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn task_without_parent_is_top_level() {
+        let req: SetTaskParentRequest =
+            serde_json::from_str(r#"{"parent_id": "Kq3Zb1xYw0A"}"#).unwrap();
+        assert_eq!(req.parent_id.as_deref(), Some("Kq3Zb1xYw0A"));
+        for x in ["{}", r#"{"parent_id": null}"#] {
+            let req: SetTaskParentRequest = serde_json::from_str(x).unwrap();
+            assert_eq!(req.parent_id, None);
+        }
+        assert!(serde_json::from_str::<AddTaskDependencyRequest>("{}").is_err());
+    }
+}
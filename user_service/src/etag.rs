@@ -0,0 +1,117 @@
+use crate::common::AppError;
+use crate::markdown::DescriptionFormat;
+use axum::http::{header, HeaderMap, HeaderValue};
+
+/// Strong entity tag of the task version.
+pub fn etag(version: i64) -> HeaderValue {
+    HeaderValue::from_str(&format!("\"{}\"", version)).unwrap()
+}
+
+/// Weak entity tag of the task version in the description format. Profiles
+/// and checklist of the task aren't covered by its version, so the tag isn't
+/// strong.
+pub fn format_etag(version: i64, format: DescriptionFormat) -> HeaderValue {
+    let format = match format {
+        DescriptionFormat::Markdown => "markdown",
+        DescriptionFormat::Html => "html",
+    };
+    HeaderValue::from_str(&format!("W/\"{}-{}\"", version, format)).unwrap()
+}
+
+/// Version of the task from `If-Match` header which modification of the task
+/// is conditional on, `None` if any version matches. Only single entity tag is
+/// supported, tags which can't match any version fail the precondition.
+pub fn if_match_version(headers: &HeaderMap) -> Result<Option<i64>, AppError> {
+    let Some(value) = headers.get(header::IF_MATCH) else {
+        return Ok(None);
+    };
+    let value = value
+        .to_str()
+        .map_err(|_| AppError::IncorrectRequest)?
+        .trim();
+    if value == "*" {
+        return Ok(None);
+    }
+    // Weak tags never match since If-Match uses strong comparison
+    value
+        .strip_prefix('"')
+        .and_then(|x| x.strip_suffix('"'))
+        .and_then(|x| x.parse().ok())
+        .map(Some)
+        .ok_or(AppError::VersionMismatch)
+}
+
+/// Checks if `If-None-Match` header matches the tag using weak comparison, so
+/// client's copy of the task is up to date.
+pub fn is_not_modified(headers: &HeaderMap, tag: &HeaderValue) -> bool {
+    let tag = tag.to_str().unwrap().trim_start_matches("W/");
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|x| x.to_str().ok())
+        .flat_map(|x| x.split(','))
+        .map(str::trim)
+        .any(|x| x == "*" || x.trim_start_matches("W/") == tag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(name: header::HeaderName, values: &[&str]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for x in values {
+            headers.append(&name, HeaderValue::from_str(x).unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn etag_is_quoted_version() {
+        assert_eq!(etag(42), "\"42\"");
+    }
+
+    #[test]
+    fn format_etag_is_weak_and_has_format() {
+        assert_eq!(
+            format_etag(42, DescriptionFormat::Markdown),
+            "W/\"42-markdown\""
+        );
+        assert_eq!(format_etag(42, DescriptionFormat::Html), "W/\"42-html\"");
+    }
+
+    #[test]
+    fn if_match_has_version_of_strong_tag() {
+        let version = |x: &str| if_match_version(&headers(header::IF_MATCH, &[x]));
+        assert_eq!(version(" \"7\" ").unwrap(), Some(7));
+        assert_eq!(version("*").unwrap(), None);
+        assert_eq!(if_match_version(&HeaderMap::new()).unwrap(), None);
+    }
+
+    #[test]
+    fn if_match_with_unmatchable_tag_fails() {
+        for x in ["W/\"7\"", "7", "\"seven\"", "\"7\", \"8\"", "\"7"] {
+            assert!(
+                matches!(
+                    if_match_version(&headers(header::IF_MATCH, &[x])),
+                    Err(AppError::VersionMismatch)
+                ),
+                "{}",
+                x
+            );
+        }
+    }
+
+    #[test]
+    fn if_none_match_matches_any_listed_tag() {
+        let tag = format_etag(7, DescriptionFormat::Html);
+        let not_modified = |x: &[&str]| is_not_modified(&headers(header::IF_NONE_MATCH, x), &tag);
+        assert!(not_modified(&["W/\"7-html\""]));
+        assert!(not_modified(&["\"5-html\", \"7-html\""]));
+        assert!(not_modified(&["W/\"5-html\"", "W/\"7-html\""]));
+        assert!(not_modified(&["*"]));
+        assert!(!not_modified(&["W/\"7-markdown\", \"7\""]));
+        assert!(!not_modified(&["W/\"5-html\", W/\"70-html\""]));
+        assert!(!not_modified(&[]));
+    }
+}
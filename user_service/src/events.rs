@@ -0,0 +1,223 @@
+use crate::common::{AppError, AppState, AppStateRef};
+use crate::proto::events::{self as ev, event::Payload};
+use axum::async_trait;
+use log::{error, info};
+use prost::Message;
+use std::time::{Duration, SystemTime};
+use tokio::time::sleep;
+use uuid::Uuid;
+
+/// Version of the schema in `api/events.proto`. It must be increased on
+/// incompatible changes of events.
+pub const SCHEMA_VERSION: u32 = 1;
+
+pub const USER_EVENTS_TOPIC: &str = "user-events";
+pub const TASK_EVENTS_TOPIC: &str = "task-events";
+
+const RELAY_BATCH_SIZE: i64 = 100;
+const RELAY_POLL_INTERVAL: Duration = Duration::from_millis(500);
+const RELAY_RETRY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Destination of domain events, e.g. message broker.
+#[async_trait]
+pub trait EventPublisher: Send + Sync {
+    async fn publish(&self, topic: &str, key: &str, payload: &[u8]) -> Result<(), String>;
+}
+
+/// Event as it is stored in the outbox of user database.
+pub struct OutboxEvent {
+    pub topic: &'static str,
+    pub key: String,
+    pub payload: Vec<u8>,
+}
+
+/// Wraps payload into event of the current schema version.
+pub fn outbox_event(payload: Payload) -> OutboxEvent {
+    let (topic, key) = match &payload {
+        Payload::UserRegistered(x) => (USER_EVENTS_TOPIC, &x.username),
+        Payload::UserUpdated(x) => (USER_EVENTS_TOPIC, &x.username),
+        Payload::TaskCreated(x) => (TASK_EVENTS_TOPIC, &x.task_id),
+        Payload::TaskUpdated(x) => (TASK_EVENTS_TOPIC, &x.task_id),
+        Payload::TaskDeleted(x) => (TASK_EVENTS_TOPIC, &x.task_id),
+        Payload::TaskViewed(x) => (TASK_EVENTS_TOPIC, &x.task_id),
+        Payload::TaskLiked(x) => (TASK_EVENTS_TOPIC, &x.task_id),
+        Payload::TaskRestored(x) => (TASK_EVENTS_TOPIC, &x.task_id),
+        Payload::TaskPurged(x) => (TASK_EVENTS_TOPIC, &x.task_id),
+        Payload::TaskDueSoon(x) => (TASK_EVENTS_TOPIC, &x.task_id),
+    };
+    let key = key.clone();
+    let event = ev::Event {
+        schema_version: SCHEMA_VERSION,
+        id: Uuid::new_v4().to_string(),
+        occurred_at: Some(SystemTime::now().into()),
+        payload: Some(payload),
+    };
+    OutboxEvent {
+        topic,
+        key,
+        payload: event.encode_to_vec(),
+    }
+}
+
+/// Stores event in the outbox of user database. It is published later by
+/// [`run_outbox_relay`], so events survive unavailability of the broker.
+/// Changes of tasks are made by tasks service, so failure to store their
+/// event is returned to the caller instead of being reported as success.
+///
+/// Events of changes in user database are stored by the statement which
+/// makes the change instead, see [`outbox_event`].
+pub async fn emit(state: &AppState, payload: Payload) -> Result<(), AppError> {
+    let event = outbox_event(payload);
+    state
+        .user_database
+        .execute(
+            "INSERT INTO event_outbox (topic, key, payload) VALUES ($1, $2, $3)",
+            &[&event.topic, &event.key, &event.payload],
+        )
+        .await
+        .map_err(|e| {
+            error!("emit: couldn't store event in outbox: {}", e);
+            AppError::IncorrectRequest
+        })?;
+    Ok(())
+}
+
+/// Publishes events from the outbox in order of their creation. Event is
+/// removed from the outbox only after publisher accepted it, so delivery is
+/// at least once. Without publisher events stay in the outbox.
+pub async fn run_outbox_relay(state: AppStateRef, publisher: Box<dyn EventPublisher>) {
+    info!("run_outbox_relay: started");
+    loop {
+        match relay_batch(&state, publisher.as_ref()).await {
+            Ok(0) => sleep(RELAY_POLL_INTERVAL).await,
+            Ok(_) => {}
+            Err(e) => {
+                error!(
+                    "run_outbox_relay: couldn't publish events: {}. Retrying ...",
+                    e
+                );
+                sleep(RELAY_RETRY_INTERVAL).await;
+            }
+        }
+    }
+}
+
+async fn relay_batch(state: &AppState, publisher: &dyn EventPublisher) -> Result<usize, String> {
+    let rows = state
+        .user_database
+        .query(
+            "SELECT id, topic, key, payload FROM event_outbox ORDER BY id LIMIT $1",
+            &[&RELAY_BATCH_SIZE],
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    for row in &rows {
+        let id: i64 = row.get(0);
+        publisher
+            .publish(row.get(1), row.get(2), row.get(3))
+            .await?;
+        state
+            .user_database
+            .execute("DELETE FROM event_outbox WHERE id=$1", &[&id])
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(rows.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+    use tokio::sync::Mutex;
+
+    /// Publisher which keeps last `capacity` events in memory.
+    struct InMemoryPublisher {
+        capacity: usize,
+        events: Mutex<VecDeque<PublishedEvent>>,
+    }
+
+    #[derive(Clone)]
+    struct PublishedEvent {
+        topic: String,
+        key: String,
+        event: ev::Event,
+    }
+
+    impl InMemoryPublisher {
+        fn new(capacity: usize) -> Self {
+            InMemoryPublisher {
+                capacity,
+                events: Mutex::new(VecDeque::with_capacity(capacity)),
+            }
+        }
+
+        async fn events(&self) -> Vec<PublishedEvent> {
+            self.events.lock().await.iter().cloned().collect()
+        }
+    }
+
+    #[async_trait]
+    impl EventPublisher for InMemoryPublisher {
+        async fn publish(&self, topic: &str, key: &str, payload: &[u8]) -> Result<(), String> {
+            let event = ev::Event::decode(payload).map_err(|e| e.to_string())?;
+            let mut events = self.events.lock().await;
+            if events.len() == self.capacity {
+                events.pop_front();
+            }
+            events.push_back(PublishedEvent {
+                topic: topic.to_string(),
+                key: key.to_string(),
+                event,
+            });
+            Ok(())
+        }
+    }
+
+    fn task_created(task_id: &str) -> Payload {
+        Payload::TaskCreated(ev::TaskCreated {
+            task_id: task_id.to_string(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn outbox_event_is_keyed_by_entity() {
+        let event = outbox_event(Payload::UserUpdated(ev::UserUpdated {
+            username: "alice".to_string(),
+        }));
+        assert_eq!(event.topic, USER_EVENTS_TOPIC);
+        assert_eq!(event.key, "alice");
+
+        let event = outbox_event(task_created("task"));
+        assert_eq!(event.topic, TASK_EVENTS_TOPIC);
+        assert_eq!(event.key, "task");
+        let decoded = ev::Event::decode(event.payload.as_slice()).unwrap();
+        assert_eq!(decoded.schema_version, SCHEMA_VERSION);
+        assert_eq!(decoded.payload, Some(task_created("task")));
+    }
+
+    #[tokio::test]
+    async fn in_memory_publisher_keeps_last_events() {
+        let publisher = InMemoryPublisher::new(2);
+        for id in ["a", "b", "c"] {
+            let event = outbox_event(task_created(id));
+            publisher
+                .publish(event.topic, &event.key, &event.payload)
+                .await
+                .unwrap();
+        }
+        let events = publisher.events().await;
+        let keys: Vec<_> = events.iter().map(|x| x.key.as_str()).collect();
+        assert_eq!(keys, ["b", "c"]);
+        assert!(events.iter().all(|x| x.topic == TASK_EVENTS_TOPIC));
+        assert_eq!(events[1].event.payload, Some(task_created("c")));
+    }
+
+    #[tokio::test]
+    async fn in_memory_publisher_rejects_malformed_payload() {
+        let publisher = InMemoryPublisher::new(2);
+        assert!(publisher.publish("topic", "key", &[0xff]).await.is_err());
+        assert!(publisher.events().await.is_empty());
+    }
+}
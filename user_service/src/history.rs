@@ -0,0 +1,203 @@
+use crate::common::{AppClaims, AppError, AppStateRef};
+use crate::etag::if_match_version;
+use crate::events;
+use crate::proto::events::{self as ev, event::Payload};
+use crate::proto::tasks_service as ts;
+use crate::tasks::{make_task, status_name, tagged, DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+use crate::users::{Profiles, UserProfile};
+use axum::{
+    extract::{Path, Query, State},
+    http::HeaderMap,
+    response::{Response, Result},
+    Json,
+};
+use log::info;
+use serde::{Deserialize, Serialize};
+
+/// Lists changes of the task from the latest ones.
+pub async fn get_task_history_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+    Query(req): Query<GetTaskHistoryRequest>,
+) -> Result<Json<TaskHistory>, AppError> {
+    info!("get_task_history_handler: handling get task history request");
+    let page_size = req.page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+    if !(1..=MAX_PAGE_SIZE).contains(&page_size) {
+        return Err(AppError::IncorrectRequest);
+    }
+    let request = tonic::Request::new(ts::GetTaskHistoryRequest {
+        user_id: claims.username,
+        task_id,
+        before_id: req.before_id.unwrap_or(0),
+        page_size,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .get_task_history(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    let history = match response.into_inner().response.unwrap() {
+        ts::task_history_response::Response::History(x) => x,
+        ts::task_history_response::Response::Error(e) => return Err(e.into()),
+    };
+    let usernames: Vec<&str> = history
+        .changes
+        .iter()
+        .map(|x| x.actor_id.as_str())
+        .collect();
+    let profiles = Profiles::fetch(&state.user_database, &usernames).await?;
+    Ok(Json(TaskHistory {
+        changes: history
+            .changes
+            .into_iter()
+            .map(|x| TaskChange::new(x, &profiles))
+            .collect(),
+        has_more: history.has_more,
+    }))
+}
+
+/// Restores title, description, assignee and reviewer of the task to their
+/// values at given version. Restoring is recorded as a new version.
+pub async fn restore_task_version_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+    headers: HeaderMap,
+    Json(req): Json<RestoreTaskVersionRequest>,
+) -> Result<Response, AppError> {
+    info!("restore_task_version_handler: handling restore task version request");
+    let request = tonic::Request::new(ts::RestoreTaskVersionRequest {
+        user_id: claims.username.clone(),
+        task_id,
+        version: req.version,
+        expected_version: if_match_version(&headers)?,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .restore_task_version(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    match response.into_inner().response.unwrap() {
+        ts::task_response::Response::Task(x) => {
+            events::emit(
+                &state,
+                Payload::TaskUpdated(ev::TaskUpdated {
+                    task_id: x.id.clone(),
+                    user_id: claims.username,
+                    title: x.title.clone(),
+                    status: status_name(x.status),
+                }),
+            )
+            .await?;
+            Ok(tagged(make_task(&state, x).await?))
+        }
+        ts::task_response::Response::Error(e) => Err(e.into()),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GetTaskHistoryRequest {
+    /// Id of the change which listing starts before
+    before_id: Option<i64>,
+    page_size: Option<i32>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RestoreTaskVersionRequest {
+    version: i64,
+}
+
+#[derive(Serialize)]
+pub struct TaskChange {
+    id: i64,
+    version: i64,
+    actor: UserProfile,
+    changed_at: String,
+    action: String,
+    fields: Vec<FieldChange>,
+}
+
+impl TaskChange {
+    fn new(x: ts::TaskChange, profiles: &Profiles) -> Self {
+        TaskChange {
+            id: x.id,
+            version: x.version,
+            actor: profiles.get(&x.actor_id),
+            action: x.action().as_str_name().to_string(),
+            changed_at: x.changed_at.unwrap().to_string(),
+            fields: x
+                .fields
+                .into_iter()
+                .map(|x| FieldChange {
+                    field: x.field,
+                    old_value: x.old_value,
+                    new_value: x.new_value,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct FieldChange {
+    field: String,
+    old_value: Option<String>,
+    new_value: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct TaskHistory {
+    changes: Vec<TaskChange>,
+    has_more: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn change_has_actor_action_and_fields() {
+        let change = ts::TaskChange {
+            id: 7,
+            version: 3,
+            actor_id: "jane".to_string(),
+            changed_at: Some(prost_types::Timestamp {
+                seconds: 1709251200,
+                nanos: 0,
+            }),
+            action: ts::TaskChangeAction::Restored.into(),
+            fields: vec![ts::FieldChange {
+                field: "assigned_to".to_string(),
+                old_value: Some("john".to_string()),
+                new_value: None,
+            }],
+        };
+        let change = serde_json::to_value(TaskChange::new(change, &Profiles::default())).unwrap();
+        assert_eq!(change["version"], 3);
+        assert_eq!(change["actor"]["username"], "jane");
+        assert_eq!(change["action"], "Restored");
+        assert_eq!(change["changed_at"], "2024-03-01T00:00:00Z");
+        assert_eq!(
+            change["fields"],
+            serde_json::json!([{"field": "assigned_to", "old_value": "john", "new_value": null}])
+        );
+    }
+
+    #[test]
+    fn restore_request_has_only_version() {
+        let req: RestoreTaskVersionRequest = serde_json::from_str(r#"{"version": 2}"#).unwrap();
+        assert_eq!(req.version, 2);
+        assert!(serde_json::from_str::<RestoreTaskVersionRequest>(
+            r#"{"version": 2, "title": "x"}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<RestoreTaskVersionRequest>("{}").is_err());
+    }
+}
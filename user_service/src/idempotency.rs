@@ -0,0 +1,420 @@
+use crate::common::{AppClaims, AppError, AppState, AppStateRef};
+use axum::{
+    body::{to_bytes, Body},
+    extract::{FromRequestParts, Request, State},
+    http::{header, HeaderMap, Method, StatusCode, Uri},
+    middleware::Next,
+    response::{Response, Result},
+};
+use log::{error, info};
+use sha2::{Digest, Sha256};
+use std::time::{Duration, SystemTime};
+use tokio::time::sleep;
+use tokio_postgres::{types::Json as Jsonb, Row};
+
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+/// Set on responses which were replayed from the stored ones.
+pub const IDEMPOTENT_REPLAYED_HEADER: &str = "idempotent-replayed";
+
+const MAX_KEY_LENGTH: usize = 255;
+const MAX_BODY_SIZE: usize = 2 * 1024 * 1024;
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+/// Key of request which didn't complete in this time is considered abandoned,
+/// e.g. because the service was restarted, and is taken over by a retry.
+const RESERVATION_LEASE: Duration = Duration::from_secs(60);
+
+/// Makes mutating requests with `Idempotency-Key` header safe to retry.
+///
+/// First request with the key is executed and its response is stored for
+/// `AppState::idempotency_window`. Retries with the same key get the stored
+/// response, while reusing the key for a different request is rejected. Keys
+/// are scoped to the user of access token, the key is ignored for requests
+/// without token. Server errors aren't stored, so such requests can be
+/// retried. Multipart uploads aren't buffered, so they can't have the key.
+pub async fn idempotency_middleware(
+    State(state): State<AppStateRef>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    if matches!(
+        *request.method(),
+        Method::GET | Method::HEAD | Method::OPTIONS
+    ) || !request.headers().contains_key(IDEMPOTENCY_KEY_HEADER)
+    {
+        return Ok(next.run(request).await);
+    }
+    if is_multipart(request.headers()) {
+        return Err(AppError::IncorrectRequest);
+    }
+    let key = request.headers()[IDEMPOTENCY_KEY_HEADER]
+        .to_str()
+        .ok()
+        .filter(|x| !x.is_empty() && x.len() <= MAX_KEY_LENGTH)
+        .ok_or(AppError::IncorrectRequest)?
+        .to_string();
+    let (mut parts, body) = request.into_parts();
+    let username = match AppClaims::from_request_parts(&mut parts, &state).await {
+        Ok(x) => x.username,
+        Err(_) => return Ok(next.run(Request::from_parts(parts, body)).await),
+    };
+    let body = to_bytes(body, MAX_BODY_SIZE)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    let fingerprint = fingerprint(&parts.method, &parts.uri, &body);
+
+    if let Some(response) = reserve_key(&state, &username, &key, &fingerprint).await? {
+        info!("idempotency_middleware: replaying response for key {}", key);
+        return replay(response);
+    }
+    let response = next.run(Request::from_parts(parts, Body::from(body))).await;
+    if response.status().is_server_error() {
+        state
+            .user_database
+            .execute(
+                "DELETE FROM idempotency_keys WHERE username=$1 AND key=$2",
+                &[&username, &key],
+            )
+            .await
+            .map_err(|_| AppError::IncorrectRequest)?;
+        return Ok(response);
+    }
+    let (parts, body) = response.into_parts();
+    let body = to_bytes(body, usize::MAX)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    state
+        .user_database
+        .execute(
+            "UPDATE idempotency_keys SET status=$3, headers=$4, body=$5 WHERE username=$1 AND key=$2",
+            &[
+                &username,
+                &key,
+                &(parts.status.as_u16() as i16),
+                &Jsonb(stored_headers(&parts.headers)),
+                &&body[..],
+            ],
+        )
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    Ok(Response::from_parts(parts, Body::from(body)))
+}
+
+fn is_multipart(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|x| x.to_str().ok())
+        .is_some_and(|x| x.to_ascii_lowercase().starts_with("multipart/"))
+}
+
+/// Identifies request, so the key can't be reused for a different one.
+fn fingerprint(method: &Method, uri: &Uri, body: &[u8]) -> Vec<u8> {
+    Sha256::new()
+        .chain_update(method.as_str())
+        .chain_update([0])
+        .chain_update(uri.to_string())
+        .chain_update([0])
+        .chain_update(body)
+        .finalize()
+        .to_vec()
+}
+
+/// Headers which are stored with the response, so replays have the version
+/// and the location of created resources.
+fn stored_headers(headers: &HeaderMap) -> Vec<(String, String)> {
+    [header::CONTENT_TYPE, header::ETAG, header::LOCATION]
+        .into_iter()
+        .filter_map(|name| {
+            let value = headers.get(&name)?.to_str().ok()?.to_string();
+            Some((name.to_string(), value))
+        })
+        .collect()
+}
+
+/// Response of completed request stored for its retries.
+#[derive(Clone, PartialEq, Debug)]
+struct StoredResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+/// Key as it is stored in user database.
+struct StoredKey {
+    fingerprint: Vec<u8>,
+    response: Option<StoredResponse>,
+    created_at: SystemTime,
+    locked_until: SystemTime,
+}
+
+impl StoredKey {
+    fn from_row(row: &Row) -> Self {
+        let response = row
+            .get::<_, Option<i16>>("status")
+            .map(|status| StoredResponse {
+                status: status as u16,
+                headers: row
+                    .get::<_, Option<Jsonb<Vec<(String, String)>>>>("headers")
+                    .map_or_else(Vec::new, |x| x.0),
+                body: row.get::<_, Option<Vec<u8>>>("body").unwrap_or_default(),
+            });
+        StoredKey {
+            fingerprint: row.get("fingerprint"),
+            response,
+            created_at: row.get("created_at"),
+            locked_until: row.get("locked_until"),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug)]
+enum Reservation {
+    /// Key isn't used, or it expired and can be reused
+    New,
+    /// Request with the key didn't complete within its lease
+    TakeOver,
+    /// Request with the key is completed
+    Replay(StoredResponse),
+}
+
+/// Decides how the request with stored key is handled at `now`.
+fn check_reservation(
+    stored: Option<&StoredKey>,
+    fingerprint: &[u8],
+    now: SystemTime,
+    window: Duration,
+) -> Result<Reservation, AppError> {
+    let Some(stored) = stored.filter(|x| x.created_at >= now - window) else {
+        return Ok(Reservation::New);
+    };
+    if stored.fingerprint != fingerprint {
+        return Err(AppError::IdempotencyKeyReused);
+    }
+    if let Some(response) = &stored.response {
+        return Ok(Reservation::Replay(response.clone()));
+    }
+    if stored.locked_until < now {
+        return Ok(Reservation::TakeOver);
+    }
+    Err(AppError::IdempotencyKeyInProgress)
+}
+
+/// Stores the key for a new request or takes over abandoned reservation of
+/// the same request. Returns stored response if request with the key was
+/// already completed.
+async fn reserve_key(
+    state: &AppState,
+    username: &str,
+    key: &str,
+    fingerprint: &[u8],
+) -> Result<Option<StoredResponse>, AppError> {
+    loop {
+        let now = SystemTime::now();
+        let stored = state
+            .user_database
+            .query_opt(
+                "SELECT fingerprint, status, headers, body, created_at, locked_until FROM idempotency_keys WHERE username=$1 AND key=$2",
+                &[&username, &key],
+            )
+            .await
+            .map_err(|_| AppError::IncorrectRequest)?
+            .map(|x| StoredKey::from_row(&x));
+        let reserved = match check_reservation(
+            stored.as_ref(),
+            fingerprint,
+            now,
+            state.idempotency_window,
+        )? {
+            Reservation::Replay(response) => return Ok(Some(response)),
+            Reservation::New => {
+                // Expired key can be reused for a new request
+                state
+                    .user_database
+                    .execute(
+                        "DELETE FROM idempotency_keys WHERE username=$1 AND key=$2 AND created_at<$3",
+                        &[&username, &key, &(now - state.idempotency_window)],
+                    )
+                    .await
+                    .map_err(|_| AppError::IncorrectRequest)?;
+                state
+                    .user_database
+                    .execute(
+                        "INSERT INTO idempotency_keys (username, key, fingerprint, created_at, locked_until) VALUES ($1, $2, $3, $4, $5) ON CONFLICT DO NOTHING",
+                        &[&username, &key, &fingerprint, &now, &(now + RESERVATION_LEASE)],
+                    )
+                    .await
+                    .map_err(|_| AppError::IncorrectRequest)?
+            }
+            Reservation::TakeOver => state
+                .user_database
+                .execute(
+                    "UPDATE idempotency_keys SET locked_until=$3 WHERE username=$1 AND key=$2 AND status IS NULL AND locked_until=$4",
+                    &[
+                        &username,
+                        &key,
+                        &(now + RESERVATION_LEASE),
+                        &stored.map(|x| x.locked_until),
+                    ],
+                )
+                .await
+                .map_err(|_| AppError::IncorrectRequest)?,
+        };
+        if reserved == 1 {
+            return Ok(None);
+        }
+        // Concurrent request with the key changed it meanwhile, so its state
+        // is checked again
+    }
+}
+
+fn replay(stored: StoredResponse) -> Result<Response, AppError> {
+    let mut response = Response::builder()
+        .status(StatusCode::from_u16(stored.status).map_err(|_| AppError::IncorrectRequest)?)
+        .header(IDEMPOTENT_REPLAYED_HEADER, "true");
+    for (name, value) in &stored.headers {
+        response = response.header(name, value);
+    }
+    response
+        .body(Body::from(stored.body))
+        .map_err(|_| AppError::IncorrectRequest)
+}
+
+/// Periodically removes keys which are older than the idempotency window.
+pub async fn run_expired_keys_cleanup(state: AppStateRef) {
+    loop {
+        let expired_before = SystemTime::now() - state.idempotency_window;
+        match state
+            .user_database
+            .execute(
+                "DELETE FROM idempotency_keys WHERE created_at<$1",
+                &[&expired_before],
+            )
+            .await
+        {
+            Ok(n) => info!("run_expired_keys_cleanup: removed {} expired keys", n),
+            Err(e) => error!("run_expired_keys_cleanup: couldn't remove keys: {}", e),
+        }
+        sleep(CLEANUP_INTERVAL).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Duration = Duration::from_secs(24 * 60 * 60);
+
+    fn stored_key(response: Option<StoredResponse>, age: u64, lease: u64) -> StoredKey {
+        let now = SystemTime::now();
+        StoredKey {
+            fingerprint: b"request".to_vec(),
+            response,
+            created_at: now - Duration::from_secs(age),
+            locked_until: now - Duration::from_secs(age) + Duration::from_secs(lease),
+        }
+    }
+
+    fn created() -> StoredResponse {
+        StoredResponse {
+            status: 200,
+            headers: vec![
+                ("content-type".to_string(), "application/json".to_string()),
+                ("etag".to_string(), "\"3\"".to_string()),
+            ],
+            body: b"{}".to_vec(),
+        }
+    }
+
+    fn check(stored: Option<&StoredKey>, fingerprint: &[u8]) -> Result<Reservation, AppError> {
+        check_reservation(stored, fingerprint, SystemTime::now(), WINDOW)
+    }
+
+    #[test]
+    fn unused_and_expired_keys_are_reserved() {
+        assert_eq!(check(None, b"request").unwrap(), Reservation::New);
+        let expired = stored_key(Some(created()), WINDOW.as_secs() + 1, 60);
+        assert_eq!(check(Some(&expired), b"other").unwrap(), Reservation::New);
+    }
+
+    #[test]
+    fn key_of_other_request_is_rejected() {
+        let stored = stored_key(Some(created()), 10, 60);
+        assert!(matches!(
+            check(Some(&stored), b"other"),
+            Err(AppError::IdempotencyKeyReused)
+        ));
+        let stored = stored_key(None, 10, 60);
+        assert!(matches!(
+            check(Some(&stored), b"other"),
+            Err(AppError::IdempotencyKeyReused)
+        ));
+    }
+
+    #[test]
+    fn completed_request_is_replayed() {
+        let stored = stored_key(Some(created()), 10, 60);
+        assert_eq!(
+            check(Some(&stored), b"request").unwrap(),
+            Reservation::Replay(created())
+        );
+    }
+
+    #[test]
+    fn abandoned_request_is_taken_over_after_lease() {
+        let stored = stored_key(None, 10, 60);
+        assert!(matches!(
+            check(Some(&stored), b"request"),
+            Err(AppError::IdempotencyKeyInProgress)
+        ));
+        let stored = stored_key(None, 61, 60);
+        assert_eq!(
+            check(Some(&stored), b"request").unwrap(),
+            Reservation::TakeOver
+        );
+    }
+
+    #[test]
+    fn fingerprint_covers_method_uri_and_body() {
+        let uri: Uri = "/tasks/1".parse().unwrap();
+        let x = fingerprint(&Method::POST, &uri, b"{}");
+        assert_eq!(x, fingerprint(&Method::POST, &uri, b"{}"));
+        assert_ne!(x, fingerprint(&Method::PATCH, &uri, b"{}"));
+        assert_ne!(
+            x,
+            fingerprint(&Method::POST, &"/tasks/2".parse().unwrap(), b"{}")
+        );
+        assert_ne!(x, fingerprint(&Method::POST, &uri, b"{ }"));
+    }
+
+    #[test]
+    fn replay_has_stored_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::CONTENT_TYPE, "application/json".parse().unwrap());
+        headers.insert(header::ETAG, "\"3\"".parse().unwrap());
+        headers.insert(header::LOCATION, "/tasks/1".parse().unwrap());
+        headers.insert(header::CACHE_CONTROL, "no-store".parse().unwrap());
+        let stored = StoredResponse {
+            status: 201,
+            headers: stored_headers(&headers),
+            body: b"{}".to_vec(),
+        };
+        assert_eq!(stored.headers.len(), 3);
+        let response = replay(stored).unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(response.headers()[header::ETAG], "\"3\"");
+        assert_eq!(response.headers()[header::LOCATION], "/tasks/1");
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+        assert_eq!(response.headers()[IDEMPOTENT_REPLAYED_HEADER], "true");
+        assert!(!response.headers().contains_key(header::CACHE_CONTROL));
+    }
+
+    #[test]
+    fn multipart_requests_are_detected() {
+        let mut headers = HeaderMap::new();
+        assert!(!is_multipart(&headers));
+        headers.insert(
+            header::CONTENT_TYPE,
+            "Multipart/Form-Data; boundary=x".parse().unwrap(),
+        );
+        assert!(is_multipart(&headers));
+    }
+}
@@ -0,0 +1,527 @@
+use crate::events::EventPublisher;
+use axum::async_trait;
+use std::collections::HashMap;
+use std::io;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::sync::Mutex;
+use tokio::time::timeout;
+
+const API_KEY_PRODUCE: i16 = 0;
+const API_KEY_METADATA: i16 = 3;
+const PRODUCE_VERSION: i16 = 3;
+const METADATA_VERSION: i16 = 1;
+/// Wait for acknowledgement from all in-sync replicas.
+const ACKS_ALL: i16 = -1;
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Minimal Kafka producer speaking the wire protocol directly.
+///
+/// It supports only what the outbox relay needs: Metadata (v1) requests to
+/// find partition leaders and Produce (v3) requests with a single record per
+/// batch. Partition of a record is chosen by CRC-32C of its key, so events of
+/// one entity keep their order.
+pub struct KafkaPublisher {
+    bootstrap_servers: Vec<String>,
+    client_id: String,
+    state: Mutex<KafkaState>,
+}
+
+#[derive(Default)]
+struct KafkaState {
+    correlation_id: i32,
+    brokers: HashMap<i32, String>,
+    /// Leader node of every partition of the topic
+    leaders: HashMap<String, Vec<i32>>,
+    connections: HashMap<String, TcpStream>,
+}
+
+impl KafkaPublisher {
+    pub fn new(bootstrap_servers: Vec<String>, client_id: &str) -> Self {
+        KafkaPublisher {
+            bootstrap_servers,
+            client_id: client_id.to_string(),
+            state: Mutex::new(KafkaState::default()),
+        }
+    }
+
+    async fn send(
+        &self,
+        state: &mut KafkaState,
+        addr: &str,
+        api_key: i16,
+        api_version: i16,
+        body: &[u8],
+    ) -> io::Result<Vec<u8>> {
+        state.correlation_id = state.correlation_id.wrapping_add(1);
+        let correlation_id = state.correlation_id;
+        let mut request = Vec::new();
+        put_i16(&mut request, api_key);
+        put_i16(&mut request, api_version);
+        put_i32(&mut request, correlation_id);
+        put_string(&mut request, &self.client_id);
+        request.extend_from_slice(body);
+
+        if !state.connections.contains_key(addr) {
+            let stream = timeout(REQUEST_TIMEOUT, TcpStream::connect(addr)).await??;
+            state.connections.insert(addr.to_string(), stream);
+        }
+        let stream = state.connections.get_mut(addr).unwrap();
+        let result = timeout(REQUEST_TIMEOUT, async {
+            stream.write_i32(request.len() as i32).await?;
+            stream.write_all(&request).await?;
+            let size = stream.read_i32().await?;
+            let mut response = vec![0; size.max(0) as usize];
+            stream.read_exact(&mut response).await?;
+            Ok::<_, io::Error>(response)
+        })
+        .await
+        .map_err(io::Error::from)
+        .and_then(|x| x);
+        let response = match result {
+            Ok(x) => x,
+            Err(e) => {
+                state.connections.remove(addr);
+                return Err(e);
+            }
+        };
+
+        let mut decoder = Decoder(&response);
+        if decoder.i32()? != correlation_id {
+            state.connections.remove(addr);
+            return Err(invalid_data("unexpected correlation id"));
+        }
+        Ok(decoder.0.to_vec())
+    }
+
+    /// Refreshes brokers and partition leaders of the topic.
+    async fn fetch_metadata(&self, state: &mut KafkaState, topic: &str) -> Result<(), String> {
+        let mut body = Vec::new();
+        put_i32(&mut body, 1);
+        put_string(&mut body, topic);
+
+        let mut addrs = self.bootstrap_servers.clone();
+        addrs.extend(state.brokers.values().cloned());
+        let mut last_error = String::from("no bootstrap servers");
+        for addr in addrs {
+            let response = match self
+                .send(state, &addr, API_KEY_METADATA, METADATA_VERSION, &body)
+                .await
+            {
+                Ok(x) => x,
+                Err(e) => {
+                    last_error = format!("{}: {}", addr, e);
+                    continue;
+                }
+            };
+            let (brokers, leaders) = parse_metadata(&response, topic).map_err(|e| e.to_string())?;
+            state.brokers = brokers;
+            state.leaders.insert(topic.to_string(), leaders);
+            return Ok(());
+        }
+        Err(last_error)
+    }
+
+    async fn produce(
+        &self,
+        state: &mut KafkaState,
+        topic: &str,
+        key: &str,
+        payload: &[u8],
+    ) -> Result<(), String> {
+        if !state.leaders.contains_key(topic) {
+            self.fetch_metadata(state, topic).await?;
+        }
+        let leaders = &state.leaders[topic];
+        let partition = crc32c::crc32c(key.as_bytes()) as usize % leaders.len();
+        let addr = state
+            .brokers
+            .get(&leaders[partition])
+            .ok_or_else(|| format!("unknown leader of {}/{}", topic, partition))?
+            .clone();
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as i64;
+        let batch = encode_record_batch(key.as_bytes(), payload, timestamp);
+        let mut body = Vec::new();
+        put_i16(&mut body, -1); // transactional_id
+        put_i16(&mut body, ACKS_ALL);
+        put_i32(&mut body, REQUEST_TIMEOUT.as_millis() as i32);
+        put_i32(&mut body, 1);
+        put_string(&mut body, topic);
+        put_i32(&mut body, 1);
+        put_i32(&mut body, partition as i32);
+        put_i32(&mut body, batch.len() as i32);
+        body.extend_from_slice(&batch);
+
+        let response = self
+            .send(state, &addr, API_KEY_PRODUCE, PRODUCE_VERSION, &body)
+            .await
+            .map_err(|e| format!("{}: {}", addr, e))?;
+        let error_code = parse_produce(&response).map_err(|e| e.to_string())?;
+        if error_code != 0 {
+            return Err(format!(
+                "produce to {}/{} failed with error code {}",
+                topic, partition, error_code
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventPublisher for KafkaPublisher {
+    async fn publish(&self, topic: &str, key: &str, payload: &[u8]) -> Result<(), String> {
+        let mut state = self.state.lock().await;
+        let result = self.produce(&mut state, topic, key, payload).await;
+        if result.is_err() {
+            // Leaders might have moved, metadata is fetched again on retry
+            state.leaders.remove(topic);
+        }
+        result
+    }
+}
+
+/// Encodes record batch (magic 2) with a single record.
+fn encode_record_batch(key: &[u8], value: &[u8], timestamp: i64) -> Vec<u8> {
+    let mut record = Vec::new();
+    record.push(0); // attributes
+    put_varint(&mut record, 0); // timestamp delta
+    put_varint(&mut record, 0); // offset delta
+    put_varint(&mut record, key.len() as i64);
+    record.extend_from_slice(key);
+    put_varint(&mut record, value.len() as i64);
+    record.extend_from_slice(value);
+    put_varint(&mut record, 0); // headers
+
+    let mut body = Vec::new();
+    put_i16(&mut body, 0); // attributes
+    put_i32(&mut body, 0); // last offset delta
+    put_i64(&mut body, timestamp);
+    put_i64(&mut body, timestamp);
+    put_i64(&mut body, -1); // producer id
+    put_i16(&mut body, -1); // producer epoch
+    put_i32(&mut body, -1); // base sequence
+    put_i32(&mut body, 1);
+    put_varint(&mut body, record.len() as i64);
+    body.extend_from_slice(&record);
+
+    let mut batch = Vec::new();
+    put_i64(&mut batch, 0); // base offset
+    put_i32(&mut batch, (4 + 1 + 4 + body.len()) as i32);
+    put_i32(&mut batch, -1); // partition leader epoch
+    batch.push(2); // magic
+    batch.extend_from_slice(&crc32c::crc32c(&body).to_be_bytes());
+    batch.extend_from_slice(&body);
+    batch
+}
+
+type Metadata = (HashMap<i32, String>, Vec<i32>);
+
+fn parse_metadata(response: &[u8], topic: &str) -> io::Result<Metadata> {
+    let mut decoder = Decoder(response);
+    let mut brokers = HashMap::new();
+    for _ in 0..decoder.i32()? {
+        let node_id = decoder.i32()?;
+        let host = decoder.string()?;
+        let port = decoder.i32()?;
+        decoder.nullable_string()?; // rack
+        brokers.insert(node_id, format!("{}:{}", host, port));
+    }
+    decoder.i32()?; // controller id
+    for _ in 0..decoder.i32()? {
+        let error_code = decoder.i16()?;
+        let name = decoder.string()?;
+        decoder.i8()?; // is internal
+        let mut leaders = Vec::new();
+        for _ in 0..decoder.i32()? {
+            let partition_error_code = decoder.i16()?;
+            let index = decoder.i32()?;
+            let leader = decoder.i32()?;
+            decoder.i32_array()?; // replicas
+            decoder.i32_array()?; // in-sync replicas
+            if partition_error_code != 0 || leader < 0 {
+                return Err(invalid_data(&format!(
+                    "partition {}/{} is unavailable (error code {})",
+                    name, index, partition_error_code
+                )));
+            }
+            leaders.push((index, leader));
+        }
+        if name != topic {
+            continue;
+        }
+        if error_code != 0 || leaders.is_empty() {
+            return Err(invalid_data(&format!(
+                "topic {} is unavailable (error code {})",
+                name, error_code
+            )));
+        }
+        leaders.sort();
+        return Ok((brokers, leaders.into_iter().map(|(_, x)| x).collect()));
+    }
+    Err(invalid_data(&format!(
+        "topic {} is missing in metadata",
+        topic
+    )))
+}
+
+/// Returns first non-zero error code of partitions in produce response.
+fn parse_produce(response: &[u8]) -> io::Result<i16> {
+    let mut decoder = Decoder(response);
+    for _ in 0..decoder.i32()? {
+        decoder.string()?;
+        for _ in 0..decoder.i32()? {
+            decoder.i32()?; // partition
+            let error_code = decoder.i16()?;
+            decoder.i64()?; // base offset
+            decoder.i64()?; // log append time
+            if error_code != 0 {
+                return Ok(error_code);
+            }
+        }
+    }
+    Ok(0)
+}
+
+fn put_i16(buf: &mut Vec<u8>, x: i16) {
+    buf.extend_from_slice(&x.to_be_bytes());
+}
+
+fn put_i32(buf: &mut Vec<u8>, x: i32) {
+    buf.extend_from_slice(&x.to_be_bytes());
+}
+
+fn put_i64(buf: &mut Vec<u8>, x: i64) {
+    buf.extend_from_slice(&x.to_be_bytes());
+}
+
+fn put_string(buf: &mut Vec<u8>, x: &str) {
+    put_i16(buf, x.len() as i16);
+    buf.extend_from_slice(x.as_bytes());
+}
+
+/// Zigzag encoded variable length integer.
+fn put_varint(buf: &mut Vec<u8>, x: i64) {
+    let mut x = ((x << 1) ^ (x >> 63)) as u64;
+    while x >= 0x80 {
+        buf.push((x as u8) | 0x80);
+        x >>= 7;
+    }
+    buf.push(x as u8);
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+struct Decoder<'a>(&'a [u8]);
+
+impl Decoder<'_> {
+    fn take(&mut self, n: usize) -> io::Result<&[u8]> {
+        if self.0.len() < n {
+            return Err(invalid_data("truncated response"));
+        }
+        let (head, tail) = self.0.split_at(n);
+        self.0 = tail;
+        Ok(head)
+    }
+
+    fn i8(&mut self) -> io::Result<i8> {
+        Ok(self.take(1)?[0] as i8)
+    }
+
+    fn i16(&mut self) -> io::Result<i16> {
+        Ok(i16::from_be_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn i32(&mut self) -> io::Result<i32> {
+        Ok(i32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn i64(&mut self) -> io::Result<i64> {
+        Ok(i64::from_be_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn nullable_string(&mut self) -> io::Result<Option<String>> {
+        let len = self.i16()?;
+        if len < 0 {
+            return Ok(None);
+        }
+        let bytes = self.take(len as usize)?;
+        Ok(Some(String::from_utf8_lossy(bytes).into_owned()))
+    }
+
+    fn string(&mut self) -> io::Result<String> {
+        self.nullable_string()?
+            .ok_or_else(|| invalid_data("unexpected null string"))
+    }
+
+    fn i32_array(&mut self) -> io::Result<Vec<i32>> {
+        (0..self.i32()?.max(0)).map(|_| self.i32()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn varints_are_zigzag_encoded() {
+        let encode = |x| {
+            let mut buf = Vec::new();
+            put_varint(&mut buf, x);
+            buf
+        };
+        assert_eq!(encode(0), [0x00]);
+        assert_eq!(encode(-1), [0x01]);
+        assert_eq!(encode(1), [0x02]);
+        assert_eq!(encode(63), [0x7e]);
+        assert_eq!(encode(-64), [0x7f]);
+        assert_eq!(encode(64), [0x80, 0x01]);
+        assert_eq!(encode(300), [0xd8, 0x04]);
+        assert_eq!(
+            encode(i64::MIN),
+            [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]
+        );
+    }
+
+    #[test]
+    fn checksum_is_crc32c() {
+        assert_eq!(crc32c::crc32c(b"123456789"), 0xe306_9283);
+    }
+
+    #[test]
+    fn record_batch_has_single_record() {
+        #[rustfmt::skip]
+        let expected: &[u8] = &[
+            0, 0, 0, 0, 0, 0, 0, 0, // base offset
+            0, 0, 0, 58, // batch length
+            0xff, 0xff, 0xff, 0xff, // partition leader epoch
+            2, // magic
+            0xe9, 0x9b, 0x8d, 0xd8, // crc
+            0, 0, // attributes
+            0, 0, 0, 0, // last offset delta
+            0, 0, 0x01, 0x8b, 0xcf, 0xe5, 0x68, 0x00, // first timestamp
+            0, 0, 0x01, 0x8b, 0xcf, 0xe5, 0x68, 0x00, // max timestamp
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, // producer id
+            0xff, 0xff, // producer epoch
+            0xff, 0xff, 0xff, 0xff, // base sequence
+            0, 0, 0, 1, // records
+            16, // record length
+            0, // attributes
+            0, // timestamp delta
+            0, // offset delta
+            2, b'k', // key
+            2, b'v', // value
+            0, // headers
+        ];
+        let batch = encode_record_batch(b"k", b"v", 1_700_000_000_000);
+        assert_eq!(batch, expected);
+        assert_eq!(crc32c::crc32c(&batch[21..]).to_be_bytes(), batch[17..21]);
+    }
+
+    #[rustfmt::skip]
+    const METADATA: &[u8] = &[
+        0, 0, 0, 2, // brokers
+        0, 0, 0, 1, 0, 1, b'a', 0, 0, 0x23, 0x84, 0xff, 0xff, // 1 a:9092 without rack
+        0, 0, 0, 2, 0, 1, b'b', 0, 0, 0x23, 0x85, 0, 2, b'r', b'1', // 2 b:9093 in rack r1
+        0, 0, 0, 1, // controller id
+        0, 0, 0, 2, // topics
+        0, 0, 0, 1, b'o', 0, // topic o
+        0, 0, 0, 1, // partitions
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, // partition 0 led by 2
+        0, 0, 0, 1, b't', 0, // topic t
+        0, 0, 0, 2, // partitions
+        0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, // partition 1 led by 2
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, // partition 0 led by 1
+    ];
+
+    #[test]
+    fn metadata_has_brokers_and_leaders_by_partition() {
+        let (brokers, leaders) = parse_metadata(METADATA, "t").unwrap();
+        assert_eq!(brokers.len(), 2);
+        assert_eq!(brokers[&1], "a:9092");
+        assert_eq!(brokers[&2], "b:9093");
+        assert_eq!(leaders, [1, 2]);
+        assert_eq!(parse_metadata(METADATA, "o").unwrap().1, [2]);
+    }
+
+    #[test]
+    fn metadata_without_topic_is_rejected() {
+        let error = parse_metadata(METADATA, "x").unwrap_err();
+        assert_eq!(error.to_string(), "topic x is missing in metadata");
+    }
+
+    #[test]
+    fn metadata_with_unavailable_partition_is_rejected() {
+        #[rustfmt::skip]
+        let response: &[u8] = &[
+            0, 0, 0, 0, // brokers
+            0, 0, 0, 1, // controller id
+            0, 0, 0, 1, // topics
+            0, 0, 0, 1, b't', 0, // topic t
+            0, 0, 0, 1, // partitions
+            0, 5, 0, 0, 0, 0, 0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0, 0, 0, 0, 0, // partition 0 without leader
+        ];
+        let error = parse_metadata(response, "t").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "partition t/0 is unavailable (error code 5)"
+        );
+    }
+
+    #[test]
+    fn truncated_metadata_is_rejected() {
+        let error = parse_metadata(&METADATA[..METADATA.len() - 1], "t").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn produce_response_has_first_error_code() {
+        #[rustfmt::skip]
+        let response: &[u8] = &[
+            0, 0, 0, 1, // topics
+            0, 1, b't', // topic t
+            0, 0, 0, 2, // partitions
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0, 0, 0, 1, 0, 6, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0, 0, 0, 0, // throttle time
+        ];
+        assert_eq!(parse_produce(response).unwrap(), 6);
+        assert_eq!(parse_produce(&[0, 0, 0, 0]).unwrap(), 0);
+        assert!(parse_produce(&response[..20]).is_err());
+    }
+
+    #[test]
+    fn decoder_reads_big_endian_values() {
+        let mut decoder = Decoder(&[
+            0xff, 0x01, 0x02, 0xff, 0xff, 0xff, 0xfe, 0, 0, 0, 0, 0, 0, 0, 3, 0, 2, b'h', b'i',
+            0xff, 0xff, 0, 0, 0, 2, 0, 0, 0, 7, 0, 0, 0, 8, 0,
+        ]);
+        assert_eq!(decoder.i8().unwrap(), -1);
+        assert_eq!(decoder.i16().unwrap(), 0x0102);
+        assert_eq!(decoder.i32().unwrap(), -2);
+        assert_eq!(decoder.i64().unwrap(), 3);
+        assert_eq!(decoder.string().unwrap(), "hi");
+        assert_eq!(decoder.nullable_string().unwrap(), None);
+        assert_eq!(decoder.i32_array().unwrap(), [7, 8]);
+        assert!(decoder.i16().is_err());
+    }
+
+    #[test]
+    fn request_header_strings_are_length_prefixed() {
+        let mut buf = Vec::new();
+        put_i16(&mut buf, API_KEY_METADATA);
+        put_i32(&mut buf, -2);
+        put_i64(&mut buf, 1);
+        put_string(&mut buf, "id");
+        assert_eq!(
+            buf,
+            [0, 3, 0xff, 0xff, 0xff, 0xfe, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2, b'i', b'd']
+        );
+    }
+}
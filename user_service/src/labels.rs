@@ -0,0 +1,323 @@
+use crate::common::{AppClaims, AppError, AppState, AppStateRef};
+use crate::events;
+use crate::proto::events::{self as ev, event::Payload};
+use crate::proto::tasks_service as ts;
+use crate::tasks::{make_task, status_name, tagged};
+use crate::users::{Profiles, UserProfile};
+use axum::{
+    extract::{Path, State},
+    response::{Response, Result},
+    Json,
+};
+use log::info;
+use serde::{Deserialize, Serialize};
+
+const MAX_NAME_LENGTH: usize = 50;
+const MAX_DESCRIPTION_LENGTH: usize = 500;
+
+pub async fn list_labels_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+) -> Result<Json<Vec<LabelInfo>>, AppError> {
+    info!("list_labels_handler: handling list labels request");
+    let labels = fetch_labels(&state, &claims.username).await?;
+    let usernames: Vec<&str> = labels.iter().map(|x| x.creator_id.as_str()).collect();
+    let profiles = Profiles::fetch(&state.user_database, &usernames).await?;
+    Ok(Json(
+        labels
+            .into_iter()
+            .map(|x| LabelInfo {
+                created_by: profiles.get(&x.creator_id),
+                label: x.into(),
+            })
+            .collect(),
+    ))
+}
+
+pub async fn create_label_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Json(req): Json<CreateLabelRequest>,
+) -> Result<Json<Label>, AppError> {
+    info!("create_label_handler: handling create label request");
+    let description = req.description.unwrap_or_default();
+    check_name(&req.name)?;
+    check_description(&description)?;
+    let request = tonic::Request::new(ts::CreateLabelRequest {
+        user_id: claims.username,
+        name: req.name,
+        color: parse_color(&req.color)?,
+        description,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .create_label(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_label(response.into_inner())
+}
+
+pub async fn update_label_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(label_id): Path<String>,
+    Json(req): Json<UpdateLabelRequest>,
+) -> Result<Json<Label>, AppError> {
+    info!("update_label_handler: handling update label request");
+    if let Some(x) = &req.new_name {
+        check_name(x)?;
+    }
+    if let Some(x) = &req.new_description {
+        check_description(x)?;
+    }
+    let request = tonic::Request::new(ts::UpdateLabelRequest {
+        user_id: claims.username,
+        label_id,
+        new_name: req.new_name,
+        new_color: req.new_color.as_deref().map(parse_color).transpose()?,
+        new_description: req.new_description,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .update_label(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_label(response.into_inner())
+}
+
+/// Deletes the label and detaches it from all tasks.
+pub async fn delete_label_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(label_id): Path<String>,
+) -> Result<Json<Label>, AppError> {
+    info!("delete_label_handler: handling delete label request");
+    let request = tonic::Request::new(ts::DeleteLabelRequest {
+        user_id: claims.username,
+        label_id,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .delete_label(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_label(response.into_inner())
+}
+
+pub async fn add_task_label_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+    Json(req): Json<AddTaskLabelRequest>,
+) -> Result<Response, AppError> {
+    info!("add_task_label_handler: handling add task label request");
+    let request = tonic::Request::new(ts::TaskLabelRequest {
+        user_id: claims.username.clone(),
+        task_id,
+        label_id: req.label_id,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .add_task_label(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_labeled_task(&state, claims.username, response.into_inner()).await
+}
+
+pub async fn remove_task_label_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path((task_id, label_id)): Path<(String, String)>,
+) -> Result<Response, AppError> {
+    info!("remove_task_label_handler: handling remove task label request");
+    let request = tonic::Request::new(ts::TaskLabelRequest {
+        user_id: claims.username.clone(),
+        task_id,
+        label_id,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .remove_task_label(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_labeled_task(&state, claims.username, response.into_inner()).await
+}
+
+async fn make_labeled_task(
+    state: &AppStateRef,
+    username: String,
+    response: ts::TaskResponse,
+) -> Result<Response, AppError> {
+    match response.response.unwrap() {
+        ts::task_response::Response::Task(x) => {
+            events::emit(
+                state,
+                Payload::TaskUpdated(ev::TaskUpdated {
+                    task_id: x.id.clone(),
+                    user_id: username,
+                    title: x.title.clone(),
+                    status: status_name(x.status),
+                }),
+            )
+            .await?;
+            Ok(tagged(make_task(state, x).await?))
+        }
+        ts::task_response::Response::Error(e) => Err(e.into()),
+    }
+}
+
+/// Lists labels of the user.
+pub async fn fetch_labels(state: &AppState, username: &str) -> Result<Vec<ts::Label>, AppError> {
+    let request = tonic::Request::new(ts::ListLabelsRequest {
+        user_id: username.to_string(),
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .list_labels(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    match response.into_inner().response.unwrap() {
+        ts::label_list_response::Response::Labels(x) => Ok(x.labels),
+        ts::label_list_response::Response::Error(e) => Err(e.into()),
+    }
+}
+
+fn make_label(response: ts::LabelResponse) -> Result<Json<Label>, AppError> {
+    match response.response.unwrap() {
+        ts::label_response::Response::Label(x) => Ok(Json(x.into())),
+        ts::label_response::Response::Error(e) => Err(e.into()),
+    }
+}
+
+/// Names are listed comma separated in task filters, so they can't contain
+/// commas.
+fn check_name(name: &str) -> Result<(), AppError> {
+    let length = name.chars().count();
+    if name.trim() != name || !(1..=MAX_NAME_LENGTH).contains(&length) || name.contains(',') {
+        return Err(AppError::IncorrectRequest);
+    }
+    Ok(())
+}
+
+fn check_description(description: &str) -> Result<(), AppError> {
+    if description.chars().count() > MAX_DESCRIPTION_LENGTH {
+        return Err(AppError::IncorrectRequest);
+    }
+    Ok(())
+}
+
+/// Checks that color is in `#rrggbb` format and converts it to lowercase.
+fn parse_color(color: &str) -> Result<String, AppError> {
+    match color.strip_prefix('#') {
+        Some(x) if x.len() == 6 && x.chars().all(|c| c.is_ascii_hexdigit()) => {
+            Ok(color.to_ascii_lowercase())
+        }
+        _ => Err(AppError::IncorrectRequest),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CreateLabelRequest {
+    name: String,
+    /// Color in `#rrggbb` format
+    color: String,
+    description: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateLabelRequest {
+    new_name: Option<String>,
+    new_color: Option<String>,
+    new_description: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AddTaskLabelRequest {
+    label_id: String,
+}
+
+#[derive(Serialize)]
+pub struct Label {
+    id: String,
+    name: String,
+    color: String,
+    description: String,
+}
+
+impl From<ts::Label> for Label {
+    fn from(x: ts::Label) -> Self {
+        Label {
+            id: x.id,
+            name: x.name,
+            color: x.color,
+            description: x.description,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct LabelInfo {
+    #[serde(flatten)]
+    label: Label,
+    /// Only creator can change or delete the label
+    created_by: UserProfile,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::ERR_CODE_LABEL_NOT_FOUND;
+
+    #[test]
+    fn names_are_trimmed_short_and_without_commas() {
+        assert!(check_name("bug").is_ok());
+        assert!(check_name(&"é".repeat(MAX_NAME_LENGTH)).is_ok());
+        for x in [
+            "",
+            " bug",
+            "bug ",
+            "bug,ui",
+            &"a".repeat(MAX_NAME_LENGTH + 1),
+        ] {
+            assert!(
+                matches!(check_name(x), Err(AppError::IncorrectRequest)),
+                "{:?}",
+                x
+            );
+        }
+        assert!(check_description(&"a".repeat(MAX_DESCRIPTION_LENGTH)).is_ok());
+        assert!(check_description(&"a".repeat(MAX_DESCRIPTION_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn colors_are_lowercase_hex() {
+        assert_eq!(parse_color("#A0b1C2").unwrap(), "#a0b1c2");
+        for x in ["a0b1c2", "#a0b1c", "#a0b1c2d", "#g0b1c2", "red"] {
+            assert!(parse_color(x).is_err(), "{:?}", x);
+        }
+    }
+
+    #[test]
+    fn labels_of_other_users_are_not_found() {
+        let e = AppError::from(ts::Error {
+            code: ERR_CODE_LABEL_NOT_FOUND,
+            message: "Label doesn't exist or belongs to another user".to_string(),
+        });
+        assert!(matches!(e, AppError::LabelNotFound));
+    }
+}
@@ -1,22 +1,58 @@
+mod access;
+mod attachments;
 mod auth;
+mod board;
+mod checklists;
+mod comments;
 mod common;
+mod cursor;
+mod dependencies;
+mod etag;
+mod events;
+mod history;
+mod idempotency;
+mod kafka;
+mod labels;
+mod markdown;
+mod projects;
 mod proto;
+mod recurrence;
+mod reminders;
+mod search;
+mod statistics;
+mod storage;
 mod tasks;
+mod templates;
+mod time_tracking;
+mod trash;
+mod users;
+mod workflow;
 
 use axum::{
-    routing::{get, post},
+    extract::DefaultBodyLimit,
+    handler::Handler,
+    middleware,
+    routing::{delete, get, patch, post, put},
     Router,
 };
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use common::AppState;
 use jwt_simple::prelude::*;
+use kafka::KafkaPublisher;
 use log::{error, info};
 use proto::tasks_service::tasks_service_client::TasksServiceClient;
+use reminders::{EventNotifier, LogNotifier, Notifier};
+use statistics::InMemoryStatistics;
 use std::env;
 use std::{sync::Arc, time::Duration};
+use storage::{LocalStorage, S3Storage, Storage};
 use tokio::time::sleep;
 use tokio_postgres::{Client, NoTls};
 use tonic::transport::Channel;
+use workflow::Workflow;
+
+/// Room for headers and boundaries of multipart form on top of attachment.
+const MULTIPART_OVERHEAD: usize = 64 * 1024;
 
 #[tokio::main]
 async fn main() {
@@ -26,24 +62,247 @@ async fn main() {
     let jwt_key = env::var("JWT_KEY")
         .map(|x| HS256Key::from_bytes(&const_hex::decode(x).unwrap()))
         .unwrap_or_else(|_| HS256Key::generate());
+    let cursor_key = HS256Key::from_bytes(&[&jwt_key.to_bytes()[..], b"page_cursor"].concat());
+    let workflow = match &args.workflow {
+        Some(path) => Workflow::from_file(path).unwrap(),
+        None => Workflow::default(),
+    };
+    let storage: Box<dyn Storage> = match args.attachment_storage {
+        AttachmentStorage::Local => {
+            Box::new(LocalStorage::new(&args.attachment_dir).await.unwrap())
+        }
+        AttachmentStorage::S3 => {
+            Box::new(S3Storage::new(&args.s3_bucket, args.s3_endpoint.as_deref()).unwrap())
+        }
+    };
+    let max_attachment_size = args.max_attachment_size_mb * 1024 * 1024;
     let app_state = Arc::new(AppState {
         user_database: connect_user_database(&args.db_config).await,
         tasks_service: tokio::sync::RwLock::new(
             connect_tasks_service(args.tasks_service_host).await,
         ),
         jwt_key,
+        cursor_key,
+        workflow,
+        statistics: Box::new(InMemoryStatistics::default()),
+        idempotency_window: Duration::from_secs(args.idempotency_window_hours * 60 * 60),
+        trash_retention: Duration::from_secs(args.trash_retention_days * 24 * 60 * 60),
+        check_blockers: !args.allow_completing_blocked_tasks,
+        storage,
+        max_attachment_size,
+        attachment_types: args.attachment_types,
     });
 
+    if args.kafka_brokers.is_empty() {
+        info!("main: no Kafka brokers are set, domain events stay in the outbox");
+    } else {
+        let publisher = KafkaPublisher::new(args.kafka_brokers, "user_service");
+        tokio::spawn(events::run_outbox_relay(
+            app_state.clone(),
+            Box::new(publisher),
+        ));
+    }
+    tokio::spawn(idempotency::run_expired_keys_cleanup(app_state.clone()));
+    tokio::spawn(trash::run_trash_purge(app_state.clone()));
+    tokio::spawn(attachments::run_deleted_attachments_cleanup(
+        app_state.clone(),
+    ));
+    tokio::spawn(recurrence::run_recurrence_scheduler(app_state.clone()));
+    let notifier: Box<dyn Notifier> = match args.reminder_notifier {
+        ReminderNotifier::Log => Box::new(LogNotifier),
+        ReminderNotifier::Events => Box::new(EventNotifier {
+            state: app_state.clone(),
+        }),
+    };
+    tokio::spawn(reminders::run_reminder_scheduler(
+        app_state.clone(),
+        notifier,
+        Duration::from_secs(args.reminder_lead_hours * 60 * 60),
+    ));
+
     let app = Router::new()
         .route("/", get(root_handler))
-        .route("/register", post(auth::register_handler))
-        .route("/login", post(auth::login_handler))
         .route("/update", post(auth::update_handler))
         .route("/createTask", post(tasks::create_task_handler))
         .route("/getTask", post(tasks::get_task_handler))
         .route("/updateTask", post(tasks::update_task_handler))
         .route("/deleteTask", post(tasks::delete_task_handler))
         .route("/getTaskPage", post(tasks::get_task_page_handler))
+        .route(
+            "/tasks/assigned",
+            get(tasks::get_assigned_task_page_handler),
+        )
+        .route(
+            "/tasks/awaitingReview",
+            get(tasks::get_awaiting_review_task_page_handler),
+        )
+        .route("/tasks/top", get(tasks::get_top_tasks_handler))
+        .route("/tasks/overdue", get(tasks::get_overdue_task_page_handler))
+        .route("/tasks/search", get(tasks::search_tasks_handler))
+        .route("/tasks/batch", post(tasks::batch_tasks_handler))
+        .route("/tasks/:id/like", post(tasks::like_task_handler))
+        .route("/tasks/:id/unlike", post(tasks::unlike_task_handler))
+        .route("/tasks/:id/stats", get(tasks::get_task_stats_handler))
+        .route(
+            "/tasks/:id/transition",
+            post(tasks::transition_task_handler),
+        )
+        .route(
+            "/tasks/:id/transitions",
+            get(tasks::get_transitions_handler),
+        )
+        .route("/tasks/:id/history", get(history::get_task_history_handler))
+        .route(
+            "/tasks/:id/history/restore",
+            post(history::restore_task_version_handler),
+        )
+        .route("/tasks/:id/move", post(board::move_task_handler))
+        .route(
+            "/tasks/:id/parent",
+            put(dependencies::set_task_parent_handler),
+        )
+        .route("/tasks/:id/subtasks", get(tasks::get_subtask_page_handler))
+        .route(
+            "/tasks/:id/checklist",
+            get(checklists::get_checklist_handler).post(checklists::add_checklist_item_handler),
+        )
+        .route(
+            "/tasks/:id/checklist/:item_id",
+            patch(checklists::update_checklist_item_handler)
+                .delete(checklists::delete_checklist_item_handler),
+        )
+        .route(
+            "/tasks/:id/checklist/:item_id/move",
+            post(checklists::move_checklist_item_handler),
+        )
+        .route(
+            "/tasks/:id/attachments",
+            get(attachments::list_attachments_handler).post(
+                attachments::upload_attachment_handler.layer(DefaultBodyLimit::max(
+                    max_attachment_size as usize + MULTIPART_OVERHEAD,
+                )),
+            ),
+        )
+        .route(
+            "/tasks/:id/attachments/:attachment_id",
+            get(attachments::download_attachment_handler)
+                .delete(attachments::delete_attachment_handler),
+        )
+        .route(
+            "/tasks/:id/timer/start",
+            post(time_tracking::start_timer_handler),
+        )
+        .route(
+            "/tasks/:id/timer/stop",
+            post(time_tracking::stop_timer_handler),
+        )
+        .route("/timer", get(time_tracking::get_timer_handler))
+        .route(
+            "/tasks/:id/time",
+            get(time_tracking::list_time_entries_handler)
+                .post(time_tracking::add_time_entry_handler),
+        )
+        .route(
+            "/tasks/:id/time/:entry_id",
+            patch(time_tracking::update_time_entry_handler)
+                .delete(time_tracking::delete_time_entry_handler),
+        )
+        .route("/reports/time", get(time_tracking::time_report_handler))
+        .route(
+            "/tasks/:id/dependencies",
+            get(dependencies::get_task_dependencies_handler)
+                .post(dependencies::add_task_dependency_handler),
+        )
+        .route(
+            "/tasks/:id/dependencies/:blocker_id",
+            delete(dependencies::remove_task_dependency_handler),
+        )
+        .route(
+            "/series",
+            get(recurrence::list_series_handler).post(recurrence::create_series_handler),
+        )
+        .route(
+            "/series/:id",
+            get(recurrence::get_series_handler).patch(recurrence::update_series_handler),
+        )
+        .route("/series/:id/stop", post(recurrence::stop_series_handler))
+        .route(
+            "/templates",
+            get(templates::list_templates_handler).post(templates::create_template_handler),
+        )
+        .route(
+            "/templates/:id",
+            get(templates::get_template_handler)
+                .patch(templates::update_template_handler)
+                .delete(templates::delete_template_handler),
+        )
+        .route(
+            "/tasks/fromTemplate",
+            post(templates::create_task_from_template_handler),
+        )
+        .route("/board", get(board::get_board_handler))
+        .route("/board/:status", get(board::get_board_column_handler))
+        .route("/tasks/:id/restore", post(trash::restore_task_handler))
+        .route("/trash", get(trash::list_trash_handler))
+        .route("/trash/:id", delete(trash::purge_task_handler))
+        .route(
+            "/projects",
+            get(projects::list_projects_handler).post(projects::create_project_handler),
+        )
+        .route(
+            "/projects/:id",
+            get(projects::get_project_handler)
+                .patch(projects::update_project_handler)
+                .delete(projects::delete_project_handler),
+        )
+        .route(
+            "/projects/:id/members",
+            get(projects::get_project_members_handler).post(projects::set_project_member_handler),
+        )
+        .route(
+            "/projects/:id/members/:username",
+            delete(projects::remove_project_member_handler),
+        )
+        .route(
+            "/projects/:id/tasks",
+            get(tasks::get_project_task_page_handler),
+        )
+        .route(
+            "/labels",
+            get(labels::list_labels_handler).post(labels::create_label_handler),
+        )
+        .route(
+            "/labels/:id",
+            patch(labels::update_label_handler).delete(labels::delete_label_handler),
+        )
+        .route("/tasks/:id/labels", post(labels::add_task_label_handler))
+        .route(
+            "/tasks/:id/labels/:label_id",
+            delete(labels::remove_task_label_handler),
+        )
+        .route(
+            "/tasks/:id/access",
+            get(access::get_task_access_handler).post(access::grant_task_access_handler),
+        )
+        .route(
+            "/tasks/:id/access/:username",
+            delete(access::revoke_task_access_handler),
+        )
+        .route(
+            "/tasks/:id/comments",
+            get(comments::get_comment_page_handler).post(comments::create_comment_handler),
+        )
+        .route(
+            "/tasks/:id/comments/:comment_id",
+            patch(comments::update_comment_handler).delete(comments::delete_comment_handler),
+        )
+        .layer(middleware::from_fn_with_state(
+            app_state.clone(),
+            idempotency::idempotency_middleware,
+        ))
+        // Authentication has no access token to scope idempotency keys by
+        .route("/register", post(auth::register_handler))
+        .route("/login", post(auth::login_handler))
         .with_state(app_state);
 
     let listener = tokio::net::TcpListener::bind(format!("{}:{}", args.host, args.port))
@@ -118,4 +377,76 @@ struct CliArgs {
     /// Hostname of tasks_service
     #[arg(short, long, default_value = "tasks_service:50051")]
     tasks_service_host: String,
+
+    /// Path to JSON file with task status workflow (built-in workflow is used by default)
+    #[arg(short, long)]
+    workflow: Option<String>,
+
+    /// Comma separated Kafka bootstrap servers for domain events (events stay in the outbox if not set)
+    #[arg(short, long, value_delimiter = ',')]
+    kafka_brokers: Vec<String>,
+
+    /// How long responses of requests with Idempotency-Key header are stored, in hours
+    #[arg(long, default_value = "24")]
+    idempotency_window_hours: u64,
+
+    /// How long deleted tasks are kept in trash before they are purged, in days
+    #[arg(long, default_value = "30")]
+    trash_retention_days: u64,
+
+    /// How long before due date assignees are reminded about tasks, in hours
+    #[arg(long, default_value = "24")]
+    reminder_lead_hours: u64,
+
+    /// Where reminders about due tasks are delivered
+    #[arg(long, value_enum, default_value_t = ReminderNotifier::Log)]
+    reminder_notifier: ReminderNotifier,
+
+    /// Allow completing tasks which are blocked by unfinished tasks
+    #[arg(long)]
+    allow_completing_blocked_tasks: bool,
+
+    /// Where content of attachments is stored
+    #[arg(long, value_enum, default_value_t = AttachmentStorage::Local)]
+    attachment_storage: AttachmentStorage,
+
+    /// Directory of local attachment storage
+    #[arg(long, default_value = "attachments")]
+    attachment_dir: String,
+
+    /// Bucket of S3 attachment storage, credentials and region are taken from AWS_* environment variables
+    #[arg(long, default_value = "attachments")]
+    s3_bucket: String,
+
+    /// Endpoint of S3-compatible attachment storage, e.g. `http://minio:9000` (AWS is used if not set)
+    #[arg(long)]
+    s3_endpoint: Option<String>,
+
+    /// Maximum size of an attachment, in megabytes
+    #[arg(long, default_value = "10")]
+    max_attachment_size_mb: u64,
+
+    /// Comma separated MIME types allowed for attachments, `type/*` allows all subtypes
+    #[arg(
+        long,
+        value_delimiter = ',',
+        default_value = "image/*,text/plain,application/pdf,application/zip"
+    )]
+    attachment_types: Vec<String>,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum AttachmentStorage {
+    /// Files in `--attachment-dir`
+    Local,
+    /// Objects in `--s3-bucket`
+    S3,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum ReminderNotifier {
+    /// Write reminders to the log
+    Log,
+    /// Emit `TaskDueSoon` events
+    Events,
 }
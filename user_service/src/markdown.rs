@@ -0,0 +1,208 @@
+use crate::common::AppError;
+use ammonia::Builder;
+use pulldown_cmark::{html, CowStr, Event, LinkType, Options, Parser, Tag, TagEnd};
+use serde::Deserialize;
+
+pub const MAX_DESCRIPTION_LENGTH: usize = 10000;
+const TASK_ID_LENGTH: usize = 11;
+
+/// Format in which task descriptions are returned.
+#[derive(Deserialize, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DescriptionFormat {
+    /// Description as it was written
+    #[default]
+    Markdown,
+    /// Description is also rendered to sanitized HTML
+    Html,
+}
+
+/// Checks that description isn't too long and has no control characters
+/// other than line breaks and tabs.
+pub fn check_description(description: &str) -> Result<(), AppError> {
+    if description.chars().count() > MAX_DESCRIPTION_LENGTH
+        || description
+            .chars()
+            .any(|c| c.is_control() && !matches!(c, '\n' | '\r' | '\t'))
+    {
+        return Err(AppError::IncorrectRequest);
+    }
+    Ok(())
+}
+
+/// Renders CommonMark with task lists, tables and strikethrough to HTML which
+/// is safe to embed into a page. Mentions of `@username` and `#task-id`
+/// outside of code and links become relative links to `/users/{username}`
+/// and `/tasks/{id}`.
+pub fn to_html(text: &str) -> String {
+    let options =
+        Options::ENABLE_TASKLISTS | Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH;
+    // Parser splits text at characters which may start markup, so mentions
+    // are looked for in merged text
+    let mut events: Vec<Event> = Vec::new();
+    for event in Parser::new_ext(text, options) {
+        match (events.last_mut(), event) {
+            (Some(Event::Text(x)), Event::Text(y)) => *x = format!("{}{}", x, y).into(),
+            (_, event) => events.push(event),
+        }
+    }
+    let mut linked = Vec::with_capacity(events.len());
+    let mut depth = 0;
+    for event in events {
+        match &event {
+            Event::Start(Tag::Link { .. } | Tag::Image { .. } | Tag::CodeBlock(_)) => depth += 1,
+            Event::End(TagEnd::Link | TagEnd::Image | TagEnd::CodeBlock) => depth -= 1,
+            _ => {}
+        }
+        match event {
+            Event::Text(x) if depth == 0 => autolink(&x, &mut linked),
+            event => linked.push(event),
+        }
+    }
+    let mut output = String::new();
+    html::push_html(&mut output, linked.into_iter());
+    sanitizer().clean(&output).to_string()
+}
+
+fn autolink(text: &str, events: &mut Vec<Event>) {
+    let mut plain = 0;
+    let mut i = 0;
+    while let Some(c) = text[i..].chars().next() {
+        let at_boundary = !text[..i].chars().next_back().is_some_and(is_word);
+        if at_boundary && (c == '@' || c == '#') {
+            let rest = &text[i + 1..];
+            let name = match c {
+                '@' => rest[..rest
+                    .find(|x| !is_word(x) && x != '.' && x != '-')
+                    .unwrap_or(rest.len())]
+                    .trim_end_matches(['.', '-']),
+                _ => &rest[..rest.find(|x| !is_word(x) && x != '-').unwrap_or(rest.len())],
+            };
+            let url = match c {
+                '@' if !name.is_empty() => Some(format!("/users/{}", name)),
+                '#' if name.len() == TASK_ID_LENGTH => Some(format!("/tasks/{}", name)),
+                _ => None,
+            };
+            if let Some(url) = url {
+                let end = i + 1 + name.len();
+                if plain < i {
+                    events.push(Event::Text(text[plain..i].to_string().into()));
+                }
+                events.push(Event::Start(Tag::Link {
+                    link_type: LinkType::Inline,
+                    dest_url: url.into(),
+                    title: CowStr::from(""),
+                    id: CowStr::from(""),
+                }));
+                events.push(Event::Text(text[i..end].to_string().into()));
+                events.push(Event::End(TagEnd::Link));
+                plain = end;
+                i = end;
+                continue;
+            }
+        }
+        i += c.len_utf8();
+    }
+    if plain < text.len() {
+        events.push(Event::Text(text[plain..].to_string().into()));
+    }
+}
+
+fn is_word(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Allows the default safe subset of HTML, disabled checkboxes of task lists
+/// and language classes of code blocks.
+fn sanitizer() -> Builder<'static> {
+    let mut builder = Builder::default();
+    builder
+        .add_tags(&["input"])
+        .add_tag_attributes("input", &["checked"])
+        .set_tag_attribute_value("input", "type", "checkbox")
+        .set_tag_attribute_value("input", "disabled", "")
+        .add_tag_attributes("code", &["class"])
+        .attribute_filter(|element, attribute, value| match (element, attribute) {
+            ("code", "class") => value
+                .strip_prefix("language-")
+                .filter(|x| {
+                    !x.is_empty()
+                        && x.chars().all(|c| {
+                            c.is_ascii_alphanumeric() || matches!(c, '+' | '#' | '-' | '_')
+                        })
+                })
+                .map(|_| value.into()),
+            _ => Some(value.into()),
+        });
+    builder
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn html_is_sanitized() {
+        let html = to_html("<script>alert(1)</script>\n\n<img src=x onerror=alert(1)>");
+        assert!(!html.contains("<script"), "{}", html);
+        assert!(!html.contains("onerror"), "{}", html);
+        let html = to_html("[x](javascript:alert(1))");
+        assert!(!html.contains("javascript:"), "{}", html);
+    }
+
+    #[test]
+    fn mentions_become_links() {
+        assert_eq!(
+            to_html("Ask @alice.smith. about #zPMPqzjM0Fw"),
+            "<p>Ask <a href=\"/users/alice.smith\" rel=\"noopener noreferrer\">@alice.smith</a>. about <a href=\"/tasks/zPMPqzjM0Fw\" rel=\"noopener noreferrer\">#zPMPqzjM0Fw</a></p>\n"
+        );
+    }
+
+    #[test]
+    fn mentions_need_word_boundary_and_valid_ids() {
+        for text in [
+            "mail bob@example.com",
+            "issue#zPMPqzjM0Fw",
+            "#short",
+            "#zPMPqzjM0Fwx",
+            "@ alone",
+        ] {
+            assert!(!to_html(text).contains("<a"), "{}", text);
+        }
+    }
+
+    #[test]
+    fn mentions_in_code_and_links_are_kept() {
+        for text in [
+            "`@alice`",
+            "```\n@alice\n```",
+            "[see @alice](https://example.com)",
+        ] {
+            assert!(!to_html(text).contains("/users/"), "{}", text);
+        }
+    }
+
+    #[test]
+    fn only_language_classes_of_code_are_kept() {
+        assert!(to_html("```rust\nfn main() {}\n```").contains("<code class=\"language-rust\">"));
+        assert!(to_html("```c++\n```").contains("<code class=\"language-c++\">"));
+        let html = to_html("```x\" onclick=\"y\n```");
+        assert!(!html.contains("class"), "{}", html);
+        assert!(!html.contains("onclick"), "{}", html);
+    }
+
+    #[test]
+    fn task_list_checkboxes_are_disabled() {
+        let html = to_html("- [x] done\n- [ ] todo");
+        assert_eq!(html.matches("disabled=\"\"").count(), 2, "{}", html);
+        assert_eq!(html.matches("checked").count(), 1, "{}", html);
+    }
+
+    #[test]
+    fn descriptions_are_checked() {
+        assert!(check_description("line\r\n\tindented").is_ok());
+        assert!(check_description("bell\u{7}").is_err());
+        assert!(check_description(&"x".repeat(MAX_DESCRIPTION_LENGTH)).is_ok());
+        assert!(check_description(&"x".repeat(MAX_DESCRIPTION_LENGTH + 1)).is_err());
+    }
+}
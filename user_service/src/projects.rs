@@ -0,0 +1,364 @@
+use crate::common::{AppClaims, AppError, AppState, AppStateRef};
+use crate::proto::tasks_service as ts;
+use crate::users::{ensure_user_exists, Profiles, UserProfile};
+use axum::{
+    extract::{Path, State},
+    response::Result,
+    Json,
+};
+use log::info;
+use serde::{Deserialize, Serialize};
+
+const MAX_NAME_LENGTH: usize = 100;
+
+pub async fn create_project_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Json(req): Json<CreateProjectRequest>,
+) -> Result<Json<Project>, AppError> {
+    info!("create_project_handler: handling create project request");
+    check_name(&req.name)?;
+    let request = tonic::Request::new(ts::CreateProjectRequest {
+        user_id: claims.username,
+        name: req.name,
+        description: req.description.unwrap_or_default(),
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .create_project(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_project(&state, response.into_inner()).await
+}
+
+/// Lists projects which user created or is a member of.
+pub async fn list_projects_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+) -> Result<Json<Vec<Project>>, AppError> {
+    info!("list_projects_handler: handling list projects request");
+    let request = tonic::Request::new(ts::ListProjectsRequest {
+        user_id: claims.username,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .list_projects(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    let projects = match response.into_inner().response.unwrap() {
+        ts::project_list_response::Response::Projects(x) => x.projects,
+        ts::project_list_response::Response::Error(e) => return Err(e.into()),
+    };
+    make_projects(&state, projects).await.map(Json)
+}
+
+pub async fn get_project_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(project_id): Path<String>,
+) -> Result<Json<Project>, AppError> {
+    info!("get_project_handler: handling get project request");
+    let project = fetch_project(&state, &claims.username, &project_id).await?;
+    Ok(Json(
+        make_projects(&state, vec![project]).await?.pop().unwrap(),
+    ))
+}
+
+pub async fn update_project_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(project_id): Path<String>,
+    Json(req): Json<UpdateProjectRequest>,
+) -> Result<Json<Project>, AppError> {
+    info!("update_project_handler: handling update project request");
+    if let Some(x) = &req.new_name {
+        check_name(x)?;
+    }
+    let request = tonic::Request::new(ts::UpdateProjectRequest {
+        user_id: claims.username,
+        project_id,
+        new_name: req.new_name,
+        new_description: req.new_description,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .update_project(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_project(&state, response.into_inner()).await
+}
+
+/// Deletes the project, its tasks stay with their creators.
+pub async fn delete_project_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(project_id): Path<String>,
+) -> Result<Json<Project>, AppError> {
+    info!("delete_project_handler: handling delete project request");
+    let request = tonic::Request::new(ts::DeleteProjectRequest {
+        user_id: claims.username,
+        project_id,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .delete_project(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_project(&state, response.into_inner()).await
+}
+
+pub async fn get_project_members_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(project_id): Path<String>,
+) -> Result<Json<Vec<ProjectMember>>, AppError> {
+    info!("get_project_members_handler: handling get project members request");
+    let request = tonic::Request::new(ts::GetProjectRequest {
+        user_id: claims.username,
+        project_id,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .get_project_members(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_members(&state, response.into_inner()).await
+}
+
+pub async fn set_project_member_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(project_id): Path<String>,
+    Json(req): Json<SetProjectMemberRequest>,
+) -> Result<Json<Vec<ProjectMember>>, AppError> {
+    info!("set_project_member_handler: handling set project member request");
+    let role = ts::TaskRole::from_str_name(&req.role).ok_or(AppError::IncorrectRequest)?;
+    ensure_user_exists(&state.user_database, &req.username).await?;
+    let request = tonic::Request::new(ts::SetProjectMemberRequest {
+        user_id: claims.username,
+        project_id,
+        member_id: req.username,
+        role: role.into(),
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .set_project_member(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_members(&state, response.into_inner()).await
+}
+
+pub async fn remove_project_member_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path((project_id, username)): Path<(String, String)>,
+) -> Result<Json<Vec<ProjectMember>>, AppError> {
+    info!("remove_project_member_handler: handling remove project member request");
+    let request = tonic::Request::new(ts::RemoveProjectMemberRequest {
+        user_id: claims.username,
+        project_id,
+        member_id: username,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .remove_project_member(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_members(&state, response.into_inner()).await
+}
+
+/// Checks that user has at least given role in the project before requests
+/// concerning the project are forwarded to tasks_service.
+pub async fn check_project_role(
+    state: &AppState,
+    username: &str,
+    project_id: &str,
+    role: ts::TaskRole,
+) -> Result<(), AppError> {
+    let project = fetch_project(state, username, project_id).await?;
+    if project.role < i32::from(role) {
+        return Err(AppError::ProjectAccessDenied);
+    }
+    Ok(())
+}
+
+async fn fetch_project(
+    state: &AppState,
+    username: &str,
+    project_id: &str,
+) -> Result<ts::Project, AppError> {
+    let request = tonic::Request::new(ts::GetProjectRequest {
+        user_id: username.to_string(),
+        project_id: project_id.to_string(),
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .get_project(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    match response.into_inner().response.unwrap() {
+        ts::project_response::Response::Project(x) => Ok(x),
+        ts::project_response::Response::Error(e) => Err(e.into()),
+    }
+}
+
+async fn make_project(
+    state: &AppState,
+    response: ts::ProjectResponse,
+) -> Result<Json<Project>, AppError> {
+    match response.response.unwrap() {
+        ts::project_response::Response::Project(x) => {
+            Ok(Json(make_projects(state, vec![x]).await?.pop().unwrap()))
+        }
+        ts::project_response::Response::Error(e) => Err(e.into()),
+    }
+}
+
+/// Converts projects from tasks_service resolving their creators.
+async fn make_projects(
+    state: &AppState,
+    projects: Vec<ts::Project>,
+) -> Result<Vec<Project>, AppError> {
+    let usernames: Vec<&str> = projects.iter().map(|x| x.creator_id.as_str()).collect();
+    let profiles = Profiles::fetch(&state.user_database, &usernames).await?;
+    Ok(projects
+        .into_iter()
+        .map(|x| Project {
+            created_by: profiles.get(&x.creator_id),
+            role: role_name(x.role),
+            id: x.id,
+            name: x.name,
+            description: x.description,
+            created_at: x.created_at.unwrap().to_string(),
+        })
+        .collect())
+}
+
+async fn make_members(
+    state: &AppState,
+    response: ts::ProjectMembersResponse,
+) -> Result<Json<Vec<ProjectMember>>, AppError> {
+    let members = match response.response.unwrap() {
+        ts::project_members_response::Response::Members(x) => x.members,
+        ts::project_members_response::Response::Error(e) => return Err(e.into()),
+    };
+    let usernames: Vec<&str> = members.iter().map(|x| x.user_id.as_str()).collect();
+    let profiles = Profiles::fetch(&state.user_database, &usernames).await?;
+    Ok(Json(
+        members
+            .iter()
+            .map(|x| ProjectMember {
+                user: profiles.get(&x.user_id),
+                role: role_name(x.role),
+            })
+            .collect(),
+    ))
+}
+
+fn role_name(role: i32) -> String {
+    ts::TaskRole::try_from(role)
+        .unwrap()
+        .as_str_name()
+        .to_string()
+}
+
+fn check_name(name: &str) -> Result<(), AppError> {
+    if name.trim().is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+        return Err(AppError::IncorrectRequest);
+    }
+    Ok(())
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CreateProjectRequest {
+    name: String,
+    description: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateProjectRequest {
+    new_name: Option<String>,
+    new_description: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SetProjectMemberRequest {
+    username: String,
+    role: String,
+}
+
+#[derive(Serialize)]
+pub struct Project {
+    id: String,
+    name: String,
+    description: String,
+    created_by: UserProfile,
+    created_at: String,
+    /// Role of the current user in the project
+    role: String,
+}
+
+#[derive(Serialize)]
+pub struct ProjectMember {
+    user: UserProfile,
+    role: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn name_has_to_be_short_and_not_blank() {
+        assert!(check_name("Website").is_ok());
+        assert!(check_name(&"é".repeat(MAX_NAME_LENGTH)).is_ok());
+        assert!(matches!(
+            check_name(&"a".repeat(MAX_NAME_LENGTH + 1)),
+            Err(AppError::IncorrectRequest)
+        ));
+        assert!(matches!(check_name(" \t"), Err(AppError::IncorrectRequest)));
+    }
+
+    #[test]
+    fn roles_are_named_as_in_requests() {
+        for role in [
+            ts::TaskRole::Viewer,
+            ts::TaskRole::Editor,
+            ts::TaskRole::Owner,
+        ] {
+            let name = role_name(role.into());
+            assert_eq!(ts::TaskRole::from_str_name(&name), Some(role));
+        }
+    }
+
+    #[test]
+    fn requests_with_unknown_fields_are_rejected() {
+        let x: UpdateProjectRequest = serde_json::from_str(r#"{"new_name": "Website"}"#).unwrap();
+        assert_eq!(x.new_name.as_deref(), Some("Website"));
+        assert!(x.new_description.is_none());
+        assert!(serde_json::from_str::<UpdateProjectRequest>(r#"{"name": "Website"}"#).is_err());
+        assert!(serde_json::from_str::<SetProjectMemberRequest>(
+            r#"{"username": "alice", "role": "Editor", "owner": true}"#
+        )
+        .is_err());
+    }
+}
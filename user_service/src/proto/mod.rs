@@ -1 +1,5 @@
+pub mod events;
+// Generated code, responses with tasks are much larger than errors and enum
+// values are prefixed as they share the package scope
+#[allow(clippy::large_enum_variant, clippy::enum_variant_names)]
 pub mod tasks_service;
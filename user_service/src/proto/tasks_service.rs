@@ -7,6 +7,30 @@ pub struct CreateTaskRequest {
     pub title: ::prost::alloc::string::String,
     #[prost(string, tag = "3")]
     pub description: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "4")]
+    pub assigned_to: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "5")]
+    pub reviewed_by: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "6")]
+    pub start_date: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "7")]
+    pub due_date: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration = "TaskPriority", tag = "8")]
+    pub priority: i32,
+    #[prost(int32, optional, tag = "9")]
+    pub estimate_minutes: ::core::option::Option<i32>,
+    #[prost(string, optional, tag = "10")]
+    pub project_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "11")]
+    pub parent_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Open by default
+    #[prost(enumeration = "TaskStatus", optional, tag = "12")]
+    pub status: ::core::option::Option<i32>,
+    #[prost(string, repeated, tag = "13")]
+    pub label_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Texts of checklist items in their order
+    #[prost(string, repeated, tag = "14")]
+    pub checklist: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -27,6 +51,26 @@ pub struct UpdateTaskRequest {
     pub new_title: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(string, optional, tag = "4")]
     pub new_description: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration = "TaskStatus", optional, tag = "5")]
+    pub new_status: ::core::option::Option<i32>,
+    #[prost(enumeration = "TaskStatus", optional, tag = "6")]
+    pub expected_status: ::core::option::Option<i32>,
+    #[prost(string, optional, tag = "7")]
+    pub new_assigned_to: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "8")]
+    pub new_reviewed_by: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(int64, optional, tag = "9")]
+    pub expected_version: ::core::option::Option<i64>,
+    #[prost(string, optional, tag = "10")]
+    pub new_start_date: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "11")]
+    pub new_due_date: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration = "TaskPriority", optional, tag = "12")]
+    pub new_priority: ::core::option::Option<i32>,
+    #[prost(int32, optional, tag = "13")]
+    pub new_estimate_minutes: ::core::option::Option<i32>,
+    #[prost(bool, tag = "14")]
+    pub check_blockers: bool,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -35,206 +79,3066 @@ pub struct DeleteTaskRequest {
     pub user_id: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub task_id: ::prost::alloc::string::String,
+    #[prost(int64, optional, tag = "3")]
+    pub expected_version: ::core::option::Option<i64>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetTaskPageRequest {
+pub struct TransitionTaskRequest {
     #[prost(string, tag = "1")]
     pub user_id: ::prost::alloc::string::String,
-    #[prost(int32, tag = "2")]
-    pub start_id: i32,
-    #[prost(int32, tag = "3")]
-    pub page_size: i32,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub changes: ::prost::alloc::vec::Vec<StatusChange>,
+    #[prost(int64, optional, tag = "4")]
+    pub expected_version: ::core::option::Option<i64>,
+    #[prost(bool, tag = "5")]
+    pub check_blockers: bool,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct TaskResponse {
-    #[prost(oneof = "task_response::Response", tags = "1, 2")]
-    pub response: ::core::option::Option<task_response::Response>,
+pub struct MoveTaskRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "TaskStatus", optional, tag = "3")]
+    pub new_status: ::core::option::Option<i32>,
+    #[prost(enumeration = "TaskStatus", optional, tag = "4")]
+    pub expected_status: ::core::option::Option<i32>,
+    #[prost(string, optional, tag = "5")]
+    pub after_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(int64, optional, tag = "6")]
+    pub expected_version: ::core::option::Option<i64>,
+    #[prost(bool, tag = "7")]
+    pub check_blockers: bool,
 }
-/// Nested message and enum types in `TaskResponse`.
-pub mod task_response {
-    #[allow(clippy::derive_partial_eq_without_eq)]
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
-    pub enum Response {
-        #[prost(message, tag = "1")]
-        Task(super::Task),
-        #[prost(message, tag = "2")]
-        Error(super::Error),
-    }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetTaskParentRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "3")]
+    pub parent_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(int64, optional, tag = "4")]
+    pub expected_version: ::core::option::Option<i64>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct TaskPageResponse {
-    #[prost(oneof = "task_page_response::Response", tags = "1, 2")]
-    pub response: ::core::option::Option<task_page_response::Response>,
+pub struct TaskDependencyRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub blocker_id: ::prost::alloc::string::String,
 }
-/// Nested message and enum types in `TaskPageResponse`.
-pub mod task_page_response {
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddChecklistItemRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub text: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "4")]
+    pub assigned_to: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateChecklistItemRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub item_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "4")]
+    pub new_text: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, optional, tag = "5")]
+    pub new_done: ::core::option::Option<bool>,
+    #[prost(string, optional, tag = "6")]
+    pub new_assigned_to: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MoveChecklistItemRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub item_id: ::prost::alloc::string::String,
+    #[prost(int32, tag = "4")]
+    pub index: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChecklistItemRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub item_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddAttachmentRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub filename: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub content_type: ::prost::alloc::string::String,
+    #[prost(int64, tag = "5")]
+    pub size: i64,
+    #[prost(string, tag = "6")]
+    pub storage_key: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttachmentRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub attachment_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListDeletedAttachmentsRequest {
+    #[prost(int32, tag = "1")]
+    pub limit: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ForgetDeletedAttachmentsRequest {
+    #[prost(string, repeated, tag = "1")]
+    pub storage_keys: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetRunningTimerRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddTimeEntryRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub spent_on: ::prost::alloc::string::String,
+    #[prost(int32, tag = "4")]
+    pub minutes: i32,
+    #[prost(string, tag = "5")]
+    pub note: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateTimeEntryRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub entry_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "4")]
+    pub new_spent_on: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(int32, optional, tag = "5")]
+    pub new_minutes: ::core::option::Option<i32>,
+    #[prost(string, optional, tag = "6")]
+    pub new_note: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeEntryRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub entry_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTimeReportRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub from: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "3")]
+    pub to: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "4")]
+    pub member_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "5")]
+    pub task_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "6")]
+    pub project_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration = "TimeReportGroup", repeated, tag = "7")]
+    pub group_by: ::prost::alloc::vec::Vec<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StatusChange {
+    #[prost(enumeration = "TaskStatus", tag = "1")]
+    pub from: i32,
+    #[prost(enumeration = "TaskStatus", tag = "2")]
+    pub to: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchTasksRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub operations: ::prost::alloc::vec::Vec<TaskOperation>,
+    #[prost(bool, tag = "3")]
+    pub atomic: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaskOperation {
+    #[prost(oneof = "task_operation::Operation", tags = "1, 2, 3, 4")]
+    pub operation: ::core::option::Option<task_operation::Operation>,
+}
+/// Nested message and enum types in `TaskOperation`.
+pub mod task_operation {
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
-    pub enum Response {
+    pub enum Operation {
         #[prost(message, tag = "1")]
-        TaskPage(super::TaskPage),
+        Create(super::CreateTaskRequest),
         #[prost(message, tag = "2")]
-        Error(super::Error),
+        Update(super::UpdateTaskRequest),
+        #[prost(message, tag = "3")]
+        Delete(super::DeleteTaskRequest),
+        #[prost(message, tag = "4")]
+        Transition(super::TransitionTaskRequest),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct Task {
+pub struct GetTaskHistoryRequest {
     #[prost(string, tag = "1")]
-    pub id: ::prost::alloc::string::String,
-    #[prost(message, optional, tag = "2")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "3")]
+    pub before_id: i64,
+    #[prost(int32, tag = "4")]
+    pub page_size: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RestoreTaskVersionRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "3")]
+    pub version: i64,
+    #[prost(int64, optional, tag = "4")]
+    pub expected_version: ::core::option::Option<i64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTrashRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(int32, tag = "2")]
+    pub offset: i32,
+    #[prost(int32, tag = "3")]
+    pub page_size: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RestoreTaskRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PurgeTaskRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PurgeExpiredTasksRequest {
+    #[prost(message, optional, tag = "1")]
+    pub deleted_before: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTaskPageRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(int32, tag = "3")]
+    pub page_size: i32,
+    #[prost(enumeration = "TaskRelation", tag = "4")]
+    pub relation: i32,
+    #[prost(message, optional, tag = "5")]
+    pub after: ::core::option::Option<TaskCursor>,
+    #[prost(message, optional, tag = "6")]
+    pub before: ::core::option::Option<TaskCursor>,
+    #[prost(bool, tag = "7")]
+    pub include_total: bool,
+    #[prost(message, optional, tag = "8")]
+    pub filter: ::core::option::Option<TaskFilter>,
+    #[prost(enumeration = "TaskSortField", tag = "9")]
+    pub sort_field: i32,
+    #[prost(enumeration = "SortDirection", tag = "10")]
+    pub sort_direction: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaskCursor {
+    #[prost(message, optional, tag = "1")]
     pub created_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(string, tag = "2")]
+    pub id: ::prost::alloc::string::String,
     #[prost(string, tag = "3")]
     pub title: ::prost::alloc::string::String,
+    #[prost(enumeration = "TaskStatus", tag = "4")]
+    pub status: i32,
+    #[prost(string, optional, tag = "5")]
+    pub due_date: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration = "TaskPriority", tag = "6")]
+    pub priority: i32,
+    #[prost(int64, tag = "7")]
+    pub position: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaskFilter {
+    #[prost(enumeration = "TaskStatus", repeated, tag = "1")]
+    pub statuses: ::prost::alloc::vec::Vec<i32>,
+    #[prost(message, optional, tag = "2")]
+    pub created_after: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "3")]
+    pub created_before: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(string, optional, tag = "4")]
+    pub text: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "5")]
+    pub assigned_to: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration = "TaskPriority", repeated, tag = "6")]
+    pub priorities: ::prost::alloc::vec::Vec<i32>,
+    #[prost(string, optional, tag = "7")]
+    pub due_from: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "8")]
+    pub due_to: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, tag = "9")]
+    pub overdue: bool,
+    #[prost(string, repeated, tag = "10")]
+    pub labels: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "11")]
+    pub project_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "12")]
+    pub parent_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Tasks with any of these ids
+    #[prost(string, repeated, tag = "13")]
+    pub ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetDueTasksRequest {
+    #[prost(string, tag = "1")]
+    pub due_to: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateLabelRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub color: ::prost::alloc::string::String,
     #[prost(string, tag = "4")]
     pub description: ::prost::alloc::string::String,
-    #[prost(enumeration = "TaskStatus", tag = "5")]
-    pub status: i32,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct Error {
-    #[prost(int32, tag = "1")]
-    pub code: i32,
+pub struct UpdateLabelRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
-    pub message: ::prost::alloc::string::String,
+    pub label_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "3")]
+    pub new_name: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "4")]
+    pub new_color: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "5")]
+    pub new_description: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct TaskPage {
-    #[prost(message, repeated, tag = "1")]
-    pub tasks: ::prost::alloc::vec::Vec<Task>,
+pub struct DeleteLabelRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub label_id: ::prost::alloc::string::String,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
-#[repr(i32)]
-pub enum TaskStatus {
-    Open = 0,
-    InProgress = 1,
-    PendingReview = 2,
-    Testing = 3,
-    Completed = 4,
-    Cancelled = 5,
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListLabelsRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
 }
-impl TaskStatus {
-    /// String value of the enum field names used in the ProtoBuf definition.
-    ///
-    /// The values are not transformed in any way and thus are considered stable
-    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
-    pub fn as_str_name(&self) -> &'static str {
-        match self {
-            TaskStatus::Open => "Open",
-            TaskStatus::InProgress => "InProgress",
-            TaskStatus::PendingReview => "PendingReview",
-            TaskStatus::Testing => "Testing",
-            TaskStatus::Completed => "Completed",
-            TaskStatus::Cancelled => "Cancelled",
-        }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaskLabelRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub label_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateProjectRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub description: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetProjectRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub project_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateProjectRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub project_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "3")]
+    pub new_name: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "4")]
+    pub new_description: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteProjectRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub project_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListProjectsRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetProjectMemberRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub project_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub member_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "TaskRole", tag = "4")]
+    pub role: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveProjectMemberRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub project_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub member_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTaskAccessRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GrantTaskAccessRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub grantee_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "TaskRole", tag = "4")]
+    pub role: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RevokeTaskAccessRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub grantee_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateCommentRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "3")]
+    pub parent_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag = "4")]
+    pub text: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateCommentRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub comment_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub new_text: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteCommentRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub comment_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCommentPageRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    /// Number of top level comments to skip
+    #[prost(int32, tag = "3")]
+    pub offset: i32,
+    #[prost(int32, tag = "4")]
+    pub page_size: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchTasksRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub terms: ::prost::alloc::vec::Vec<SearchTerm>,
+    #[prost(message, optional, tag = "3")]
+    pub filter: ::core::option::Option<TaskFilter>,
+    #[prost(int32, tag = "4")]
+    pub offset: i32,
+    #[prost(int32, tag = "5")]
+    pub page_size: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchTerm {
+    #[prost(string, tag = "1")]
+    pub text: ::prost::alloc::string::String,
+    #[prost(enumeration = "SearchTermKind", tag = "2")]
+    pub kind: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaskResponse {
+    #[prost(oneof = "task_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<task_response::Response>,
+}
+/// Nested message and enum types in `TaskResponse`.
+pub mod task_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        Task(super::Task),
+        #[prost(message, tag = "2")]
+        Error(super::Error),
     }
-    /// Creates an enum from field names used in the ProtoBuf definition.
-    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
-        match value {
-            "Open" => Some(Self::Open),
-            "InProgress" => Some(Self::InProgress),
-            "PendingReview" => Some(Self::PendingReview),
-            "Testing" => Some(Self::Testing),
-            "Completed" => Some(Self::Completed),
-            "Cancelled" => Some(Self::Cancelled),
-            _ => None,
-        }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchTasksResponse {
+    #[prost(oneof = "batch_tasks_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<batch_tasks_response::Response>,
+}
+/// Nested message and enum types in `BatchTasksResponse`.
+pub mod batch_tasks_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        Results(super::BatchResults),
+        #[prost(message, tag = "2")]
+        Error(super::Error),
     }
 }
-/// Generated client implementations.
-pub mod tasks_service_client {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    use tonic::codegen::http::Uri;
-    #[derive(Debug, Clone)]
-    pub struct TasksServiceClient<T> {
-        inner: tonic::client::Grpc<T>,
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchResults {
+    #[prost(message, repeated, tag = "1")]
+    pub results: ::prost::alloc::vec::Vec<TaskResponse>,
+    #[prost(bool, tag = "2")]
+    pub committed: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaskHistoryResponse {
+    #[prost(oneof = "task_history_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<task_history_response::Response>,
+}
+/// Nested message and enum types in `TaskHistoryResponse`.
+pub mod task_history_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        History(super::TaskHistory),
+        #[prost(message, tag = "2")]
+        Error(super::Error),
     }
-    impl TasksServiceClient<tonic::transport::Channel> {
-        /// Attempt to create a new client by connecting to a given endpoint.
-        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
-        where
-            D: TryInto<tonic::transport::Endpoint>,
-            D::Error: Into<StdError>,
-        {
-            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
-            Ok(Self::new(conn))
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaskHistory {
+    #[prost(message, repeated, tag = "1")]
+    pub changes: ::prost::alloc::vec::Vec<TaskChange>,
+    #[prost(bool, tag = "2")]
+    pub has_more: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaskChange {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(int64, tag = "2")]
+    pub version: i64,
+    #[prost(string, tag = "3")]
+    pub actor_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "4")]
+    pub changed_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(enumeration = "TaskChangeAction", tag = "5")]
+    pub action: i32,
+    #[prost(message, repeated, tag = "6")]
+    pub fields: ::prost::alloc::vec::Vec<FieldChange>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FieldChange {
+    #[prost(string, tag = "1")]
+    pub field: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub old_value: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "3")]
+    pub new_value: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TrashResponse {
+    #[prost(oneof = "trash_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<trash_response::Response>,
+}
+/// Nested message and enum types in `TrashResponse`.
+pub mod trash_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        Trash(super::TrashPage),
+        #[prost(message, tag = "2")]
+        Error(super::Error),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TrashPage {
+    #[prost(message, repeated, tag = "1")]
+    pub items: ::prost::alloc::vec::Vec<TrashItem>,
+    #[prost(bool, tag = "2")]
+    pub has_more: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TrashItem {
+    #[prost(message, optional, tag = "1")]
+    pub task: ::core::option::Option<Task>,
+    #[prost(message, optional, tag = "2")]
+    pub deleted_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(string, tag = "3")]
+    pub deleted_by: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PurgeExpiredTasksResponse {
+    #[prost(oneof = "purge_expired_tasks_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<purge_expired_tasks_response::Response>,
+}
+/// Nested message and enum types in `PurgeExpiredTasksResponse`.
+pub mod purge_expired_tasks_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(int64, tag = "1")]
+        PurgedCount(i64),
+        #[prost(message, tag = "2")]
+        Error(super::Error),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaskPageResponse {
+    #[prost(oneof = "task_page_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<task_page_response::Response>,
+}
+/// Nested message and enum types in `TaskPageResponse`.
+pub mod task_page_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        TaskPage(super::TaskPage),
+        #[prost(message, tag = "2")]
+        Error(super::Error),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LabelResponse {
+    #[prost(oneof = "label_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<label_response::Response>,
+}
+/// Nested message and enum types in `LabelResponse`.
+pub mod label_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        Label(super::Label),
+        #[prost(message, tag = "2")]
+        Error(super::Error),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LabelListResponse {
+    #[prost(oneof = "label_list_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<label_list_response::Response>,
+}
+/// Nested message and enum types in `LabelListResponse`.
+pub mod label_list_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        Labels(super::LabelList),
+        #[prost(message, tag = "2")]
+        Error(super::Error),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaskDependenciesResponse {
+    #[prost(oneof = "task_dependencies_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<task_dependencies_response::Response>,
+}
+/// Nested message and enum types in `TaskDependenciesResponse`.
+pub mod task_dependencies_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        Dependencies(super::TaskDependencies),
+        #[prost(message, tag = "2")]
+        Error(super::Error),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChecklistResponse {
+    #[prost(oneof = "checklist_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<checklist_response::Response>,
+}
+/// Nested message and enum types in `ChecklistResponse`.
+pub mod checklist_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        Checklist(super::Checklist),
+        #[prost(message, tag = "2")]
+        Error(super::Error),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttachmentResponse {
+    #[prost(oneof = "attachment_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<attachment_response::Response>,
+}
+/// Nested message and enum types in `AttachmentResponse`.
+pub mod attachment_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        Attachment(super::Attachment),
+        #[prost(message, tag = "2")]
+        Error(super::Error),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttachmentListResponse {
+    #[prost(oneof = "attachment_list_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<attachment_list_response::Response>,
+}
+/// Nested message and enum types in `AttachmentListResponse`.
+pub mod attachment_list_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        Attachments(super::AttachmentList),
+        #[prost(message, tag = "2")]
+        Error(super::Error),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeletedAttachmentsResponse {
+    #[prost(oneof = "deleted_attachments_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<deleted_attachments_response::Response>,
+}
+/// Nested message and enum types in `DeletedAttachmentsResponse`.
+pub mod deleted_attachments_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        Deleted(super::DeletedAttachments),
+        #[prost(message, tag = "2")]
+        Error(super::Error),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeEntryResponse {
+    #[prost(oneof = "time_entry_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<time_entry_response::Response>,
+}
+/// Nested message and enum types in `TimeEntryResponse`.
+pub mod time_entry_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        Entry(super::TimeEntry),
+        #[prost(message, tag = "2")]
+        Error(super::Error),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeEntryListResponse {
+    #[prost(oneof = "time_entry_list_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<time_entry_list_response::Response>,
+}
+/// Nested message and enum types in `TimeEntryListResponse`.
+pub mod time_entry_list_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        Entries(super::TimeEntryList),
+        #[prost(message, tag = "2")]
+        Error(super::Error),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeReportResponse {
+    #[prost(oneof = "time_report_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<time_report_response::Response>,
+}
+/// Nested message and enum types in `TimeReportResponse`.
+pub mod time_report_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        Report(super::TimeReport),
+        #[prost(message, tag = "2")]
+        Error(super::Error),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProjectResponse {
+    #[prost(oneof = "project_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<project_response::Response>,
+}
+/// Nested message and enum types in `ProjectResponse`.
+pub mod project_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        Project(super::Project),
+        #[prost(message, tag = "2")]
+        Error(super::Error),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProjectListResponse {
+    #[prost(oneof = "project_list_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<project_list_response::Response>,
+}
+/// Nested message and enum types in `ProjectListResponse`.
+pub mod project_list_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        Projects(super::ProjectList),
+        #[prost(message, tag = "2")]
+        Error(super::Error),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProjectMembersResponse {
+    #[prost(oneof = "project_members_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<project_members_response::Response>,
+}
+/// Nested message and enum types in `ProjectMembersResponse`.
+pub mod project_members_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        Members(super::ProjectMembers),
+        #[prost(message, tag = "2")]
+        Error(super::Error),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaskAccessResponse {
+    #[prost(oneof = "task_access_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<task_access_response::Response>,
+}
+/// Nested message and enum types in `TaskAccessResponse`.
+pub mod task_access_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        AccessList(super::TaskAccessList),
+        #[prost(message, tag = "2")]
+        Error(super::Error),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommentResponse {
+    #[prost(oneof = "comment_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<comment_response::Response>,
+}
+/// Nested message and enum types in `CommentResponse`.
+pub mod comment_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        Comment(super::Comment),
+        #[prost(message, tag = "2")]
+        Error(super::Error),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommentPageResponse {
+    #[prost(oneof = "comment_page_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<comment_page_response::Response>,
+}
+/// Nested message and enum types in `CommentPageResponse`.
+pub mod comment_page_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        CommentPage(super::CommentPage),
+        #[prost(message, tag = "2")]
+        Error(super::Error),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchTasksResponse {
+    #[prost(oneof = "search_tasks_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<search_tasks_response::Response>,
+}
+/// Nested message and enum types in `SearchTasksResponse`.
+pub mod search_tasks_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "1")]
+        Results(super::SearchResults),
+        #[prost(message, tag = "2")]
+        Error(super::Error),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Task {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub created_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(string, tag = "3")]
+    pub title: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub description: ::prost::alloc::string::String,
+    #[prost(enumeration = "TaskStatus", tag = "5")]
+    pub status: i32,
+    #[prost(string, optional, tag = "6")]
+    pub assigned_to: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "7")]
+    pub reviewed_by: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(int64, tag = "8")]
+    pub version: i64,
+    #[prost(string, optional, tag = "9")]
+    pub start_date: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "10")]
+    pub due_date: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration = "TaskPriority", tag = "11")]
+    pub priority: i32,
+    #[prost(int32, optional, tag = "12")]
+    pub estimate_minutes: ::core::option::Option<i32>,
+    #[prost(message, repeated, tag = "13")]
+    pub labels: ::prost::alloc::vec::Vec<Label>,
+    #[prost(string, optional, tag = "14")]
+    pub project_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(int64, tag = "15")]
+    pub position: i64,
+    #[prost(string, optional, tag = "16")]
+    pub parent_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, optional, tag = "17")]
+    pub subtasks: ::core::option::Option<SubtaskProgress>,
+    #[prost(int32, tag = "18")]
+    pub open_blockers: i32,
+    #[prost(message, optional, tag = "19")]
+    pub checklist: ::core::option::Option<ChecklistProgress>,
+    /// Attachments without created_at and storage_key
+    #[prost(message, repeated, tag = "20")]
+    pub attachments: ::prost::alloc::vec::Vec<Attachment>,
+    #[prost(int32, tag = "21")]
+    pub logged_minutes: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeEntry {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub spent_on: ::prost::alloc::string::String,
+    /// Missing while the timer is running
+    #[prost(int32, optional, tag = "5")]
+    pub minutes: ::core::option::Option<i32>,
+    /// Start of the timer, missing for entries logged by hand
+    #[prost(message, optional, tag = "6")]
+    pub started_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(string, tag = "7")]
+    pub note: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "8")]
+    pub created_at: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeEntryList {
+    #[prost(message, repeated, tag = "1")]
+    pub entries: ::prost::alloc::vec::Vec<TimeEntry>,
+}
+/// Columns which aren't grouped by are missing
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeReportRow {
+    #[prost(string, optional, tag = "1")]
+    pub user_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "2")]
+    pub task_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "3")]
+    pub task_title: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "4")]
+    pub day: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(int64, tag = "5")]
+    pub minutes: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeReport {
+    #[prost(message, repeated, tag = "1")]
+    pub rows: ::prost::alloc::vec::Vec<TimeReportRow>,
+    #[prost(int64, tag = "2")]
+    pub total_minutes: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Attachment {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub filename: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub content_type: ::prost::alloc::string::String,
+    #[prost(int64, tag = "4")]
+    pub size: i64,
+    #[prost(string, tag = "5")]
+    pub uploader_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "6")]
+    pub created_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(string, tag = "7")]
+    pub storage_key: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttachmentList {
+    #[prost(message, repeated, tag = "1")]
+    pub attachments: ::prost::alloc::vec::Vec<Attachment>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeletedAttachments {
+    #[prost(string, repeated, tag = "1")]
+    pub storage_keys: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChecklistProgress {
+    #[prost(int32, tag = "1")]
+    pub total: i32,
+    #[prost(int32, tag = "2")]
+    pub done: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Checklist {
+    #[prost(message, repeated, tag = "1")]
+    pub items: ::prost::alloc::vec::Vec<ChecklistItem>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChecklistItem {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub text: ::prost::alloc::string::String,
+    #[prost(bool, tag = "3")]
+    pub done: bool,
+    #[prost(string, optional, tag = "4")]
+    pub assigned_to: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, optional, tag = "5")]
+    pub created_at: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubtaskProgress {
+    #[prost(int32, tag = "1")]
+    pub total: i32,
+    #[prost(int32, tag = "2")]
+    pub completed: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaskDependencies {
+    #[prost(message, repeated, tag = "1")]
+    pub blocked_by: ::prost::alloc::vec::Vec<Task>,
+    #[prost(message, repeated, tag = "2")]
+    pub blocks: ::prost::alloc::vec::Vec<Task>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Error {
+    #[prost(int32, tag = "1")]
+    pub code: i32,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaskPage {
+    #[prost(message, repeated, tag = "1")]
+    pub tasks: ::prost::alloc::vec::Vec<Task>,
+    #[prost(bool, tag = "2")]
+    pub has_more: bool,
+    #[prost(int64, tag = "3")]
+    pub total: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Label {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub color: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub description: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub creator_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LabelList {
+    #[prost(message, repeated, tag = "1")]
+    pub labels: ::prost::alloc::vec::Vec<Label>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Project {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub description: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub creator_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "5")]
+    pub created_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(enumeration = "TaskRole", tag = "6")]
+    pub role: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProjectList {
+    #[prost(message, repeated, tag = "1")]
+    pub projects: ::prost::alloc::vec::Vec<Project>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProjectMember {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "TaskRole", tag = "2")]
+    pub role: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProjectMembers {
+    #[prost(message, repeated, tag = "1")]
+    pub members: ::prost::alloc::vec::Vec<ProjectMember>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaskAccess {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "TaskRole", tag = "2")]
+    pub role: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaskAccessList {
+    #[prost(message, repeated, tag = "1")]
+    pub entries: ::prost::alloc::vec::Vec<TaskAccess>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Comment {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub author_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "4")]
+    pub parent_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag = "5")]
+    pub text: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "6")]
+    pub created_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "7")]
+    pub updated_at: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommentPage {
+    #[prost(message, repeated, tag = "1")]
+    pub comments: ::prost::alloc::vec::Vec<Comment>,
+    #[prost(bool, tag = "2")]
+    pub has_more: bool,
+    /// Latest replies are left out since page has too many of them
+    #[prost(bool, tag = "3")]
+    pub replies_truncated: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchHit {
+    #[prost(message, optional, tag = "1")]
+    pub task: ::core::option::Option<Task>,
+    #[prost(float, tag = "2")]
+    pub rank: f32,
+    #[prost(string, tag = "3")]
+    pub title_highlight: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub snippet: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchResults {
+    #[prost(message, repeated, tag = "1")]
+    pub hits: ::prost::alloc::vec::Vec<SearchHit>,
+    #[prost(bool, tag = "2")]
+    pub has_more: bool,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TaskStatus {
+    Open = 0,
+    InProgress = 1,
+    PendingReview = 2,
+    Testing = 3,
+    Completed = 4,
+    Cancelled = 5,
+}
+impl TaskStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TaskStatus::Open => "Open",
+            TaskStatus::InProgress => "InProgress",
+            TaskStatus::PendingReview => "PendingReview",
+            TaskStatus::Testing => "Testing",
+            TaskStatus::Completed => "Completed",
+            TaskStatus::Cancelled => "Cancelled",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Open" => Some(Self::Open),
+            "InProgress" => Some(Self::InProgress),
+            "PendingReview" => Some(Self::PendingReview),
+            "Testing" => Some(Self::Testing),
+            "Completed" => Some(Self::Completed),
+            "Cancelled" => Some(Self::Cancelled),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TaskPriority {
+    Low = 0,
+    Normal = 1,
+    High = 2,
+    Urgent = 3,
+}
+impl TaskPriority {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TaskPriority::Low => "Low",
+            TaskPriority::Normal => "Normal",
+            TaskPriority::High => "High",
+            TaskPriority::Urgent => "Urgent",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Low" => Some(Self::Low),
+            "Normal" => Some(Self::Normal),
+            "High" => Some(Self::High),
+            "Urgent" => Some(Self::Urgent),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SearchTermKind {
+    Word = 0,
+    Phrase = 1,
+    Prefix = 2,
+}
+impl SearchTermKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SearchTermKind::Word => "Word",
+            SearchTermKind::Phrase => "Phrase",
+            SearchTermKind::Prefix => "Prefix",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Word" => Some(Self::Word),
+            "Phrase" => Some(Self::Phrase),
+            "Prefix" => Some(Self::Prefix),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TaskSortField {
+    CreatedAt = 0,
+    Title = 1,
+    Status = 2,
+    DueDate = 3,
+    Priority = 4,
+    Position = 5,
+}
+impl TaskSortField {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TaskSortField::CreatedAt => "CreatedAt",
+            TaskSortField::Title => "Title",
+            TaskSortField::Status => "Status",
+            TaskSortField::DueDate => "DueDate",
+            TaskSortField::Priority => "Priority",
+            TaskSortField::Position => "Position",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CreatedAt" => Some(Self::CreatedAt),
+            "Title" => Some(Self::Title),
+            "Status" => Some(Self::Status),
+            "DueDate" => Some(Self::DueDate),
+            "Priority" => Some(Self::Priority),
+            "Position" => Some(Self::Position),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SortDirection {
+    Ascending = 0,
+    Descending = 1,
+}
+impl SortDirection {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "Ascending",
+            SortDirection::Descending => "Descending",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Ascending" => Some(Self::Ascending),
+            "Descending" => Some(Self::Descending),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TaskChangeAction {
+    Created = 0,
+    Updated = 1,
+    Deleted = 2,
+    Restored = 3,
+    RestoredFromTrash = 4,
+}
+impl TaskChangeAction {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TaskChangeAction::Created => "Created",
+            TaskChangeAction::Updated => "Updated",
+            TaskChangeAction::Deleted => "Deleted",
+            TaskChangeAction::Restored => "Restored",
+            TaskChangeAction::RestoredFromTrash => "RestoredFromTrash",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Created" => Some(Self::Created),
+            "Updated" => Some(Self::Updated),
+            "Deleted" => Some(Self::Deleted),
+            "Restored" => Some(Self::Restored),
+            "RestoredFromTrash" => Some(Self::RestoredFromTrash),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TaskRole {
+    Viewer = 0,
+    Editor = 1,
+    Owner = 2,
+}
+impl TaskRole {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TaskRole::Viewer => "Viewer",
+            TaskRole::Editor => "Editor",
+            TaskRole::Owner => "Owner",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Viewer" => Some(Self::Viewer),
+            "Editor" => Some(Self::Editor),
+            "Owner" => Some(Self::Owner),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TimeReportGroup {
+    ByUser = 0,
+    ByTask = 1,
+    ByDay = 2,
+}
+impl TimeReportGroup {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TimeReportGroup::ByUser => "ByUser",
+            TimeReportGroup::ByTask => "ByTask",
+            TimeReportGroup::ByDay => "ByDay",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ByUser" => Some(Self::ByUser),
+            "ByTask" => Some(Self::ByTask),
+            "ByDay" => Some(Self::ByDay),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TaskRelation {
+    CreatedOrShared = 0,
+    Assigned = 1,
+    AwaitingReview = 2,
+    Visible = 3,
+}
+impl TaskRelation {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TaskRelation::CreatedOrShared => "CreatedOrShared",
+            TaskRelation::Assigned => "Assigned",
+            TaskRelation::AwaitingReview => "AwaitingReview",
+            TaskRelation::Visible => "Visible",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CreatedOrShared" => Some(Self::CreatedOrShared),
+            "Assigned" => Some(Self::Assigned),
+            "AwaitingReview" => Some(Self::AwaitingReview),
+            "Visible" => Some(Self::Visible),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod tasks_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct TasksServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl TasksServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> TasksServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> TasksServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            TasksServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        pub async fn create_task(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateTaskRequest>,
+        ) -> std::result::Result<tonic::Response<super::TaskResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/CreateTask",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "CreateTask"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_task(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTaskRequest>,
+        ) -> std::result::Result<tonic::Response<super::TaskResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/GetTask",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "GetTask"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn update_task(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateTaskRequest>,
+        ) -> std::result::Result<tonic::Response<super::TaskResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/UpdateTask",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "UpdateTask"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn delete_task(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteTaskRequest>,
+        ) -> std::result::Result<tonic::Response<super::TaskResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/DeleteTask",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "DeleteTask"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_task_page(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTaskPageRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TaskPageResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/GetTaskPage",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "GetTaskPage"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_task_access(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTaskAccessRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TaskAccessResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/GetTaskAccess",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "GetTaskAccess"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn grant_task_access(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GrantTaskAccessRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TaskAccessResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/GrantTaskAccess",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("tasks_service.TasksService", "GrantTaskAccess"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn revoke_task_access(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RevokeTaskAccessRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TaskAccessResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/RevokeTaskAccess",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("tasks_service.TasksService", "RevokeTaskAccess"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn create_comment(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateCommentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CommentResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/CreateComment",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "CreateComment"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn update_comment(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateCommentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CommentResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/UpdateComment",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "UpdateComment"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn delete_comment(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteCommentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CommentResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/DeleteComment",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "DeleteComment"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_comment_page(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetCommentPageRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CommentPageResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/GetCommentPage",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "GetCommentPage"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn search_tasks(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SearchTasksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SearchTasksResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/SearchTasks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "SearchTasks"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn batch_tasks(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BatchTasksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BatchTasksResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/BatchTasks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "BatchTasks"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_task_history(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTaskHistoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TaskHistoryResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/GetTaskHistory",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "GetTaskHistory"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn restore_task_version(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RestoreTaskVersionRequest>,
+        ) -> std::result::Result<tonic::Response<super::TaskResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/RestoreTaskVersion",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("tasks_service.TasksService", "RestoreTaskVersion"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_trash(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListTrashRequest>,
+        ) -> std::result::Result<tonic::Response<super::TrashResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/ListTrash",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "ListTrash"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn restore_task(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RestoreTaskRequest>,
+        ) -> std::result::Result<tonic::Response<super::TaskResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/RestoreTask",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "RestoreTask"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn purge_task(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PurgeTaskRequest>,
+        ) -> std::result::Result<tonic::Response<super::TaskResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/PurgeTask",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "PurgeTask"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn purge_expired_tasks(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PurgeExpiredTasksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PurgeExpiredTasksResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/PurgeExpiredTasks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("tasks_service.TasksService", "PurgeExpiredTasks"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_due_tasks(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetDueTasksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TaskPageResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/GetDueTasks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "GetDueTasks"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn create_label(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateLabelRequest>,
+        ) -> std::result::Result<tonic::Response<super::LabelResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/CreateLabel",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "CreateLabel"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn update_label(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateLabelRequest>,
+        ) -> std::result::Result<tonic::Response<super::LabelResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/UpdateLabel",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "UpdateLabel"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn delete_label(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteLabelRequest>,
+        ) -> std::result::Result<tonic::Response<super::LabelResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/DeleteLabel",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "DeleteLabel"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_labels(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListLabelsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LabelListResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/ListLabels",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "ListLabels"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn add_task_label(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TaskLabelRequest>,
+        ) -> std::result::Result<tonic::Response<super::TaskResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/AddTaskLabel",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "AddTaskLabel"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn remove_task_label(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TaskLabelRequest>,
+        ) -> std::result::Result<tonic::Response<super::TaskResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/RemoveTaskLabel",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("tasks_service.TasksService", "RemoveTaskLabel"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn create_project(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateProjectRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProjectResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/CreateProject",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "CreateProject"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_project(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetProjectRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProjectResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/GetProject",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "GetProject"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn update_project(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateProjectRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProjectResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/UpdateProject",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "UpdateProject"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn delete_project(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteProjectRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProjectResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/DeleteProject",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "DeleteProject"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_projects(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListProjectsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProjectListResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/ListProjects",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "ListProjects"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_project_members(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetProjectRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProjectMembersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/GetProjectMembers",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("tasks_service.TasksService", "GetProjectMembers"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn set_project_member(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetProjectMemberRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProjectMembersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/SetProjectMember",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("tasks_service.TasksService", "SetProjectMember"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn remove_project_member(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RemoveProjectMemberRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProjectMembersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/RemoveProjectMember",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("tasks_service.TasksService", "RemoveProjectMember"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn move_task(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MoveTaskRequest>,
+        ) -> std::result::Result<tonic::Response<super::TaskResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/MoveTask",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "MoveTask"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn set_task_parent(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetTaskParentRequest>,
+        ) -> std::result::Result<tonic::Response<super::TaskResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/SetTaskParent",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "SetTaskParent"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_task_dependencies(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTaskRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TaskDependenciesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/GetTaskDependencies",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("tasks_service.TasksService", "GetTaskDependencies"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn add_task_dependency(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TaskDependencyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TaskDependenciesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/AddTaskDependency",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("tasks_service.TasksService", "AddTaskDependency"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn remove_task_dependency(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TaskDependencyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TaskDependenciesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/RemoveTaskDependency",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("tasks_service.TasksService", "RemoveTaskDependency"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_checklist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTaskRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ChecklistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/GetChecklist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "GetChecklist"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn add_checklist_item(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddChecklistItemRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ChecklistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/AddChecklistItem",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("tasks_service.TasksService", "AddChecklistItem"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn update_checklist_item(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateChecklistItemRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ChecklistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/UpdateChecklistItem",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("tasks_service.TasksService", "UpdateChecklistItem"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn move_checklist_item(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MoveChecklistItemRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ChecklistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/MoveChecklistItem",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("tasks_service.TasksService", "MoveChecklistItem"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn delete_checklist_item(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ChecklistItemRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ChecklistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/DeleteChecklistItem",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("tasks_service.TasksService", "DeleteChecklistItem"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn add_attachment(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddAttachmentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AttachmentResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/AddAttachment",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "AddAttachment"));
+            self.inner.unary(req, path, codec).await
         }
-    }
-    impl<T> TasksServiceClient<T>
-    where
-        T: tonic::client::GrpcService<tonic::body::BoxBody>,
-        T::Error: Into<StdError>,
-        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
-        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
-    {
-        pub fn new(inner: T) -> Self {
-            let inner = tonic::client::Grpc::new(inner);
-            Self { inner }
+        pub async fn list_attachments(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTaskRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AttachmentListResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/ListAttachments",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("tasks_service.TasksService", "ListAttachments"),
+                );
+            self.inner.unary(req, path, codec).await
         }
-        pub fn with_origin(inner: T, origin: Uri) -> Self {
-            let inner = tonic::client::Grpc::with_origin(inner, origin);
-            Self { inner }
+        pub async fn get_attachment(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AttachmentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AttachmentResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/GetAttachment",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "GetAttachment"));
+            self.inner.unary(req, path, codec).await
         }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> TasksServiceClient<InterceptedService<T, F>>
-        where
-            F: tonic::service::Interceptor,
-            T::ResponseBody: Default,
-            T: tonic::codegen::Service<
-                http::Request<tonic::body::BoxBody>,
-                Response = http::Response<
-                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
-                >,
-            >,
-            <T as tonic::codegen::Service<
-                http::Request<tonic::body::BoxBody>,
-            >>::Error: Into<StdError> + Send + Sync,
-        {
-            TasksServiceClient::new(InterceptedService::new(inner, interceptor))
+        pub async fn delete_attachment(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AttachmentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AttachmentResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/DeleteAttachment",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("tasks_service.TasksService", "DeleteAttachment"),
+                );
+            self.inner.unary(req, path, codec).await
         }
-        /// Compress requests with the given encoding.
-        ///
-        /// This requires the server to support it otherwise it might respond with an
-        /// error.
-        #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.send_compressed(encoding);
-            self
+        pub async fn list_deleted_attachments(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListDeletedAttachmentsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeletedAttachmentsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/ListDeletedAttachments",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "tasks_service.TasksService",
+                        "ListDeletedAttachments",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
-        /// Enable decompressing responses.
-        #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.accept_compressed(encoding);
-            self
+        pub async fn forget_deleted_attachments(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ForgetDeletedAttachmentsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeletedAttachmentsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/ForgetDeletedAttachments",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "tasks_service.TasksService",
+                        "ForgetDeletedAttachments",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
-        /// Limits the maximum size of a decoded message.
-        ///
-        /// Default: `4MB`
-        #[must_use]
-        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_decoding_message_size(limit);
-            self
+        pub async fn start_timer(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTaskRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimeEntryResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/StartTimer",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "StartTimer"));
+            self.inner.unary(req, path, codec).await
         }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_encoding_message_size(limit);
-            self
+        pub async fn stop_timer(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTaskRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimeEntryResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/StopTimer",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "StopTimer"));
+            self.inner.unary(req, path, codec).await
         }
-        pub async fn create_task(
+        pub async fn get_running_timer(
             &mut self,
-            request: impl tonic::IntoRequest<super::CreateTaskRequest>,
-        ) -> std::result::Result<tonic::Response<super::TaskResponse>, tonic::Status> {
+            request: impl tonic::IntoRequest<super::GetRunningTimerRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimeEntryResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -246,17 +3150,22 @@ pub mod tasks_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/tasks_service.TasksService/CreateTask",
+                "/tasks_service.TasksService/GetRunningTimer",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("tasks_service.TasksService", "CreateTask"));
+                .insert(
+                    GrpcMethod::new("tasks_service.TasksService", "GetRunningTimer"),
+                );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn get_task(
+        pub async fn add_time_entry(
             &mut self,
-            request: impl tonic::IntoRequest<super::GetTaskRequest>,
-        ) -> std::result::Result<tonic::Response<super::TaskResponse>, tonic::Status> {
+            request: impl tonic::IntoRequest<super::AddTimeEntryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimeEntryResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -268,17 +3177,20 @@ pub mod tasks_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/tasks_service.TasksService/GetTask",
+                "/tasks_service.TasksService/AddTimeEntry",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("tasks_service.TasksService", "GetTask"));
+                .insert(GrpcMethod::new("tasks_service.TasksService", "AddTimeEntry"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn update_task(
+        pub async fn update_time_entry(
             &mut self,
-            request: impl tonic::IntoRequest<super::UpdateTaskRequest>,
-        ) -> std::result::Result<tonic::Response<super::TaskResponse>, tonic::Status> {
+            request: impl tonic::IntoRequest<super::UpdateTimeEntryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimeEntryResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -290,17 +3202,22 @@ pub mod tasks_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/tasks_service.TasksService/UpdateTask",
+                "/tasks_service.TasksService/UpdateTimeEntry",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("tasks_service.TasksService", "UpdateTask"));
+                .insert(
+                    GrpcMethod::new("tasks_service.TasksService", "UpdateTimeEntry"),
+                );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn delete_task(
+        pub async fn delete_time_entry(
             &mut self,
-            request: impl tonic::IntoRequest<super::DeleteTaskRequest>,
-        ) -> std::result::Result<tonic::Response<super::TaskResponse>, tonic::Status> {
+            request: impl tonic::IntoRequest<super::TimeEntryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimeEntryResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -312,18 +3229,20 @@ pub mod tasks_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/tasks_service.TasksService/DeleteTask",
+                "/tasks_service.TasksService/DeleteTimeEntry",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("tasks_service.TasksService", "DeleteTask"));
+                .insert(
+                    GrpcMethod::new("tasks_service.TasksService", "DeleteTimeEntry"),
+                );
             self.inner.unary(req, path, codec).await
         }
-        pub async fn get_task_page(
+        pub async fn list_time_entries(
             &mut self,
-            request: impl tonic::IntoRequest<super::GetTaskPageRequest>,
+            request: impl tonic::IntoRequest<super::GetTaskRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::TaskPageResponse>,
+            tonic::Response<super::TimeEntryListResponse>,
             tonic::Status,
         > {
             self.inner
@@ -337,11 +3256,38 @@ pub mod tasks_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/tasks_service.TasksService/GetTaskPage",
+                "/tasks_service.TasksService/ListTimeEntries",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("tasks_service.TasksService", "GetTaskPage"));
+                .insert(
+                    GrpcMethod::new("tasks_service.TasksService", "ListTimeEntries"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_time_report(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTimeReportRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimeReportResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/tasks_service.TasksService/GetTimeReport",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("tasks_service.TasksService", "GetTimeReport"));
             self.inner.unary(req, path, codec).await
         }
     }
@@ -0,0 +1,674 @@
+use crate::common::{AppClaims, AppError, AppStateRef};
+use crate::events;
+use crate::proto::events::{self as ev, event::Payload};
+use crate::proto::tasks_service as ts;
+use crate::tasks::{fetch_task, parse_date, status_name, CreateTaskRequest};
+use axum::{
+    extract::{Path, State},
+    response::Result,
+    Json,
+};
+use chrono::{DateTime, Days, Months, NaiveDate, SecondsFormat, Utc};
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr, time::Duration};
+use tokio::time::sleep;
+use tokio_postgres::{types::Json as Jsonb, Row};
+
+const SCHEDULER_INTERVAL: Duration = Duration::from_secs(60);
+const MAX_INTERVAL: u32 = 1000;
+const MAX_COUNT: u32 = 1000;
+const SERIES_COLUMNS: &str =
+    "id, owner, template, rule, starts_on, last_on, next_on, last_task_id, created_at, stopped_at";
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+/// Subset of iCalendar RRULE: `FREQ=DAILY|WEEKLY|MONTHLY` with optional
+/// `INTERVAL`, and either `UNTIL` date or `COUNT` of occurrences.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Rule {
+    pub frequency: Frequency,
+    pub interval: u32,
+    pub until: Option<NaiveDate>,
+    pub count: Option<u32>,
+}
+
+impl Rule {
+    /// Dates of occurrences of the series which starts on `start`. Monthly
+    /// occurrences fall on the last day of shorter months.
+    pub fn dates(&self, start: NaiveDate) -> impl Iterator<Item = NaiveDate> + '_ {
+        let interval = u64::from(self.interval);
+        (0u64..)
+            .map_while(move |n| match self.frequency {
+                Frequency::Daily => start.checked_add_days(Days::new(n * interval)),
+                Frequency::Weekly => start.checked_add_days(Days::new(7 * n * interval)),
+                Frequency::Monthly => {
+                    start.checked_add_months(Months::new(u32::try_from(n * interval).ok()?))
+                }
+            })
+            .take_while(|x| *x <= self.until.unwrap_or(NaiveDate::MAX))
+            .take(self.count.map_or(usize::MAX, |x| x as usize))
+    }
+
+    /// First occurrence after `date`, or the first one at all.
+    fn next_after(&self, start: NaiveDate, date: Option<NaiveDate>) -> Option<NaiveDate> {
+        self.dates(start).find(|x| Some(*x) > date)
+    }
+}
+
+impl FromStr for Rule {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_ascii_uppercase();
+        let s = s.strip_prefix("RRULE:").unwrap_or(&s);
+        let (mut frequency, mut interval, mut until, mut count) = (None, None, None, None);
+        for part in s.split(';') {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or(AppError::InvalidRecurrenceRule)?;
+            let duplicate = match key {
+                "FREQ" => frequency
+                    .replace(match value {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        _ => return Err(AppError::InvalidRecurrenceRule),
+                    })
+                    .is_some(),
+                "INTERVAL" => interval
+                    .replace(parse_limited(value, MAX_INTERVAL)?)
+                    .is_some(),
+                // Time of day is ignored, occurrences are whole days
+                "UNTIL" => until
+                    .replace(
+                        NaiveDate::parse_from_str(value.get(..8).unwrap_or(value), "%Y%m%d")
+                            .ok()
+                            .filter(|_| value.len() == 8 || value[8..].starts_with('T'))
+                            .ok_or(AppError::InvalidRecurrenceRule)?,
+                    )
+                    .is_some(),
+                "COUNT" => count.replace(parse_limited(value, MAX_COUNT)?).is_some(),
+                _ => return Err(AppError::InvalidRecurrenceRule),
+            };
+            if duplicate {
+                return Err(AppError::InvalidRecurrenceRule);
+            }
+        }
+        if until.is_some() && count.is_some() {
+            return Err(AppError::InvalidRecurrenceRule);
+        }
+        Ok(Rule {
+            frequency: frequency.ok_or(AppError::InvalidRecurrenceRule)?,
+            interval: interval.unwrap_or(1),
+            until,
+            count,
+        })
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let frequency = match self.frequency {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+        };
+        write!(f, "FREQ={}", frequency)?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if let Some(until) = self.until {
+            write!(f, ";UNTIL={}", until.format("%Y%m%d"))?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_limited(value: &str, max: u32) -> Result<u32, AppError> {
+    value
+        .parse()
+        .ok()
+        .filter(|x| (1..=max).contains(x))
+        .ok_or(AppError::InvalidRecurrenceRule)
+}
+
+/// Creates a series of tasks made from the template. Occurrence is created on
+/// its date, or earlier once the previous occurrence is completed.
+pub async fn create_series_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Json(req): Json<CreateSeriesRequest>,
+) -> Result<Json<Series>, AppError> {
+    info!("create_series_handler: handling create series request");
+    let rule: Rule = req.rule.parse()?;
+    let starts_on = parse_date(&req.starts_on)?;
+    let next_on = rule
+        .next_after(starts_on, None)
+        .ok_or(AppError::InvalidRecurrenceRule)?;
+    req.template
+        .clone()
+        .into_request(next_on)
+        .into_proto(&state, &claims.username)
+        .await?;
+    let row = state
+        .user_database
+        .query_one(
+            &format!(
+                "INSERT INTO task_series (owner, template, rule, starts_on, next_on, created_at) VALUES ($1, $2, $3, $4, $5, now()) RETURNING {}",
+                SERIES_COLUMNS
+            ),
+            &[
+                &claims.username,
+                &Jsonb(&req.template),
+                &rule.to_string(),
+                &starts_on,
+                &next_on,
+            ],
+        )
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    Ok(Json(Series::from_row(&row)?))
+}
+
+/// Lists series which user owns, including stopped ones.
+pub async fn list_series_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+) -> Result<Json<Vec<Series>>, AppError> {
+    info!("list_series_handler: handling list series request");
+    let rows = state
+        .user_database
+        .query(
+            &format!(
+                "SELECT {} FROM task_series WHERE owner=$1 ORDER BY id",
+                SERIES_COLUMNS
+            ),
+            &[&claims.username],
+        )
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    Ok(Json(
+        rows.iter()
+            .map(Series::from_row)
+            .collect::<Result<_, _>>()?,
+    ))
+}
+
+pub async fn get_series_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(series_id): Path<i32>,
+) -> Result<Json<Series>, AppError> {
+    info!("get_series_handler: handling get series request");
+    Ok(Json(
+        fetch_series(&state, &claims.username, series_id).await?,
+    ))
+}
+
+/// Changes the rule or the template of the series. Already created tasks are
+/// kept, the new rule applies to occurrences after the last created one.
+pub async fn update_series_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(series_id): Path<i32>,
+    Json(req): Json<UpdateSeriesRequest>,
+) -> Result<Json<Series>, AppError> {
+    info!("update_series_handler: handling update series request");
+    let new_rule = req
+        .new_rule
+        .as_deref()
+        .map(str::parse::<Rule>)
+        .transpose()?;
+    loop {
+        let series = fetch_series(&state, &claims.username, series_id).await?;
+        if series.stopped_at.is_some() {
+            return Err(AppError::SeriesStopped);
+        }
+        let rule = new_rule.clone().unwrap_or(series.rule()?);
+        let template = req.new_template.clone().unwrap_or(series.template);
+        let next_on = rule.next_after(series.starts, series.last_on);
+        template
+            .clone()
+            .into_request(next_on.unwrap_or(series.starts))
+            .into_proto(&state, &claims.username)
+            .await?;
+        // Scheduler may have created an occurrence meanwhile, then the next
+        // date is computed again
+        let row = state
+            .user_database
+            .query_opt(
+                &format!(
+                    "UPDATE task_series SET rule=$3, template=$4, next_on=$5 WHERE id=$1 AND owner=$2 AND stopped_at IS NULL AND last_on IS NOT DISTINCT FROM $6 RETURNING {}",
+                    SERIES_COLUMNS
+                ),
+                &[
+                    &series_id,
+                    &claims.username,
+                    &rule.to_string(),
+                    &Jsonb(&template),
+                    &next_on,
+                    &series.last_on,
+                ],
+            )
+            .await
+            .map_err(|_| AppError::IncorrectRequest)?;
+        if let Some(row) = row {
+            return Ok(Json(Series::from_row(&row)?));
+        }
+    }
+}
+
+/// Stops creating tasks of the series, created tasks are kept. Stopping is
+/// permanent.
+pub async fn stop_series_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(series_id): Path<i32>,
+) -> Result<Json<Series>, AppError> {
+    info!("stop_series_handler: handling stop series request");
+    let row = state
+        .user_database
+        .query_opt(
+            &format!(
+                "UPDATE task_series SET stopped_at=COALESCE(stopped_at, now()) WHERE id=$1 AND owner=$2 RETURNING {}",
+                SERIES_COLUMNS
+            ),
+            &[&series_id, &claims.username],
+        )
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?
+        .ok_or(AppError::SeriesNotFound)?;
+    Ok(Json(Series::from_row(&row)?))
+}
+
+async fn fetch_series(
+    state: &AppStateRef,
+    username: &str,
+    series_id: i32,
+) -> Result<Series, AppError> {
+    let row = state
+        .user_database
+        .query_opt(
+            &format!(
+                "SELECT {} FROM task_series WHERE id=$1 AND owner=$2",
+                SERIES_COLUMNS
+            ),
+            &[&series_id, &username],
+        )
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?
+        .ok_or(AppError::SeriesNotFound)?;
+    Series::from_row(&row)
+}
+
+/// Periodically creates tasks of series which are due, or whose previous
+/// task was completed. Occurrences missed while the service was down are
+/// skipped, only the latest due one is created.
+pub async fn run_recurrence_scheduler(state: AppStateRef) {
+    info!("run_recurrence_scheduler: started");
+    loop {
+        if let Err(e) = create_occurrences(&state).await {
+            error!(
+                "run_recurrence_scheduler: couldn't create occurrences: {}",
+                e
+            );
+        }
+        sleep(SCHEDULER_INTERVAL).await;
+    }
+}
+
+async fn create_occurrences(state: &AppStateRef) -> Result<(), String> {
+    let today = Utc::now().date_naive();
+    let rows = state
+        .user_database
+        .query(
+            &format!(
+                "SELECT {} FROM task_series WHERE stopped_at IS NULL AND next_on IS NOT NULL AND (next_on<=$1 OR last_task_id IS NOT NULL)",
+                SERIES_COLUMNS
+            ),
+            &[&today],
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    for row in rows {
+        let series = Series::from_row(&row).map_err(|e| e.message().to_string())?;
+        if let Err(e) = create_occurrence(state, series, today).await {
+            error!("create_occurrences: {}", e);
+        }
+    }
+    Ok(())
+}
+
+async fn create_occurrence(
+    state: &AppStateRef,
+    series: Series,
+    today: NaiveDate,
+) -> Result<(), String> {
+    let rule = series.rule().map_err(|e| e.message().to_string())?;
+    let mut upcoming = rule
+        .dates(series.starts)
+        .skip_while(|x| Some(*x) <= series.last_on);
+    let Some(next) = upcoming.next() else {
+        return Ok(());
+    };
+    let date = if next <= today {
+        upcoming.take_while(|x| *x <= today).last().unwrap_or(next)
+    } else if is_previous_completed(state, &series).await {
+        next
+    } else {
+        return Ok(());
+    };
+    // Occurrence is reserved before creating the task, so it isn't created
+    // twice
+    let reserved = state
+        .user_database
+        .execute(
+            "UPDATE task_series SET last_on=$2, next_on=$3, last_task_id=NULL WHERE id=$1 AND stopped_at IS NULL AND last_on IS NOT DISTINCT FROM $4",
+            &[
+                &series.id,
+                &date,
+                &rule.next_after(series.starts, Some(date)),
+                &series.last_on,
+            ],
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    if reserved == 0 {
+        return Ok(());
+    }
+    match create_task(state, &series, date).await {
+        Ok(task_id) => {
+            info!(
+                "create_occurrence: created task {} of series {} for {}",
+                task_id, series.id, date
+            );
+            state
+                .user_database
+                .execute(
+                    "UPDATE task_series SET last_task_id=$3 WHERE id=$1 AND last_on=$2",
+                    &[&series.id, &date, &task_id],
+                )
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(())
+        }
+        Err(e) => {
+            state
+                .user_database
+                .execute(
+                    "UPDATE task_series SET last_on=$3, next_on=$4, last_task_id=$5 WHERE id=$1 AND last_on=$2",
+                    &[
+                        &series.id,
+                        &date,
+                        &series.last_on,
+                        &next,
+                        &series.last_task_id,
+                    ],
+                )
+                .await
+                .map_err(|e| e.to_string())?;
+            Err(format!(
+                "couldn't create task of series {} for {}: {}",
+                series.id, date, e
+            ))
+        }
+    }
+}
+
+async fn is_previous_completed(state: &AppStateRef, series: &Series) -> bool {
+    let Some(task_id) = &series.last_task_id else {
+        return false;
+    };
+    // Deleted or inaccessible task waits for the next date
+    fetch_task(state, &series.owner, task_id)
+        .await
+        .is_ok_and(|x| x.status == i32::from(ts::TaskStatus::Completed))
+}
+
+async fn create_task(
+    state: &AppStateRef,
+    series: &Series,
+    date: NaiveDate,
+) -> Result<String, String> {
+    let request = tonic::Request::new(ts::CreateTaskRequest {
+        user_id: series.owner.clone(),
+        ..series
+            .template
+            .clone()
+            .into_request(date)
+            .into_proto(state, &series.owner)
+            .await
+            .map_err(|e| e.message().to_string())?
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .create_task(request)
+        .await
+        .map_err(|e| e.to_string())?;
+    match response.into_inner().response {
+        Some(ts::task_response::Response::Task(x)) => {
+            // Task exists already, so the occurrence isn't retried
+            let event = events::emit(
+                state,
+                Payload::TaskCreated(ev::TaskCreated {
+                    task_id: x.id.clone(),
+                    user_id: series.owner.clone(),
+                    title: x.title,
+                    status: status_name(x.status),
+                }),
+            )
+            .await;
+            if event.is_err() {
+                error!("create_task: task {} was created without its event", x.id);
+            }
+            Ok(x.id)
+        }
+        Some(ts::task_response::Response::Error(e)) => Err(e.message),
+        None => Err("empty response".to_string()),
+    }
+}
+
+/// Task created for every occurrence, it is due on the date of occurrence.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct SeriesTemplate {
+    title: String,
+    description: String,
+    assigned_to: Option<String>,
+    reviewed_by: Option<String>,
+    priority: Option<String>,
+    estimate_minutes: Option<i32>,
+    project_id: Option<String>,
+}
+
+impl SeriesTemplate {
+    fn into_request(self, date: NaiveDate) -> CreateTaskRequest {
+        CreateTaskRequest {
+            title: self.title,
+            description: self.description,
+            assigned_to: self.assigned_to,
+            reviewed_by: self.reviewed_by,
+            start_date: None,
+            due_date: Some(date.to_string()),
+            priority: self.priority,
+            estimate_minutes: self.estimate_minutes,
+            project_id: self.project_id,
+            parent_id: None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CreateSeriesRequest {
+    rule: String,
+    /// Date of the first occurrence
+    starts_on: String,
+    template: SeriesTemplate,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateSeriesRequest {
+    new_rule: Option<String>,
+    new_template: Option<SeriesTemplate>,
+}
+
+#[derive(Serialize)]
+pub struct Series {
+    id: i32,
+    rule: String,
+    starts_on: String,
+    template: SeriesTemplate,
+    /// Date of the next occurrence, missing once the series is finished
+    next_on: Option<String>,
+    /// Task of the latest occurrence
+    last_task_id: Option<String>,
+    created_at: String,
+    stopped_at: Option<String>,
+    #[serde(skip)]
+    owner: String,
+    #[serde(skip)]
+    starts: NaiveDate,
+    #[serde(skip)]
+    last_on: Option<NaiveDate>,
+}
+
+impl Series {
+    fn from_row(row: &Row) -> Result<Self, AppError> {
+        let Jsonb(template) = row
+            .try_get("template")
+            .map_err(|_| AppError::IncorrectRequest)?;
+        let starts: NaiveDate = row.get("starts_on");
+        let timestamp = |x: DateTime<Utc>| x.to_rfc3339_opts(SecondsFormat::AutoSi, true);
+        Ok(Series {
+            id: row.get("id"),
+            owner: row.get("owner"),
+            template,
+            rule: row.get("rule"),
+            starts_on: starts.to_string(),
+            starts,
+            last_on: row.get("last_on"),
+            next_on: row
+                .get::<_, Option<NaiveDate>>("next_on")
+                .map(|x| x.to_string()),
+            last_task_id: row.get("last_task_id"),
+            created_at: timestamp(row.get("created_at")),
+            stopped_at: row
+                .get::<_, Option<DateTime<Utc>>>("stopped_at")
+                .map(timestamp),
+        })
+    }
+
+    fn rule(&self) -> Result<Rule, AppError> {
+        self.rule.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    fn dates(rule: &str, start: &str) -> Vec<String> {
+        let rule: Rule = rule.parse().unwrap();
+        rule.dates(date(start))
+            .take(5)
+            .map(|x| x.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn rule_is_parsed_and_formatted() {
+        let rule: Rule = " rrule:freq=weekly;interval=2;count=3 ".parse().unwrap();
+        assert_eq!(
+            rule,
+            Rule {
+                frequency: Frequency::Weekly,
+                interval: 2,
+                until: None,
+                count: Some(3),
+            }
+        );
+        assert_eq!(rule.to_string(), "FREQ=WEEKLY;INTERVAL=2;COUNT=3");
+        let rule: Rule = "FREQ=DAILY;UNTIL=20240301T120000Z".parse().unwrap();
+        assert_eq!(rule.until, Some(date("2024-03-01")));
+        assert_eq!(rule.to_string(), "FREQ=DAILY;UNTIL=20240301");
+    }
+
+    #[test]
+    fn invalid_rules_are_rejected() {
+        for rule in [
+            "",
+            "INTERVAL=2",
+            "FREQ=YEARLY",
+            "FREQ=DAILY;FREQ=WEEKLY",
+            "FREQ=DAILY;INTERVAL=0",
+            "FREQ=DAILY;INTERVAL=1001",
+            "FREQ=DAILY;COUNT=0",
+            "FREQ=DAILY;COUNT=2;UNTIL=20240301",
+            "FREQ=DAILY;UNTIL=2024-03-01",
+            "FREQ=DAILY;UNTIL=20240301X",
+            "FREQ=DAILY;BYDAY=MO",
+            "FREQ=DAILY;",
+        ] {
+            assert!(
+                matches!(rule.parse::<Rule>(), Err(AppError::InvalidRecurrenceRule)),
+                "{}",
+                rule
+            );
+        }
+    }
+
+    #[test]
+    fn monthly_dates_fall_on_last_day_of_shorter_months() {
+        assert_eq!(
+            dates("FREQ=MONTHLY", "2024-01-31"),
+            [
+                "2024-01-31",
+                "2024-02-29",
+                "2024-03-31",
+                "2024-04-30",
+                "2024-05-31"
+            ]
+        );
+    }
+
+    #[test]
+    fn dates_stop_at_count_or_until() {
+        assert_eq!(
+            dates("FREQ=DAILY;INTERVAL=3;COUNT=2", "2024-03-01"),
+            ["2024-03-01", "2024-03-04"]
+        );
+        assert_eq!(
+            dates("FREQ=WEEKLY;UNTIL=20240315", "2024-03-01"),
+            ["2024-03-01", "2024-03-08", "2024-03-15"]
+        );
+    }
+
+    #[test]
+    fn next_occurrence_follows_the_date() {
+        let rule: Rule = "FREQ=WEEKLY;COUNT=2".parse().unwrap();
+        let start = date("2024-03-01");
+        assert_eq!(rule.next_after(start, None), Some(start));
+        assert_eq!(
+            rule.next_after(start, Some(date("2024-03-03"))),
+            Some(date("2024-03-08"))
+        );
+        assert_eq!(rule.next_after(start, Some(date("2024-03-08"))), None);
+    }
+}
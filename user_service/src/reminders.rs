@@ -0,0 +1,149 @@
+use crate::common::{AppState, AppStateRef};
+use crate::events;
+use crate::proto::events::{self as ev, event::Payload};
+use crate::proto::tasks_service as ts;
+use axum::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use log::{error, info};
+use std::time::{Duration, SystemTime};
+use tokio::time::sleep;
+
+const REMINDER_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// Reminder for the assignee that the task is due soon.
+pub struct Reminder {
+    pub task_id: String,
+    pub title: String,
+    pub assignee: String,
+    /// Due date in `YYYY-MM-DD` format
+    pub due_date: String,
+}
+
+/// Delivers reminders to assignees, e.g. by email or push notification.
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    async fn notify(&self, reminder: &Reminder) -> Result<(), String>;
+}
+
+/// Only writes reminders to the log.
+pub struct LogNotifier;
+
+#[async_trait]
+impl Notifier for LogNotifier {
+    async fn notify(&self, reminder: &Reminder) -> Result<(), String> {
+        info!(
+            "reminder: task {} \"{}\" of {} is due {}",
+            reminder.task_id, reminder.title, reminder.assignee, reminder.due_date
+        );
+        Ok(())
+    }
+}
+
+/// Emits `TaskDueSoon` domain events, so reminders are delivered by
+/// consumers of task events.
+pub struct EventNotifier {
+    pub state: AppStateRef,
+}
+
+#[async_trait]
+impl Notifier for EventNotifier {
+    async fn notify(&self, reminder: &Reminder) -> Result<(), String> {
+        events::emit(
+            &self.state,
+            Payload::TaskDueSoon(ev::TaskDueSoon {
+                task_id: reminder.task_id.clone(),
+                assignee: reminder.assignee.clone(),
+                title: reminder.title.clone(),
+                due_date: reminder.due_date.clone(),
+            }),
+        )
+        .await
+        .map_err(|e| e.message().to_string())
+    }
+}
+
+/// Periodically reminds assignees about tasks which are due within `lead`
+/// time. Every assignee is reminded once per task and due date, so changing
+/// the due date or the assignee leads to a new reminder.
+pub async fn run_reminder_scheduler(
+    state: AppStateRef,
+    notifier: Box<dyn Notifier>,
+    lead: Duration,
+) {
+    info!("run_reminder_scheduler: started");
+    loop {
+        if let Err(e) = send_reminders(&state, notifier.as_ref(), lead).await {
+            error!("run_reminder_scheduler: couldn't send reminders: {}", e);
+        }
+        sleep(REMINDER_INTERVAL).await;
+    }
+}
+
+async fn send_reminders(
+    state: &AppState,
+    notifier: &dyn Notifier,
+    lead: Duration,
+) -> Result<(), String> {
+    let today = Utc::now().date_naive();
+    let due_to = DateTime::<Utc>::from(SystemTime::now() + lead).date_naive();
+    let request = tonic::Request::new(ts::GetDueTasksRequest {
+        due_to: due_to.to_string(),
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .get_due_tasks(request)
+        .await
+        .map_err(|e| e.to_string())?;
+    let tasks = match response.into_inner().response {
+        Some(ts::task_page_response::Response::TaskPage(x)) => x.tasks,
+        Some(ts::task_page_response::Response::Error(e)) => return Err(e.message),
+        None => return Err("empty response".to_string()),
+    };
+    for task in tasks {
+        let (Some(assignee), Some(due_date)) = (task.assigned_to, task.due_date) else {
+            continue;
+        };
+        let date = NaiveDate::parse_from_str(&due_date, "%Y-%m-%d").map_err(|e| e.to_string())?;
+        // Reminder is reserved before sending, so it isn't sent twice
+        let reserved = state
+            .user_database
+            .execute(
+                "INSERT INTO task_reminders (task_id, due_date, assignee, sent_at) VALUES ($1, $2, $3, now()) ON CONFLICT DO NOTHING",
+                &[&task.id, &date, &assignee],
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+        if reserved == 0 {
+            continue;
+        }
+        let reminder = Reminder {
+            task_id: task.id,
+            title: task.title,
+            assignee,
+            due_date,
+        };
+        if let Err(e) = notifier.notify(&reminder).await {
+            error!(
+                "send_reminders: couldn't remind about task {}: {}",
+                reminder.task_id, e
+            );
+            state
+                .user_database
+                .execute(
+                    "DELETE FROM task_reminders WHERE task_id=$1 AND due_date=$2 AND assignee=$3",
+                    &[&reminder.task_id, &date, &reminder.assignee],
+                )
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+    }
+    // Tasks which are already due aren't reminded about anymore
+    state
+        .user_database
+        .execute("DELETE FROM task_reminders WHERE due_date<$1", &[&today])
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
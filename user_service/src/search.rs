@@ -0,0 +1,150 @@
+use crate::common::AppError;
+use crate::proto::tasks_service as ts;
+
+/// Parsed full-text search query.
+///
+/// Query consists of words, `"quoted phrases"`, prefixes ending with `*` and
+/// `field:value` operators. Supported operators are `status:<TaskStatus>`
+/// (can be repeated to match any of statuses) and `assignee:<username>`.
+pub struct SearchQuery {
+    pub terms: Vec<ts::SearchTerm>,
+    pub statuses: Vec<i32>,
+    pub assigned_to: Option<String>,
+}
+
+impl SearchQuery {
+    pub fn parse(q: &str) -> Result<Self, AppError> {
+        let mut query = SearchQuery {
+            terms: Vec::new(),
+            statuses: Vec::new(),
+            assigned_to: None,
+        };
+        let mut chars = q.chars().peekable();
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() {
+                chars.next();
+            } else if c == '"' {
+                chars.next();
+                let phrase = chars.by_ref().take_while(|&x| x != '"').collect();
+                query.add_term(phrase, ts::SearchTermKind::Phrase);
+            } else {
+                let token: String = chars.by_ref().take_while(|x| !x.is_whitespace()).collect();
+                query.add_token(&token)?;
+            }
+        }
+        if query.terms.is_empty() {
+            return Err(AppError::IncorrectRequest);
+        }
+        Ok(query)
+    }
+
+    fn add_token(&mut self, token: &str) -> Result<(), AppError> {
+        match token.split_once(':') {
+            Some(("status", value)) => {
+                let status = (0..)
+                    .map_while(|x| ts::TaskStatus::try_from(x).ok())
+                    .find(|x| x.as_str_name().eq_ignore_ascii_case(value))
+                    .ok_or(AppError::IncorrectRequest)?;
+                self.statuses.push(status.into());
+            }
+            Some(("assignee", value)) if !value.is_empty() => {
+                if self.assigned_to.replace(value.to_string()).is_some() {
+                    return Err(AppError::IncorrectRequest);
+                }
+            }
+            _ => match token.strip_suffix('*') {
+                Some(prefix) => self.add_term(prefix.to_string(), ts::SearchTermKind::Prefix),
+                None => self.add_term(token.to_string(), ts::SearchTermKind::Word),
+            },
+        }
+        Ok(())
+    }
+
+    /// Terms without letters and digits can't match anything and are skipped.
+    /// Numeric characters other than digits, e.g. `½`, aren't indexed.
+    fn add_term(&mut self, text: String, kind: ts::SearchTermKind) {
+        if text
+            .chars()
+            .any(|c| c.is_alphabetic() || c.is_ascii_digit())
+        {
+            self.terms.push(ts::SearchTerm {
+                text,
+                kind: kind.into(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ts::SearchTermKind::*;
+
+    fn terms(query: &SearchQuery) -> Vec<(&str, ts::SearchTermKind)> {
+        query
+            .terms
+            .iter()
+            .map(|x| (x.text.as_str(), x.kind()))
+            .collect()
+    }
+
+    #[test]
+    fn words_phrases_and_prefixes_are_parsed() {
+        let query = SearchQuery::parse(r#"fix  "login page" crash* "#).unwrap();
+        assert_eq!(
+            terms(&query),
+            [("fix", Word), ("login page", Phrase), ("crash", Prefix)]
+        );
+        assert!(query.statuses.is_empty());
+        assert_eq!(query.assigned_to, None);
+    }
+
+    #[test]
+    fn unterminated_quote_takes_rest_of_query() {
+        let query = SearchQuery::parse(r#"bug "out of memory"#).unwrap();
+        assert_eq!(terms(&query), [("bug", Word), ("out of memory", Phrase)]);
+    }
+
+    #[test]
+    fn status_operators_are_collected() {
+        assert!(matches!(
+            SearchQuery::parse("status:done bug"),
+            Err(AppError::IncorrectRequest)
+        ));
+        let query = SearchQuery::parse("status:open bug status:INPROGRESS").unwrap();
+        assert_eq!(
+            query.statuses,
+            [
+                i32::from(ts::TaskStatus::Open),
+                i32::from(ts::TaskStatus::InProgress)
+            ]
+        );
+        assert_eq!(terms(&query), [("bug", Word)]);
+    }
+
+    #[test]
+    fn assignee_operator_is_allowed_once() {
+        let query = SearchQuery::parse("assignee:alice bug").unwrap();
+        assert_eq!(query.assigned_to.as_deref(), Some("alice"));
+        assert!(SearchQuery::parse("assignee:alice assignee:bob bug").is_err());
+        // Operator without value is searched as a word
+        let query = SearchQuery::parse("assignee: bug").unwrap();
+        assert_eq!(query.assigned_to, None);
+        assert_eq!(terms(&query), [("assignee:", Word), ("bug", Word)]);
+    }
+
+    #[test]
+    fn query_without_terms_is_rejected() {
+        for q in [
+            "",
+            "   ",
+            "status:open",
+            "assignee:alice",
+            "* \"\" -",
+            "\"\"",
+            "½* \"² ³\" -*",
+        ] {
+            assert!(SearchQuery::parse(q).is_err(), "{:?}", q);
+        }
+    }
+}
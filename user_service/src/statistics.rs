@@ -0,0 +1,141 @@
+use crate::common::AppError;
+use axum::async_trait;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use tokio::sync::RwLock;
+
+#[derive(Serialize, Clone, Copy, Default)]
+pub struct TaskStats {
+    pub views: u64,
+    pub likes: u64,
+}
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum StatsMetric {
+    Views,
+    Likes,
+}
+
+/// Storage of task views and likes.
+#[async_trait]
+pub trait StatisticsClient: Send + Sync {
+    async fn record_view(&self, task_id: &str, username: &str) -> Result<(), AppError>;
+    /// Likes the task. Repeated likes by the same user are ignored.
+    async fn like(&self, task_id: &str, username: &str) -> Result<(), AppError>;
+    async fn unlike(&self, task_id: &str, username: &str) -> Result<(), AppError>;
+    async fn get_stats(&self, task_id: &str) -> Result<TaskStats, AppError>;
+    /// Lists ids of at most `limit` tasks sorted by `metric` in descending
+    /// order, tasks with the same value are sorted by id.
+    async fn top_tasks(
+        &self,
+        metric: StatsMetric,
+        limit: usize,
+    ) -> Result<Vec<(String, TaskStats)>, AppError>;
+}
+
+/// Statistics kept in memory of the service. They are lost on restart.
+#[derive(Default)]
+pub struct InMemoryStatistics {
+    tasks: RwLock<HashMap<String, TaskCounters>>,
+}
+
+#[derive(Default)]
+struct TaskCounters {
+    views: u64,
+    likes: HashSet<String>,
+}
+
+impl TaskCounters {
+    fn stats(&self) -> TaskStats {
+        TaskStats {
+            views: self.views,
+            likes: self.likes.len() as u64,
+        }
+    }
+}
+
+#[async_trait]
+impl StatisticsClient for InMemoryStatistics {
+    async fn record_view(&self, task_id: &str, _username: &str) -> Result<(), AppError> {
+        let mut tasks = self.tasks.write().await;
+        tasks.entry(task_id.to_string()).or_default().views += 1;
+        Ok(())
+    }
+
+    async fn like(&self, task_id: &str, username: &str) -> Result<(), AppError> {
+        let mut tasks = self.tasks.write().await;
+        tasks
+            .entry(task_id.to_string())
+            .or_default()
+            .likes
+            .insert(username.to_string());
+        Ok(())
+    }
+
+    async fn unlike(&self, task_id: &str, username: &str) -> Result<(), AppError> {
+        let mut tasks = self.tasks.write().await;
+        if let Some(counters) = tasks.get_mut(task_id) {
+            counters.likes.remove(username);
+        }
+        Ok(())
+    }
+
+    async fn get_stats(&self, task_id: &str) -> Result<TaskStats, AppError> {
+        let tasks = self.tasks.read().await;
+        Ok(tasks.get(task_id).map(|x| x.stats()).unwrap_or_default())
+    }
+
+    async fn top_tasks(
+        &self,
+        metric: StatsMetric,
+        limit: usize,
+    ) -> Result<Vec<(String, TaskStats)>, AppError> {
+        let tasks = self.tasks.read().await;
+        // Heap keeps the best tasks found so far with the worst one on top
+        let mut top = BinaryHeap::with_capacity(limit + 1);
+        for (id, x) in tasks.iter() {
+            let stats = x.stats();
+            let value = match metric {
+                StatsMetric::Views => stats.views,
+                StatsMetric::Likes => stats.likes,
+            };
+            top.push(Reverse((value, Reverse(id.as_str()))));
+            if top.len() > limit {
+                top.pop();
+            }
+        }
+        Ok(top
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((_, Reverse(id)))| (id.to_string(), tasks[id].stats()))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn top_tasks_are_limited_and_sorted() {
+        let statistics = InMemoryStatistics::default();
+        for (task_id, views) in [("a", 2), ("b", 5), ("c", 1), ("d", 5), ("e", 3)] {
+            for _ in 0..views {
+                statistics.record_view(task_id, "alice").await.unwrap();
+            }
+        }
+        statistics.like("c", "alice").await.unwrap();
+        statistics.like("c", "bob").await.unwrap();
+        statistics.like("a", "bob").await.unwrap();
+        let ids = |x: Vec<(String, TaskStats)>| x.into_iter().map(|(id, _)| id).collect::<Vec<_>>();
+        let top = statistics.top_tasks(StatsMetric::Views, 3).await.unwrap();
+        assert_eq!(top[0].1.views, 5);
+        assert_eq!(ids(top), ["b", "d", "e"]);
+        let top = statistics.top_tasks(StatsMetric::Likes, 2).await.unwrap();
+        assert_eq!(ids(top), ["c", "a"]);
+        let top = statistics.top_tasks(StatsMetric::Views, 10).await.unwrap();
+        assert_eq!(ids(top), ["b", "d", "e", "a", "c"]);
+    }
+}
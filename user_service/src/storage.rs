@@ -0,0 +1,127 @@
+use axum::{async_trait, body::Bytes};
+use futures::{stream::BoxStream, StreamExt, TryStreamExt};
+use object_store::{
+    aws::AmazonS3Builder, buffered::BufWriter, path::Path, GetOptions, GetRange, ObjectStore,
+};
+use std::{io, io::SeekFrom, ops::Range, path::PathBuf, sync::Arc};
+use tokio::{
+    fs::{self, File},
+    io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt},
+};
+use tokio_util::io::{ReaderStream, StreamReader};
+
+/// Content of a stored file as a stream of chunks.
+pub type ByteStream<'a> = BoxStream<'a, io::Result<Bytes>>;
+
+/// Storage of attachment content. Keys are generated by user service, so
+/// they are safe to use as file names.
+#[async_trait]
+pub trait Storage: Send + Sync {
+    /// Stores content under the key. Nothing is stored if the stream fails.
+    async fn put(&self, key: &str, content: ByteStream<'_>) -> io::Result<()>;
+    /// Reads non-empty range of the content, the range has to be within its
+    /// size.
+    async fn get(&self, key: &str, range: Range<u64>) -> io::Result<ByteStream<'static>>;
+    /// Removes the content, missing keys are ignored.
+    async fn delete(&self, key: &str) -> io::Result<()>;
+}
+
+/// Files in a directory of local filesystem.
+pub struct LocalStorage {
+    root: PathBuf,
+}
+
+impl LocalStorage {
+    pub async fn new(root: impl Into<PathBuf>) -> io::Result<Self> {
+        let root = root.into();
+        fs::create_dir_all(&root).await?;
+        Ok(LocalStorage { root })
+    }
+}
+
+#[async_trait]
+impl Storage for LocalStorage {
+    async fn put(&self, key: &str, content: ByteStream<'_>) -> io::Result<()> {
+        // Content is renamed into place once complete, so readers never see
+        // partial files
+        let path = self.root.join(key);
+        let partial = self.root.join(format!("{}.part", key));
+        let result = async {
+            let mut file = File::create(&partial).await?;
+            tokio::io::copy(&mut StreamReader::new(content), &mut file).await?;
+            file.sync_all().await?;
+            fs::rename(&partial, &path).await
+        }
+        .await;
+        if result.is_err() {
+            let _ = fs::remove_file(&partial).await;
+        }
+        result
+    }
+
+    async fn get(&self, key: &str, range: Range<u64>) -> io::Result<ByteStream<'static>> {
+        let mut file = File::open(self.root.join(key)).await?;
+        file.seek(SeekFrom::Start(range.start)).await?;
+        Ok(ReaderStream::new(file.take(range.end - range.start)).boxed())
+    }
+
+    async fn delete(&self, key: &str) -> io::Result<()> {
+        match fs::remove_file(self.root.join(key)).await {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Objects in a bucket of S3 or S3-compatible storage.
+pub struct S3Storage {
+    store: Arc<dyn ObjectStore>,
+}
+
+impl S3Storage {
+    /// Connects to the bucket with credentials and region from `AWS_*`
+    /// environment variables. Custom endpoint is addressed in path style and
+    /// may use plain HTTP, e.g. a local MinIO.
+    pub fn new(bucket: &str, endpoint: Option<&str>) -> object_store::Result<Self> {
+        let mut builder = AmazonS3Builder::from_env().with_bucket_name(bucket);
+        if let Some(endpoint) = endpoint {
+            builder = builder.with_endpoint(endpoint).with_allow_http(true);
+        }
+        Ok(S3Storage {
+            store: Arc::new(builder.build()?),
+        })
+    }
+}
+
+#[async_trait]
+impl Storage for S3Storage {
+    async fn put(&self, key: &str, content: ByteStream<'_>) -> io::Result<()> {
+        // Large content is sent as multipart upload which is aborted on error
+        let mut writer = BufWriter::new(self.store.clone(), Path::from(key));
+        let result = async {
+            tokio::io::copy(&mut StreamReader::new(content), &mut writer).await?;
+            writer.shutdown().await
+        }
+        .await;
+        if result.is_err() {
+            let _ = writer.abort().await;
+        }
+        result
+    }
+
+    async fn get(&self, key: &str, range: Range<u64>) -> io::Result<ByteStream<'static>> {
+        let options = GetOptions {
+            range: Some(GetRange::Bounded(range.start as usize..range.end as usize)),
+            ..Default::default()
+        };
+        let result = self.store.get_opts(&Path::from(key), options).await?;
+        Ok(result.into_stream().map_err(io::Error::from).boxed())
+    }
+
+    async fn delete(&self, key: &str) -> io::Result<()> {
+        match self.store.delete(&Path::from(key)).await {
+            Err(object_store::Error::NotFound { .. }) | Ok(_) => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
@@ -1,20 +1,55 @@
-use crate::common::{AppClaims, AppError, AppStateRef};
+use crate::common::{AppClaims, AppError, AppState, AppStateRef};
+use crate::cursor::PageCursor;
+use crate::etag::{etag, format_etag, if_match_version, is_not_modified};
+use crate::events;
+use crate::labels::Label;
+use crate::markdown::{self, check_description, DescriptionFormat};
+use crate::projects::check_project_role;
+use crate::proto::events::{self as ev, event::Payload};
 use crate::proto::tasks_service as ts;
-use axum::{extract::State, response::Result, Json};
+use crate::search::SearchQuery;
+use crate::statistics::{StatsMetric, TaskStats};
+use crate::users::{ensure_user_exists, Profiles, UserProfile};
+use crate::workflow::TransitionConfig;
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response, Result},
+    Json,
+};
+use chrono::{NaiveDate, NaiveTime};
 use jwt_simple::prelude::*;
-use log::info;
+use log::{error, info};
 use serde::Deserialize;
+use std::collections::HashMap;
+
+pub(crate) const DEFAULT_PAGE_SIZE: i32 = 20;
+pub(crate) const MAX_PAGE_SIZE: i32 = 100;
+const MAX_FILTER_TEXT_LENGTH: usize = 100;
+const MAX_SEARCH_QUERY_LENGTH: usize = 200;
+const MAX_BATCH_SIZE: usize = 100;
+/// Number of top tasks per requested one whose visibility is checked at once.
+const TOP_TASKS_BATCH_FACTOR: usize = 4;
 
 pub async fn create_task_handler(
     State(state): State<AppStateRef>,
     claims: AppClaims,
     Json(req): Json<CreateTaskRequest>,
-) -> Result<Json<Task>, AppError> {
+) -> Result<Response, AppError> {
     info!("create_task_handler: handling create task request");
+    let request = req.into_proto(&state, &claims.username).await?;
+    create_task(&state, claims.username, request).await
+}
+
+/// Creates task on behalf of the user and makes response with it.
+pub async fn create_task(
+    state: &AppState,
+    username: String,
+    request: ts::CreateTaskRequest,
+) -> Result<Response, AppError> {
     let request = tonic::Request::new(ts::CreateTaskRequest {
-        user_id: claims.username,
-        title: req.title,
-        description: req.description,
+        user_id: username.clone(),
+        ..request
     });
     let response = state
         .tasks_service
@@ -24,63 +59,65 @@ pub async fn create_task_handler(
         .await
         .map_err(|_| AppError::IncorrectRequest)?;
     match response.into_inner().response.unwrap() {
-        ts::task_response::Response::Task(x) => Ok(Json(Task {
-            id: x.id,
-            created_at: x.created_at.unwrap().to_string(),
-            title: x.title,
-            description: x.description,
-            status: ts::TaskStatus::try_from(x.status)
-                .unwrap()
-                .as_str_name()
-                .to_string(),
-        })),
-        ts::task_response::Response::Error(_) => Err(AppError::IncorrectRequest),
+        ts::task_response::Response::Task(x) => {
+            events::emit(
+                state,
+                Payload::TaskCreated(ev::TaskCreated {
+                    task_id: x.id.clone(),
+                    user_id: username,
+                    title: x.title.clone(),
+                    status: status_name(x.status),
+                }),
+            )
+            .await?;
+            Ok(tagged(make_task(state, x).await?))
+        }
+        ts::task_response::Response::Error(e) => Err(e.into()),
     }
 }
 
 pub async fn get_task_handler(
     State(state): State<AppStateRef>,
     claims: AppClaims,
+    headers: HeaderMap,
     Json(req): Json<GetTaskRequest>,
-) -> Result<Json<Task>, AppError> {
+) -> Result<Response, AppError> {
     info!("get_task_handler: handling get task request");
-    let request = tonic::Request::new(ts::GetTaskRequest {
-        user_id: claims.username,
-        task_id: req.task_id,
-    });
-    let response = state
-        .tasks_service
-        .write()
+    let task = fetch_task(&state, &claims.username, &req.task_id).await?;
+    if let Err(e) = state
+        .statistics
+        .record_view(&task.id, &claims.username)
         .await
-        .get_task(request)
-        .await
-        .map_err(|_| AppError::IncorrectRequest)?;
-    match response.into_inner().response.unwrap() {
-        ts::task_response::Response::Task(x) => Ok(Json(Task {
-            id: x.id,
-            created_at: x.created_at.unwrap().to_string(),
-            title: x.title,
-            description: x.description,
-            status: ts::TaskStatus::try_from(x.status)
-                .unwrap()
-                .as_str_name()
-                .to_string(),
-        })),
-        ts::task_response::Response::Error(_) => Err(AppError::IncorrectRequest),
+    {
+        error!("get_task_handler: couldn't record view: {:?}", e);
     }
+    events::emit(
+        &state,
+        Payload::TaskViewed(ev::TaskViewed {
+            task_id: task.id.clone(),
+            user_id: claims.username,
+        }),
+    )
+    .await?;
+    let tag = format_etag(task.version, req.format.unwrap_or_default());
+    if is_not_modified(&headers, &tag) {
+        return Ok((StatusCode::NOT_MODIFIED, [(header::ETAG, tag)]).into_response());
+    }
+    let task = make_task(&state, task).await?.formatted(req.format);
+    Ok(([(header::ETAG, tag)], Json(task)).into_response())
 }
 
 pub async fn update_task_handler(
     State(state): State<AppStateRef>,
     claims: AppClaims,
+    headers: HeaderMap,
     Json(req): Json<UpdateTaskRequest>,
-) -> Result<Json<Task>, AppError> {
+) -> Result<Response, AppError> {
     info!("update_task_handler: handling update task request");
     let request = tonic::Request::new(ts::UpdateTaskRequest {
-        user_id: claims.username,
-        task_id: req.task_id,
-        new_title: req.new_title,
-        new_description: req.new_description,
+        user_id: claims.username.clone(),
+        expected_version: if_match_version(&headers)?,
+        ..req.into_proto(&state).await?
     });
     let response = state
         .tasks_service
@@ -90,29 +127,34 @@ pub async fn update_task_handler(
         .await
         .map_err(|_| AppError::IncorrectRequest)?;
     match response.into_inner().response.unwrap() {
-        ts::task_response::Response::Task(x) => Ok(Json(Task {
-            id: x.id,
-            created_at: x.created_at.unwrap().to_string(),
-            title: x.title,
-            description: x.description,
-            status: ts::TaskStatus::try_from(x.status)
-                .unwrap()
-                .as_str_name()
-                .to_string(),
-        })),
-        ts::task_response::Response::Error(_) => Err(AppError::IncorrectRequest),
+        ts::task_response::Response::Task(x) => {
+            events::emit(
+                &state,
+                Payload::TaskUpdated(ev::TaskUpdated {
+                    task_id: x.id.clone(),
+                    user_id: claims.username,
+                    title: x.title.clone(),
+                    status: status_name(x.status),
+                }),
+            )
+            .await?;
+            Ok(tagged(make_task(&state, x).await?))
+        }
+        ts::task_response::Response::Error(e) => Err(e.into()),
     }
 }
 
 pub async fn delete_task_handler(
     State(state): State<AppStateRef>,
     claims: AppClaims,
+    headers: HeaderMap,
     Json(req): Json<DeleteTaskRequest>,
 ) -> Result<Json<Task>, AppError> {
     info!("delete_task_handler: handling delete task request");
     let request = tonic::Request::new(ts::DeleteTaskRequest {
-        user_id: claims.username,
+        user_id: claims.username.clone(),
         task_id: req.task_id,
+        expected_version: if_match_version(&headers)?,
     });
     let response = state
         .tasks_service
@@ -122,17 +164,18 @@ pub async fn delete_task_handler(
         .await
         .map_err(|_| AppError::IncorrectRequest)?;
     match response.into_inner().response.unwrap() {
-        ts::task_response::Response::Task(x) => Ok(Json(Task {
-            id: x.id,
-            created_at: x.created_at.unwrap().to_string(),
-            title: x.title,
-            description: x.description,
-            status: ts::TaskStatus::try_from(x.status)
-                .unwrap()
-                .as_str_name()
-                .to_string(),
-        })),
-        ts::task_response::Response::Error(_) => Err(AppError::IncorrectRequest),
+        ts::task_response::Response::Task(x) => {
+            events::emit(
+                &state,
+                Payload::TaskDeleted(ev::TaskDeleted {
+                    task_id: x.id.clone(),
+                    user_id: claims.username,
+                }),
+            )
+            .await?;
+            Ok(Json(make_task(&state, x).await?))
+        }
+        ts::task_response::Response::Error(e) => Err(e.into()),
     }
 }
 
@@ -142,50 +185,731 @@ pub async fn get_task_page_handler(
     Json(req): Json<GetTaskPageRequest>,
 ) -> Result<Json<TaskPage>, AppError> {
     info!("get_task_page_handler: handling get task page request");
-    let request = tonic::Request::new(ts::GetTaskPageRequest {
+    fetch_task_page(
+        &state,
+        claims.username,
+        ts::TaskRelation::CreatedOrShared,
+        req,
+    )
+    .await
+}
+
+pub async fn get_assigned_task_page_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Query(req): Query<GetTaskPageRequest>,
+) -> Result<Json<TaskPage>, AppError> {
+    info!("get_assigned_task_page_handler: handling get assigned task page request");
+    fetch_task_page(&state, claims.username, ts::TaskRelation::Assigned, req).await
+}
+
+pub async fn get_awaiting_review_task_page_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Query(req): Query<GetTaskPageRequest>,
+) -> Result<Json<TaskPage>, AppError> {
+    info!("get_awaiting_review_task_page_handler: handling get awaiting review task page request");
+    fetch_task_page(
+        &state,
+        claims.username,
+        ts::TaskRelation::AwaitingReview,
+        req,
+    )
+    .await
+}
+
+/// Lists unfinished tasks visible to the user which are past their due date,
+/// the most overdue first unless other sorting is requested.
+pub async fn get_overdue_task_page_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Query(mut req): Query<GetTaskPageRequest>,
+) -> Result<Json<TaskPage>, AppError> {
+    info!("get_overdue_task_page_handler: handling get overdue task page request");
+    req.overdue = Some(true);
+    req.sort.get_or_insert(TaskSortField::DueDate);
+    fetch_task_page(&state, claims.username, ts::TaskRelation::Visible, req).await
+}
+
+/// Lists tasks of the project, user must be a member of it.
+pub async fn get_project_task_page_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(project_id): Path<String>,
+    Query(mut req): Query<GetTaskPageRequest>,
+) -> Result<Json<TaskPage>, AppError> {
+    info!("get_project_task_page_handler: handling get project task page request");
+    check_project_role(&state, &claims.username, &project_id, ts::TaskRole::Viewer).await?;
+    req.project = Some(project_id);
+    fetch_task_page(&state, claims.username, ts::TaskRelation::Visible, req).await
+}
+
+/// Lists subtasks of the task which are visible to the user.
+pub async fn get_subtask_page_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+    Query(mut req): Query<GetTaskPageRequest>,
+) -> Result<Json<TaskPage>, AppError> {
+    info!("get_subtask_page_handler: handling get subtask page request");
+    fetch_task(&state, &claims.username, &task_id).await?;
+    req.parent = Some(task_id);
+    fetch_task_page(&state, claims.username, ts::TaskRelation::Visible, req).await
+}
+
+pub async fn fetch_task_page(
+    state: &AppStateRef,
+    username: String,
+    relation: ts::TaskRelation,
+    req: GetTaskPageRequest,
+) -> Result<Json<TaskPage>, AppError> {
+    let page_size = req.page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+    if !(1..=MAX_PAGE_SIZE).contains(&page_size) {
+        return Err(AppError::IncorrectRequest);
+    }
+    let mut request = ts::GetTaskPageRequest {
+        relation: relation.into(),
+        filter: Some(make_task_filter(&req)?),
+        sort_field: match req.sort.unwrap_or(TaskSortField::CreatedAt) {
+            TaskSortField::CreatedAt => ts::TaskSortField::CreatedAt,
+            TaskSortField::Title => ts::TaskSortField::Title,
+            TaskSortField::Status => ts::TaskSortField::Status,
+            TaskSortField::DueDate => ts::TaskSortField::DueDate,
+            TaskSortField::Priority => ts::TaskSortField::Priority,
+            TaskSortField::Position => ts::TaskSortField::Position,
+        }
+        .into(),
+        sort_direction: match req.order.unwrap_or(SortOrder::Asc) {
+            SortOrder::Asc => ts::SortDirection::Ascending,
+            SortOrder::Desc => ts::SortDirection::Descending,
+        }
+        .into(),
+        ..Default::default()
+    };
+    let scope = PageCursor::scope(&request);
+    let cursor = req
+        .cursor
+        .map(|x| PageCursor::decode(&x, &state.cursor_key, &username, scope))
+        .transpose()?;
+    match &cursor {
+        Some(PageCursor::After(x)) => request.after = Some(x.clone()),
+        Some(PageCursor::Before(x)) => request.before = Some(x.clone()),
+        None => {}
+    };
+    let include_total = req.include_total.unwrap_or(false);
+    request.user_id = username.clone();
+    request.page_size = page_size;
+    request.include_total = include_total;
+    let request = tonic::Request::new(request);
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .get_task_page(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    let page = match response.into_inner().response.unwrap() {
+        ts::task_page_response::Response::TaskPage(x) => x,
+        ts::task_page_response::Response::Error(e) => return Err(e.into()),
+    };
+
+    // Page which was requested backwards has more tasks before it and
+    // always has tasks after it, and vice versa
+    let backwards = matches!(cursor, Some(PageCursor::Before(_)));
+    let (has_prev, has_next) = if backwards {
+        (page.has_more, true)
+    } else {
+        (cursor.is_some(), page.has_more)
+    };
+    let encode = |x: PageCursor| x.encode(&state.cursor_key, &username, scope);
+    let prev_cursor = page
+        .tasks
+        .first()
+        .filter(|_| has_prev)
+        .map(|x| encode(PageCursor::before(x)));
+    let next_cursor = page
+        .tasks
+        .last()
+        .filter(|_| has_next)
+        .map(|x| encode(PageCursor::after(x)));
+    Ok(Json(TaskPage {
+        tasks: make_tasks(state, page.tasks)
+            .await?
+            .into_iter()
+            .map(|x| x.formatted(req.format))
+            .collect(),
+        next_cursor,
+        prev_cursor,
+        total: include_total.then_some(page.total),
+    }))
+}
+
+pub async fn search_tasks_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Query(req): Query<SearchTasksRequest>,
+) -> Result<Json<SearchResults>, AppError> {
+    info!("search_tasks_handler: handling search tasks request");
+    let page_size = req.page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+    let offset = req.offset.unwrap_or(0);
+    if !(1..=MAX_PAGE_SIZE).contains(&page_size)
+        || offset < 0
+        || req.q.chars().count() > MAX_SEARCH_QUERY_LENGTH
+    {
+        return Err(AppError::IncorrectRequest);
+    }
+    let query = SearchQuery::parse(&req.q)?;
+    let request = tonic::Request::new(ts::SearchTasksRequest {
         user_id: claims.username,
-        start_id: req.start_id,
-        page_size: req.page_size,
+        terms: query.terms,
+        filter: Some(ts::TaskFilter {
+            statuses: query.statuses,
+            assigned_to: query.assigned_to,
+            ..Default::default()
+        }),
+        offset,
+        page_size,
     });
     let response = state
         .tasks_service
         .write()
         .await
-        .get_task_page(request)
+        .search_tasks(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    let results = match response.into_inner().response.unwrap() {
+        ts::search_tasks_response::Response::Results(x) => x,
+        ts::search_tasks_response::Response::Error(e) => return Err(e.into()),
+    };
+    let tasks = results
+        .hits
+        .iter()
+        .map(|x| x.task.clone().unwrap())
+        .collect();
+    Ok(Json(SearchResults {
+        hits: make_tasks(&state, tasks)
+            .await?
+            .into_iter()
+            .zip(results.hits)
+            .map(|(task, x)| SearchHit {
+                task: task.formatted(req.format),
+                rank: x.rank,
+                title_highlight: x.title_highlight,
+                snippet: x.snippet,
+            })
+            .collect(),
+        has_more: results.has_more,
+    }))
+}
+
+pub async fn transition_task_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+    headers: HeaderMap,
+    Json(req): Json<TransitionTaskRequest>,
+) -> Result<Response, AppError> {
+    info!("transition_task_handler: handling transition task request");
+    let task = fetch_task(&state, &claims.username, &task_id).await?;
+    let from = ts::TaskStatus::try_from(task.status).map_err(|_| AppError::IncorrectRequest)?;
+    let transition = state
+        .workflow
+        .find(&req.transition, from)
+        .ok_or(AppError::TransitionNotAllowed)?;
+    let request = tonic::Request::new(ts::UpdateTaskRequest {
+        user_id: claims.username.clone(),
+        task_id,
+        new_status: Some(transition.to.into()),
+        expected_status: Some(from.into()),
+        expected_version: if_match_version(&headers)?,
+        check_blockers: state.check_blockers,
+        ..Default::default()
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .update_task(request)
         .await
         .map_err(|_| AppError::IncorrectRequest)?;
     match response.into_inner().response.unwrap() {
-        ts::task_page_response::Response::TaskPage(mut x) => Ok(Json(TaskPage {
-            tasks: x
-                .tasks
-                .iter_mut()
-                .map(|t| Task {
-                    id: t.id.clone(),
-                    created_at: t.created_at.clone().unwrap().to_string(),
-                    title: t.title.clone(),
-                    description: t.description.clone(),
-                    status: ts::TaskStatus::try_from(t.status)
-                        .unwrap()
-                        .as_str_name()
-                        .to_string(),
+        ts::task_response::Response::Task(x) => {
+            events::emit(
+                &state,
+                Payload::TaskUpdated(ev::TaskUpdated {
+                    task_id: x.id.clone(),
+                    user_id: claims.username,
+                    title: x.title.clone(),
+                    status: status_name(x.status),
+                }),
+            )
+            .await?;
+            Ok(tagged(make_task(&state, x).await?))
+        }
+        ts::task_response::Response::Error(e) => Err(e.into()),
+    }
+}
+
+/// Executes operations in a single request to tasks_service. Operations with
+/// unknown users are rejected before anything is executed.
+pub async fn batch_tasks_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Json(req): Json<BatchTasksRequest>,
+) -> Result<Json<Vec<BatchItemResult>>, AppError> {
+    info!("batch_tasks_handler: handling batch tasks request");
+    if req.operations.is_empty() || req.operations.len() > MAX_BATCH_SIZE {
+        return Err(AppError::IncorrectRequest);
+    }
+    let atomic = req.atomic.unwrap_or(false);
+    let mut operations = Vec::with_capacity(req.operations.len());
+    for x in req.operations {
+        // Invalid operation fails only itself unless batch is atomic
+        match make_task_operation(&state, &claims.username, x).await {
+            Err(e) if atomic => return Err(e),
+            x => operations.push(x),
+        }
+    }
+    let valid: Vec<_> = operations
+        .iter()
+        .filter_map(|x| x.as_ref().ok().cloned())
+        .collect();
+    let mut batch = ts::BatchResults::default();
+    if !valid.is_empty() {
+        let request = tonic::Request::new(ts::BatchTasksRequest {
+            user_id: claims.username.clone(),
+            operations: valid,
+            atomic,
+        });
+        let response = state
+            .tasks_service
+            .write()
+            .await
+            .batch_tasks(request)
+            .await
+            .map_err(|_| AppError::IncorrectRequest)?;
+        batch = match response.into_inner().response.unwrap() {
+            ts::batch_tasks_response::Response::Results(x) => x,
+            ts::batch_tasks_response::Response::Error(e) => return Err(e.into()),
+        };
+    }
+    let mut results = Vec::with_capacity(operations.len());
+    let mut tasks = Vec::new();
+    let mut executed = batch.results.into_iter();
+    for operation in &operations {
+        let op = match operation {
+            Ok(x) => x,
+            Err(e) => {
+                results.push(BatchItemResult {
+                    status: BatchItemStatus::Failed,
+                    task: None,
+                    error: Some(e.message()),
+                });
+                continue;
+            }
+        };
+        // Operations after failed one aren't executed in atomic mode
+        let (status, error) = match executed.next().map(|x| x.response.unwrap()) {
+            Some(ts::task_response::Response::Task(x)) if batch.committed => {
+                events::emit(&state, task_event(op, &x, &claims.username)).await?;
+                tasks.push((results.len(), x));
+                (BatchItemStatus::Ok, None)
+            }
+            Some(ts::task_response::Response::Task(_)) => (BatchItemStatus::RolledBack, None),
+            Some(ts::task_response::Response::Error(e)) => {
+                (BatchItemStatus::Failed, Some(AppError::from(e).message()))
+            }
+            None => (BatchItemStatus::Skipped, None),
+        };
+        results.push(BatchItemResult {
+            status,
+            task: None,
+            error,
+        });
+    }
+    let (indices, tasks): (Vec<_>, Vec<_>) = tasks.into_iter().unzip();
+    for (i, task) in indices.into_iter().zip(make_tasks(&state, tasks).await?) {
+        results[i].task = Some(task);
+    }
+    Ok(Json(results))
+}
+
+async fn make_task_operation(
+    state: &AppState,
+    username: &str,
+    op: BatchOperation,
+) -> Result<ts::TaskOperation, AppError> {
+    use ts::task_operation::Operation;
+    let operation = match op {
+        BatchOperation::Create(x) => Operation::Create(x.into_proto(state, username).await?),
+        BatchOperation::Update(x) => Operation::Update(x.into_proto(state).await?),
+        BatchOperation::Delete(x) => Operation::Delete(ts::DeleteTaskRequest {
+            task_id: x.task_id,
+            ..Default::default()
+        }),
+        // Status changes are resolved here since tasks_service isn't aware of
+        // the workflow, it picks the one matching current status of the task
+        BatchOperation::Transition(x) => Operation::Transition(ts::TransitionTaskRequest {
+            task_id: x.task_id,
+            changes: state
+                .workflow
+                .named(&x.transition)
+                .flat_map(|t| {
+                    t.from.iter().map(|&from| ts::StatusChange {
+                        from: from.into(),
+                        to: t.to.into(),
+                    })
                 })
                 .collect(),
-        })),
-        ts::task_page_response::Response::Error(_) => Err(AppError::IncorrectRequest),
+            check_blockers: state.check_blockers,
+            ..Default::default()
+        }),
+    };
+    Ok(ts::TaskOperation {
+        operation: Some(operation),
+    })
+}
+
+fn task_event(op: &ts::TaskOperation, task: &ts::Task, username: &str) -> Payload {
+    use ts::task_operation::Operation;
+    match op.operation {
+        Some(Operation::Create(_)) => Payload::TaskCreated(ev::TaskCreated {
+            task_id: task.id.clone(),
+            user_id: username.to_string(),
+            title: task.title.clone(),
+            status: status_name(task.status),
+        }),
+        Some(Operation::Delete(_)) => Payload::TaskDeleted(ev::TaskDeleted {
+            task_id: task.id.clone(),
+            user_id: username.to_string(),
+        }),
+        _ => Payload::TaskUpdated(ev::TaskUpdated {
+            task_id: task.id.clone(),
+            user_id: username.to_string(),
+            title: task.title.clone(),
+            status: status_name(task.status),
+        }),
+    }
+}
+
+pub async fn get_transitions_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+) -> Result<Json<Vec<TransitionConfig>>, AppError> {
+    info!("get_transitions_handler: handling get transitions request");
+    let task = fetch_task(&state, &claims.username, &task_id).await?;
+    let from = ts::TaskStatus::try_from(task.status).map_err(|_| AppError::IncorrectRequest)?;
+    Ok(Json(
+        state
+            .workflow
+            .available(from)
+            .map(TransitionConfig::from)
+            .collect(),
+    ))
+}
+
+pub async fn like_task_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+) -> Result<Json<TaskStats>, AppError> {
+    info!("like_task_handler: handling like task request");
+    fetch_task(&state, &claims.username, &task_id).await?;
+    state.statistics.like(&task_id, &claims.username).await?;
+    events::emit(
+        &state,
+        Payload::TaskLiked(ev::TaskLiked {
+            task_id: task_id.clone(),
+            user_id: claims.username,
+            liked: true,
+        }),
+    )
+    .await?;
+    Ok(Json(state.statistics.get_stats(&task_id).await?))
+}
+
+pub async fn unlike_task_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+) -> Result<Json<TaskStats>, AppError> {
+    info!("unlike_task_handler: handling unlike task request");
+    fetch_task(&state, &claims.username, &task_id).await?;
+    state.statistics.unlike(&task_id, &claims.username).await?;
+    events::emit(
+        &state,
+        Payload::TaskLiked(ev::TaskLiked {
+            task_id: task_id.clone(),
+            user_id: claims.username,
+            liked: false,
+        }),
+    )
+    .await?;
+    Ok(Json(state.statistics.get_stats(&task_id).await?))
+}
+
+pub async fn get_task_stats_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+) -> Result<Json<TaskStats>, AppError> {
+    info!("get_task_stats_handler: handling get task stats request");
+    fetch_task(&state, &claims.username, &task_id).await?;
+    Ok(Json(state.statistics.get_stats(&task_id).await?))
+}
+
+/// Lists most viewed or liked tasks among those visible to the user.
+pub async fn get_top_tasks_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Query(req): Query<GetTopTasksRequest>,
+) -> Result<Json<Vec<TopTask>>, AppError> {
+    info!("get_top_tasks_handler: handling get top tasks request");
+    let limit = req.limit.unwrap_or(10);
+    if !(1..=100).contains(&limit) {
+        return Err(AppError::IncorrectRequest);
+    }
+    let mut tasks = Vec::new();
+    let mut stats = Vec::new();
+    // Tasks which were deleted or aren't accessible by user are skipped, so
+    // visibility of top tasks is checked in growing batches until enough are
+    // found or there are no more tasks
+    let mut checked = 0;
+    let mut candidates = limit * TOP_TASKS_BATCH_FACTOR;
+    loop {
+        let top = state.statistics.top_tasks(req.by, candidates).await?;
+        let Some(batch) = top.get(checked..).filter(|x| !x.is_empty()) else {
+            break;
+        };
+        let request = tonic::Request::new(ts::GetTaskPageRequest {
+            user_id: claims.username.clone(),
+            page_size: batch.len() as i32,
+            relation: ts::TaskRelation::Visible.into(),
+            filter: Some(ts::TaskFilter {
+                ids: batch.iter().map(|(id, _)| id.clone()).collect(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        let response = state
+            .tasks_service
+            .write()
+            .await
+            .get_task_page(request)
+            .await
+            .map_err(|_| AppError::IncorrectRequest)?;
+        let mut visible: HashMap<_, _> = match response.into_inner().response.unwrap() {
+            ts::task_page_response::Response::TaskPage(x) => {
+                x.tasks.into_iter().map(|x| (x.id.clone(), x)).collect()
+            }
+            ts::task_page_response::Response::Error(e) => return Err(e.into()),
+        };
+        for (task_id, x) in batch {
+            if let Some(task) = visible.remove(task_id) {
+                tasks.push(task);
+                stats.push(*x);
+            }
+        }
+        if tasks.len() >= limit {
+            tasks.truncate(limit);
+            stats.truncate(limit);
+            break;
+        }
+        if top.len() < candidates {
+            break;
+        }
+        checked = top.len();
+        candidates *= 2;
+    }
+    Ok(Json(
+        make_tasks(&state, tasks)
+            .await?
+            .into_iter()
+            .zip(stats)
+            .map(|(task, stats)| TopTask {
+                task: task.formatted(req.format),
+                stats,
+            })
+            .collect(),
+    ))
+}
+
+fn make_task_filter(req: &GetTaskPageRequest) -> Result<ts::TaskFilter, AppError> {
+    let statuses = match &req.status {
+        Some(x) => x
+            .split(',')
+            .map(|x| {
+                ts::TaskStatus::from_str_name(x.trim())
+                    .map(i32::from)
+                    .ok_or(AppError::IncorrectRequest)
+            })
+            .collect::<Result<_, _>>()?,
+        None => Vec::new(),
+    };
+    let priorities = match &req.priority {
+        Some(x) => x
+            .split(',')
+            .map(|x| parse_priority(x.trim()))
+            .collect::<Result<_, _>>()?,
+        None => Vec::new(),
+    };
+    let created_from = req.created_from.as_deref().map(parse_date).transpose()?;
+    let created_to = req.created_to.as_deref().map(parse_date).transpose()?;
+    if let (Some(from), Some(to)) = (created_from, created_to) {
+        if from > to {
+            return Err(AppError::IncorrectRequest);
+        }
+    }
+    let due_from = req.due_from.as_deref().map(parse_date).transpose()?;
+    let due_to = req.due_to.as_deref().map(parse_date).transpose()?;
+    if let (Some(from), Some(to)) = (due_from, due_to) {
+        if from > to {
+            return Err(AppError::IncorrectRequest);
+        }
+    }
+    let mut labels: Vec<String> = match &req.label {
+        Some(x) => x.split(',').map(|x| x.trim().to_string()).collect(),
+        None => Vec::new(),
+    };
+    labels.sort();
+    labels.dedup();
+    if let Some(text) = &req.text {
+        if text.is_empty() || text.chars().count() > MAX_FILTER_TEXT_LENGTH {
+            return Err(AppError::IncorrectRequest);
+        }
+    }
+    // Date range is inclusive, so it ends at the start of the day after `created_to`
+    let start_of_day = |x: NaiveDate| prost_types::Timestamp {
+        seconds: x.and_time(NaiveTime::MIN).and_utc().timestamp(),
+        nanos: 0,
+    };
+    Ok(ts::TaskFilter {
+        statuses,
+        created_after: created_from.map(start_of_day),
+        created_before: created_to
+            .map(|x| x.succ_opt().ok_or(AppError::IncorrectDateFormat))
+            .transpose()?
+            .map(start_of_day),
+        text: req.text.clone(),
+        assigned_to: req.assigned_to.clone(),
+        priorities,
+        due_from: due_from.map(|x| x.to_string()),
+        due_to: due_to.map(|x| x.to_string()),
+        overdue: req.overdue.unwrap_or(false),
+        labels,
+        project_id: req.project.clone(),
+        parent_id: req.parent.clone(),
+        ids: Vec::new(),
+    })
+}
+
+pub fn parse_date(x: &str) -> Result<NaiveDate, AppError> {
+    NaiveDate::parse_from_str(x, "%Y-%m-%d").map_err(|_| AppError::IncorrectDateFormat)
+}
+
+fn parse_priority(x: &str) -> Result<i32, AppError> {
+    ts::TaskPriority::from_str_name(x)
+        .map(i32::from)
+        .ok_or(AppError::IncorrectRequest)
+}
+
+/// Checks that both dates are correct and the task doesn't start after it is
+/// due. Empty date is allowed since it removes the date of the task. If only
+/// one date is changed, tasks_service checks it against the other one.
+fn check_dates(start_date: Option<&str>, due_date: Option<&str>) -> Result<(), AppError> {
+    let parse = |x: Option<&str>| x.filter(|x| !x.is_empty()).map(parse_date).transpose();
+    if let (Some(start), Some(due)) = (parse(start_date)?, parse(due_date)?) {
+        if start > due {
+            return Err(AppError::StartAfterDue);
+        }
+    }
+    Ok(())
+}
+
+pub async fn fetch_task(
+    state: &AppStateRef,
+    username: &str,
+    task_id: &str,
+) -> Result<ts::Task, AppError> {
+    let request = tonic::Request::new(ts::GetTaskRequest {
+        user_id: username.to_string(),
+        task_id: task_id.to_string(),
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .get_task(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    match response.into_inner().response.unwrap() {
+        ts::task_response::Response::Task(x) => Ok(x),
+        ts::task_response::Response::Error(e) => Err(e.into()),
     }
 }
 
 #[derive(Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct CreateTaskRequest {
-    title: String,
-    description: String,
+    pub title: String,
+    pub description: String,
+    pub assigned_to: Option<String>,
+    pub reviewed_by: Option<String>,
+    pub start_date: Option<String>,
+    pub due_date: Option<String>,
+    pub priority: Option<String>,
+    pub estimate_minutes: Option<i32>,
+    pub project_id: Option<String>,
+    /// Task to create the task as a subtask of
+    pub parent_id: Option<String>,
+}
+
+impl CreateTaskRequest {
+    /// Validates the request and converts it for tasks_service, user isn't set.
+    pub async fn into_proto(
+        self,
+        state: &AppState,
+        username: &str,
+    ) -> Result<ts::CreateTaskRequest, AppError> {
+        if let Some(project_id) = &self.project_id {
+            check_project_role(state, username, project_id, ts::TaskRole::Editor).await?;
+        }
+        for username in [&self.assigned_to, &self.reviewed_by].into_iter().flatten() {
+            ensure_user_exists(&state.user_database, username).await?;
+        }
+        if self.start_date.as_deref() == Some("") || self.due_date.as_deref() == Some("") {
+            return Err(AppError::IncorrectDateFormat);
+        }
+        check_dates(self.start_date.as_deref(), self.due_date.as_deref())?;
+        check_description(&self.description)?;
+        if self.estimate_minutes.is_some_and(|x| x <= 0) {
+            return Err(AppError::IncorrectRequest);
+        }
+        Ok(ts::CreateTaskRequest {
+            title: self.title,
+            description: self.description,
+            assigned_to: self.assigned_to,
+            reviewed_by: self.reviewed_by,
+            start_date: self.start_date,
+            due_date: self.due_date,
+            priority: match &self.priority {
+                Some(x) => parse_priority(x)?,
+                None => ts::TaskPriority::Normal.into(),
+            },
+            estimate_minutes: self.estimate_minutes,
+            project_id: self.project_id,
+            parent_id: self.parent_id,
+            ..Default::default()
+        })
+    }
 }
 
 #[derive(Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct GetTaskRequest {
     task_id: String,
+    format: Option<DescriptionFormat>,
 }
 
 #[derive(Deserialize)]
@@ -194,6 +918,52 @@ pub struct UpdateTaskRequest {
     task_id: String,
     new_title: Option<String>,
     new_description: Option<String>,
+    new_assigned_to: Option<String>,
+    new_reviewed_by: Option<String>,
+    /// Empty date removes it
+    new_start_date: Option<String>,
+    new_due_date: Option<String>,
+    new_priority: Option<String>,
+    /// Zero removes the estimate
+    new_estimate_minutes: Option<i32>,
+}
+
+impl UpdateTaskRequest {
+    /// Validates the request and converts it for tasks_service, user and
+    /// expected version aren't set.
+    async fn into_proto(self, state: &AppState) -> Result<ts::UpdateTaskRequest, AppError> {
+        // Empty username unassigns the task
+        for username in [&self.new_assigned_to, &self.new_reviewed_by]
+            .into_iter()
+            .flatten()
+            .filter(|x| !x.is_empty())
+        {
+            ensure_user_exists(&state.user_database, username).await?;
+        }
+        check_dates(self.new_start_date.as_deref(), self.new_due_date.as_deref())?;
+        if let Some(x) = &self.new_description {
+            check_description(x)?;
+        }
+        if self.new_estimate_minutes.is_some_and(|x| x < 0) {
+            return Err(AppError::IncorrectRequest);
+        }
+        Ok(ts::UpdateTaskRequest {
+            task_id: self.task_id,
+            new_title: self.new_title,
+            new_description: self.new_description,
+            new_assigned_to: self.new_assigned_to,
+            new_reviewed_by: self.new_reviewed_by,
+            new_start_date: self.new_start_date,
+            new_due_date: self.new_due_date,
+            new_priority: self
+                .new_priority
+                .as_deref()
+                .map(parse_priority)
+                .transpose()?,
+            new_estimate_minutes: self.new_estimate_minutes,
+            ..Default::default()
+        })
+    }
 }
 
 #[derive(Deserialize)]
@@ -202,11 +972,124 @@ pub struct DeleteTaskRequest {
     task_id: String,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Clone)]
 #[serde(deny_unknown_fields)]
 pub struct GetTaskPageRequest {
-    start_id: i32,
-    page_size: i32,
+    cursor: Option<String>,
+    page_size: Option<i32>,
+    include_total: Option<bool>,
+    /// Comma separated statuses
+    status: Option<String>,
+    created_from: Option<String>,
+    created_to: Option<String>,
+    /// Substring of title or description
+    text: Option<String>,
+    assigned_to: Option<String>,
+    /// Comma separated priorities
+    priority: Option<String>,
+    /// Inclusive range of due dates
+    due_from: Option<String>,
+    due_to: Option<String>,
+    overdue: Option<bool>,
+    /// Comma separated names of labels, tasks must have all of them
+    label: Option<String>,
+    project: Option<String>,
+    /// Only subtasks of the task with this id
+    parent: Option<String>,
+    sort: Option<TaskSortField>,
+    order: Option<SortOrder>,
+    format: Option<DescriptionFormat>,
+}
+
+impl GetTaskPageRequest {
+    /// Makes request for a page of the board column with given status. Columns
+    /// are ordered by position and always have the total count, so only
+    /// filters can be chosen.
+    pub fn board_column(&self, status: ts::TaskStatus) -> Result<Self, AppError> {
+        if self.status.is_some()
+            || self.include_total.is_some()
+            || self.sort.is_some()
+            || self.order.is_some()
+        {
+            return Err(AppError::IncorrectRequest);
+        }
+        Ok(GetTaskPageRequest {
+            status: Some(status.as_str_name().to_string()),
+            include_total: Some(true),
+            sort: Some(TaskSortField::Position),
+            ..self.clone()
+        })
+    }
+
+    pub fn is_first_page(&self) -> bool {
+        self.cursor.is_none()
+    }
+}
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskSortField {
+    CreatedAt,
+    Title,
+    Status,
+    DueDate,
+    Priority,
+    /// Order of tasks in board columns
+    Position,
+}
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SearchTasksRequest {
+    q: String,
+    offset: Option<i32>,
+    page_size: Option<i32>,
+    format: Option<DescriptionFormat>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TransitionTaskRequest {
+    transition: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BatchTasksRequest {
+    operations: Vec<BatchOperation>,
+    /// Roll back all operations if any of them fails
+    atomic: Option<bool>,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum BatchOperation {
+    Create(CreateTaskRequest),
+    Update(UpdateTaskRequest),
+    Delete(DeleteTaskRequest),
+    Transition(BatchTransitionRequest),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BatchTransitionRequest {
+    task_id: String,
+    transition: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GetTopTasksRequest {
+    by: StatsMetric,
+    limit: Option<usize>,
+    format: Option<DescriptionFormat>,
 }
 
 #[derive(Serialize)]
@@ -215,10 +1098,283 @@ pub struct Task {
     created_at: String,
     title: String,
     description: String,
+    /// Description rendered from Markdown, only with `format=html`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description_html: Option<String>,
     status: String,
+    assigned_to: Option<UserProfile>,
+    reviewed_by: Option<UserProfile>,
+    version: i64,
+    start_date: Option<String>,
+    due_date: Option<String>,
+    priority: String,
+    estimate_minutes: Option<i32>,
+    labels: Vec<Label>,
+    project_id: Option<String>,
+    position: i64,
+    parent_id: Option<String>,
+    subtasks: SubtaskProgress,
+    /// Number of blockers which are neither completed nor cancelled
+    open_blockers: i32,
+    checklist: ChecklistProgress,
+    attachments: Vec<AttachmentInfo>,
+    /// Minutes logged by finished time entries
+    logged_minutes: i32,
+}
+
+/// Attachment as listed in the task, `GET /tasks/{id}/attachments` has the
+/// details.
+#[derive(Serialize)]
+pub struct AttachmentInfo {
+    id: String,
+    filename: String,
+    content_type: String,
+    size: i64,
+}
+
+#[derive(Serialize)]
+pub struct ChecklistProgress {
+    total: i32,
+    done: i32,
+}
+
+#[derive(Serialize)]
+pub struct SubtaskProgress {
+    total: i32,
+    completed: i32,
+}
+
+impl Task {
+    fn new(x: ts::Task, profiles: &Profiles) -> Result<Self, AppError> {
+        Ok(Task {
+            id: x.id,
+            created_at: x.created_at.unwrap().to_string(),
+            title: x.title,
+            description: x.description,
+            description_html: None,
+            status: status_name(x.status),
+            assigned_to: x.assigned_to.map(|u| profiles.get(&u)),
+            reviewed_by: x.reviewed_by.map(|u| profiles.get(&u)),
+            version: x.version,
+            start_date: x.start_date,
+            due_date: x.due_date,
+            priority: priority_name(x.priority)?,
+            estimate_minutes: x.estimate_minutes,
+            labels: x.labels.into_iter().map(Label::from).collect(),
+            project_id: x.project_id,
+            position: x.position,
+            parent_id: x.parent_id,
+            subtasks: x
+                .subtasks
+                .map(|x| SubtaskProgress {
+                    total: x.total,
+                    completed: x.completed,
+                })
+                .unwrap_or(SubtaskProgress {
+                    total: 0,
+                    completed: 0,
+                }),
+            open_blockers: x.open_blockers,
+            checklist: x
+                .checklist
+                .map(|x| ChecklistProgress {
+                    total: x.total,
+                    done: x.done,
+                })
+                .unwrap_or(ChecklistProgress { total: 0, done: 0 }),
+            attachments: x
+                .attachments
+                .into_iter()
+                .map(|x| AttachmentInfo {
+                    id: x.id,
+                    filename: x.filename,
+                    content_type: x.content_type,
+                    size: x.size,
+                })
+                .collect(),
+            logged_minutes: x.logged_minutes,
+        })
+    }
+
+    /// Adds description rendered to sanitized HTML if it is requested.
+    pub fn formatted(mut self, format: Option<DescriptionFormat>) -> Self {
+        if format == Some(DescriptionFormat::Html) {
+            self.description_html = Some(markdown::to_html(&self.description));
+        }
+        self
+    }
+}
+
+pub fn status_name(status: i32) -> String {
+    ts::TaskStatus::try_from(status)
+        .unwrap()
+        .as_str_name()
+        .to_string()
+}
+
+pub fn priority_name(priority: i32) -> Result<String, AppError> {
+    ts::TaskPriority::try_from(priority)
+        .map(|x| x.as_str_name().to_string())
+        .map_err(|_| AppError::IncorrectRequest)
+}
+
+/// Converts tasks from tasks_service resolving referenced users.
+pub async fn make_tasks(state: &AppState, tasks: Vec<ts::Task>) -> Result<Vec<Task>, AppError> {
+    let usernames: Vec<&str> = tasks
+        .iter()
+        .flat_map(|x| [x.assigned_to.as_deref(), x.reviewed_by.as_deref()])
+        .flatten()
+        .collect();
+    let profiles = Profiles::fetch(&state.user_database, &usernames).await?;
+    tasks.into_iter().map(|x| Task::new(x, &profiles)).collect()
+}
+
+pub async fn make_task(state: &AppState, task: ts::Task) -> Result<Task, AppError> {
+    Ok(make_tasks(state, vec![task]).await?.pop().unwrap())
+}
+
+/// Makes response with the task and its version in `ETag` header.
+pub fn tagged(task: Task) -> Response {
+    ([(header::ETAG, etag(task.version))], Json(task)).into_response()
 }
 
 #[derive(Serialize)]
 pub struct TaskPage {
     tasks: Vec<Task>,
+    next_cursor: Option<String>,
+    prev_cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total: Option<i64>,
+}
+
+#[derive(Serialize)]
+pub struct TopTask {
+    task: Task,
+    stats: TaskStats,
+}
+
+#[derive(Serialize)]
+pub struct SearchHit {
+    task: Task,
+    rank: f32,
+    /// Title with matches wrapped into `<mark>` tags
+    title_highlight: String,
+    /// Fragments of description and comments with matches
+    snippet: String,
+}
+
+#[derive(Serialize)]
+pub struct SearchResults {
+    hits: Vec<SearchHit>,
+    has_more: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchItemStatus {
+    Ok,
+    Failed,
+    /// Operation succeeded but atomic batch was rolled back
+    RolledBack,
+    Skipped,
+}
+
+#[derive(Serialize)]
+pub struct BatchItemResult {
+    status: BatchItemStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    task: Option<Task>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'static str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page_request(query: serde_json::Value) -> Result<GetTaskPageRequest, serde_json::Error> {
+        serde_json::from_value(query)
+    }
+
+    fn filter(query: serde_json::Value) -> Result<ts::TaskFilter, AppError> {
+        make_task_filter(&page_request(query).unwrap())
+    }
+
+    #[test]
+    fn filter_has_statuses_text_and_assignee() {
+        let x = filter(serde_json::json!({
+            "status": "Open, InProgress",
+            "text": "login",
+            "assigned_to": "jane",
+        }))
+        .unwrap();
+        assert_eq!(
+            x.statuses,
+            [
+                i32::from(ts::TaskStatus::Open),
+                i32::from(ts::TaskStatus::InProgress)
+            ]
+        );
+        assert_eq!(x.text.as_deref(), Some("login"));
+        assert_eq!(x.assigned_to.as_deref(), Some("jane"));
+        assert!(filter(serde_json::json!({})).unwrap().statuses.is_empty());
+    }
+
+    #[test]
+    fn created_range_includes_its_last_day() {
+        let x = filter(serde_json::json!({
+            "created_from": "2024-03-01",
+            "created_to": "2024-03-01",
+        }))
+        .unwrap();
+        let day = |x: Option<prost_types::Timestamp>| x.unwrap().seconds;
+        assert_eq!(day(x.created_after), 1709251200);
+        assert_eq!(day(x.created_before), 1709251200 + 24 * 60 * 60);
+    }
+
+    #[test]
+    fn invalid_filters_are_rejected() {
+        for query in [
+            serde_json::json!({"status": "Open,Done"}),
+            serde_json::json!({"created_from": "2024-03-02", "created_to": "2024-03-01"}),
+            serde_json::json!({"text": ""}),
+            serde_json::json!({"text": "x".repeat(MAX_FILTER_TEXT_LENGTH + 1)}),
+        ] {
+            assert!(
+                matches!(filter(query.clone()), Err(AppError::IncorrectRequest)),
+                "{}",
+                query
+            );
+        }
+        assert!(matches!(
+            filter(serde_json::json!({"created_to": "01.03.2024"})),
+            Err(AppError::IncorrectDateFormat)
+        ));
+    }
+
+    #[test]
+    fn only_known_sorting_is_accepted() {
+        let x = page_request(serde_json::json!({"sort": "created_at", "order": "desc"})).unwrap();
+        assert!(matches!(x.sort, Some(TaskSortField::CreatedAt)));
+        assert!(matches!(x.order, Some(SortOrder::Desc)));
+        assert!(page_request(serde_json::json!({"sort": "rank"})).is_err());
+        assert!(page_request(serde_json::json!({"order": "up"})).is_err());
+        assert!(page_request(serde_json::json!({"limit": 10})).is_err());
+    }
+
+    #[test]
+    fn task_can_not_start_after_it_is_due() {
+        assert!(check_dates(Some("2024-03-01"), Some("2024-03-01")).is_ok());
+        assert!(matches!(
+            check_dates(Some("2024-03-02"), Some("2024-03-01")),
+            Err(AppError::StartAfterDue)
+        ));
+        // Single date is checked by tasks_service against the stored one
+        assert!(check_dates(Some("2024-03-02"), None).is_ok());
+        assert!(check_dates(Some("2024-03-02"), Some("")).is_ok());
+        assert!(matches!(
+            check_dates(Some("2024-02-30"), None),
+            Err(AppError::IncorrectDateFormat)
+        ));
+    }
 }
@@ -0,0 +1,600 @@
+use crate::checklists::check_text;
+use crate::common::{AppClaims, AppError, AppState, AppStateRef};
+use crate::labels::{fetch_labels, Label};
+use crate::markdown::check_description;
+use crate::proto::tasks_service as ts;
+use crate::tasks::{create_task, CreateTaskRequest};
+use crate::users::{Profiles, UserProfile};
+use axum::{
+    extract::{Path, State},
+    response::{Response, Result},
+    Json,
+};
+use chrono::{DateTime, SecondsFormat, Utc};
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use tokio_postgres::{error::SqlState, Row};
+
+const MAX_NAME_LENGTH: usize = 100;
+const MAX_TITLE_LENGTH: usize = 500;
+const MAX_CHECKLIST_ITEMS: usize = 100;
+const MAX_LABELS: usize = 50;
+const TEMPLATE_COLUMNS: &str =
+    "id, owner, name, title, description, status, label_ids, checklist, shared, created_at, updated_at";
+
+/// Lists templates which user owns and templates shared by other users.
+pub async fn list_templates_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+) -> Result<Json<Vec<Template>>, AppError> {
+    info!("list_templates_handler: handling list templates request");
+    let rows = state
+        .user_database
+        .query(
+            &format!(
+                "SELECT {} FROM task_templates WHERE owner=$1 OR shared ORDER BY name, id",
+                TEMPLATE_COLUMNS
+            ),
+            &[&claims.username],
+        )
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    Ok(Json(make_templates(&state, &rows).await?))
+}
+
+pub async fn create_template_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Json(req): Json<CreateTemplateRequest>,
+) -> Result<Json<Template>, AppError> {
+    info!("create_template_handler: handling create template request");
+    let description = req.description.unwrap_or_default();
+    let status = req
+        .status
+        .unwrap_or_else(|| ts::TaskStatus::Open.as_str_name().to_string());
+    let label_ids = dedup(req.label_ids.unwrap_or_default());
+    let checklist = req.checklist.unwrap_or_default();
+    check_name(&req.name)?;
+    check_content(&req.title, &description, &checklist)?;
+    check_status(&state, &status)?;
+    check_labels(&state, &claims.username, &label_ids).await?;
+    let row = state
+        .user_database
+        .query_opt(
+            &format!(
+                "INSERT INTO task_templates (owner, name, title, description, status, label_ids, checklist, shared, created_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, now()) ON CONFLICT DO NOTHING RETURNING {}",
+                TEMPLATE_COLUMNS
+            ),
+            &[
+                &claims.username,
+                &req.name,
+                &req.title,
+                &description,
+                &status,
+                &label_ids,
+                &checklist,
+                &req.shared.unwrap_or(false),
+            ],
+        )
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?
+        .ok_or(AppError::TemplateAlreadyExists)?;
+    make_template(&state, &row).await
+}
+
+pub async fn get_template_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(template_id): Path<i32>,
+) -> Result<Json<Template>, AppError> {
+    info!("get_template_handler: handling get template request");
+    let row = fetch_template(&state, &claims.username, template_id).await?;
+    make_template(&state, &row).await
+}
+
+/// Changes the template, only its owner can do it. Tasks created from the
+/// template are left as is.
+pub async fn update_template_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(template_id): Path<i32>,
+    Json(req): Json<UpdateTemplateRequest>,
+) -> Result<Json<Template>, AppError> {
+    info!("update_template_handler: handling update template request");
+    if let Some(x) = &req.new_name {
+        check_name(x)?;
+    }
+    if let Some(x) = &req.new_status {
+        check_status(&state, x)?;
+    }
+    let new_label_ids = req.new_label_ids.map(dedup);
+    if let Some(x) = &new_label_ids {
+        check_labels(&state, &claims.username, x).await?;
+    }
+    let row = fetch_template(&state, &claims.username, template_id).await?;
+    if row.get::<_, String>("owner") != claims.username {
+        return Err(AppError::TemplateNotFound);
+    }
+    check_content(
+        req.new_title.as_ref().unwrap_or(&row.get("title")),
+        req.new_description
+            .as_ref()
+            .unwrap_or(&row.get("description")),
+        req.new_checklist.as_ref().unwrap_or(&row.get("checklist")),
+    )?;
+    let row = state
+        .user_database
+        .query_opt(
+            &format!(
+                "UPDATE task_templates SET name=COALESCE($3, name), title=COALESCE($4, title), description=COALESCE($5, description), status=COALESCE($6, status), label_ids=COALESCE($7, label_ids), checklist=COALESCE($8, checklist), shared=COALESCE($9, shared), updated_at=now() WHERE id=$1 AND owner=$2 RETURNING {}",
+                TEMPLATE_COLUMNS
+            ),
+            &[
+                &template_id,
+                &claims.username,
+                &req.new_name,
+                &req.new_title,
+                &req.new_description,
+                &req.new_status,
+                &new_label_ids,
+                &req.new_checklist,
+                &req.new_shared,
+            ],
+        )
+        .await
+        .map_err(|e| match e.code() {
+            Some(&SqlState::UNIQUE_VIOLATION) => AppError::TemplateAlreadyExists,
+            _ => AppError::IncorrectRequest,
+        })?
+        .ok_or(AppError::TemplateNotFound)?;
+    make_template(&state, &row).await
+}
+
+/// Deletes the template, only its owner can do it.
+pub async fn delete_template_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(template_id): Path<i32>,
+) -> Result<Json<Template>, AppError> {
+    info!("delete_template_handler: handling delete template request");
+    let row = state
+        .user_database
+        .query_opt(
+            &format!(
+                "DELETE FROM task_templates WHERE id=$1 AND owner=$2 RETURNING {}",
+                TEMPLATE_COLUMNS
+            ),
+            &[&template_id, &claims.username],
+        )
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?
+        .ok_or(AppError::TemplateNotFound)?;
+    make_template(&state, &row).await
+}
+
+/// Creates task from the template with placeholders filled from variables of
+/// the request. Built-in `today` and `user` variables can be overridden.
+/// Labels of the template are resolved by name to labels of the user, other
+/// labels and labels deleted since the template was saved are skipped.
+pub async fn create_task_from_template_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Json(req): Json<CreateTaskFromTemplateRequest>,
+) -> Result<Response, AppError> {
+    info!("create_task_from_template_handler: handling create task from template request");
+    let row = fetch_template(&state, &claims.username, req.template_id).await?;
+    let mut variables = HashMap::from([
+        ("today".to_string(), Utc::now().date_naive().to_string()),
+        ("user".to_string(), claims.username.clone()),
+    ]);
+    variables.extend(req.variables.unwrap_or_default());
+    let fill = |x: &str| -> Result<String, AppError> {
+        parse(x)?
+            .into_iter()
+            .map(|part| match part {
+                Part::Text(x) => Ok(x),
+                Part::Variable(x) => variables
+                    .get(x)
+                    .map(String::as_str)
+                    .ok_or(AppError::TemplateVariableMissing),
+            })
+            .collect()
+    };
+    let title = fill(row.get("title"))?;
+    let description = fill(row.get("description"))?;
+    let checklist = row
+        .get::<_, Vec<String>>("checklist")
+        .iter()
+        .map(|x| fill(x))
+        .collect::<Result<Vec<_>, _>>()?;
+    if title.trim().is_empty() || title.chars().count() > MAX_TITLE_LENGTH {
+        return Err(AppError::IncorrectRequest);
+    }
+    for x in &checklist {
+        check_text(x)?;
+    }
+    let status = row.get::<_, String>("status");
+    check_status(&state, &status)?;
+    let owner: String = row.get("owner");
+    let labels = fetch_labels(&state, &claims.username).await?;
+    let owner_labels = if owner == claims.username {
+        labels.clone()
+    } else {
+        fetch_labels(&state, &owner).await?
+    };
+    let label_ids = resolve_labels(
+        &row.get::<_, Vec<String>>("label_ids"),
+        &owner_labels,
+        &labels,
+    );
+    let request = CreateTaskRequest {
+        title,
+        description,
+        assigned_to: req.assigned_to,
+        reviewed_by: req.reviewed_by,
+        start_date: req.start_date,
+        due_date: req.due_date,
+        priority: req.priority,
+        estimate_minutes: req.estimate_minutes,
+        project_id: req.project_id,
+        parent_id: req.parent_id,
+    };
+    let request = ts::CreateTaskRequest {
+        status: ts::TaskStatus::from_str_name(&status).map(i32::from),
+        label_ids,
+        checklist,
+        ..request.into_proto(&state, &claims.username).await?
+    };
+    create_task(&state, claims.username, request).await
+}
+
+/// Maps labels of template owner to the labels of the user with the same
+/// names, since labels are scoped to their creators. Labels without match are
+/// skipped.
+fn resolve_labels(
+    label_ids: &[String],
+    owner_labels: &[ts::Label],
+    labels: &[ts::Label],
+) -> Vec<String> {
+    let names: HashMap<&str, &str> = owner_labels
+        .iter()
+        .map(|x| (x.id.as_str(), x.name.as_str()))
+        .collect();
+    let ids: HashMap<&str, &str> = labels
+        .iter()
+        .map(|x| (x.name.as_str(), x.id.as_str()))
+        .collect();
+    label_ids
+        .iter()
+        .filter_map(|x| ids.get(names.get(x.as_str())?).map(|x| x.to_string()))
+        .collect()
+}
+
+/// Fetches template which user owns or which is shared.
+async fn fetch_template(
+    state: &AppState,
+    username: &str,
+    template_id: i32,
+) -> Result<Row, AppError> {
+    state
+        .user_database
+        .query_opt(
+            &format!(
+                "SELECT {} FROM task_templates WHERE id=$1 AND (owner=$2 OR shared)",
+                TEMPLATE_COLUMNS
+            ),
+            &[&template_id, &username],
+        )
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?
+        .ok_or(AppError::TemplateNotFound)
+}
+
+async fn make_template(state: &AppState, row: &Row) -> Result<Json<Template>, AppError> {
+    let mut templates = make_templates(state, std::slice::from_ref(row)).await?;
+    Ok(Json(templates.remove(0)))
+}
+
+async fn make_templates(state: &AppState, rows: &[Row]) -> Result<Vec<Template>, AppError> {
+    let usernames: Vec<&str> = rows.iter().map(|x| x.get("owner")).collect();
+    let profiles = Profiles::fetch(&state.user_database, &usernames).await?;
+    // Templates have labels of their owners
+    let owners: BTreeSet<&str> = rows
+        .iter()
+        .filter(|x| !x.get::<_, Vec<String>>("label_ids").is_empty())
+        .map(|x| x.get("owner"))
+        .collect();
+    let mut labels: HashMap<String, ts::Label> = HashMap::new();
+    for owner in owners {
+        labels.extend(
+            fetch_labels(state, owner)
+                .await?
+                .into_iter()
+                .map(|x| (x.id.clone(), x)),
+        );
+    }
+    let timestamp = |x: DateTime<Utc>| x.to_rfc3339_opts(SecondsFormat::AutoSi, true);
+    rows.iter()
+        .map(|row| {
+            let title: String = row.get("title");
+            let description: String = row.get("description");
+            let checklist: Vec<String> = row.get("checklist");
+            let mut variables = BTreeSet::new();
+            for text in [&title, &description].into_iter().chain(&checklist) {
+                for part in parse(text)? {
+                    if let Part::Variable(x) = part {
+                        variables.insert(x.to_string());
+                    }
+                }
+            }
+            Ok(Template {
+                id: row.get("id"),
+                name: row.get("name"),
+                title,
+                description,
+                status: row.get("status"),
+                // Deleted labels are skipped
+                labels: row
+                    .get::<_, Vec<String>>("label_ids")
+                    .iter()
+                    .filter_map(|x| labels.get(x).cloned().map(Label::from))
+                    .collect(),
+                checklist,
+                variables: variables.into_iter().collect(),
+                shared: row.get("shared"),
+                created_by: profiles.get(row.get("owner")),
+                created_at: timestamp(row.get("created_at")),
+                updated_at: row
+                    .get::<_, Option<DateTime<Utc>>>("updated_at")
+                    .map(timestamp),
+            })
+        })
+        .collect()
+}
+
+#[derive(PartialEq, Debug)]
+enum Part<'a> {
+    Text(&'a str),
+    Variable(&'a str),
+}
+
+/// Splits text into literal parts and `{{ name }}` placeholders. Names consist
+/// of letters, digits and underscores.
+fn parse(text: &str) -> Result<Vec<Part<'_>>, AppError> {
+    let mut parts = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let (end, name) = rest[start + 2..]
+            .find("}}")
+            .map(|end| (start + 2 + end, rest[start + 2..start + 2 + end].trim()))
+            .ok_or(AppError::InvalidTemplatePlaceholder)?;
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return Err(AppError::InvalidTemplatePlaceholder);
+        }
+        parts.push(Part::Text(&rest[..start]));
+        parts.push(Part::Variable(name));
+        rest = &rest[end + 2..];
+    }
+    parts.push(Part::Text(rest));
+    Ok(parts)
+}
+
+fn check_name(name: &str) -> Result<(), AppError> {
+    let length = name.chars().count();
+    if name.trim() != name || !(1..=MAX_NAME_LENGTH).contains(&length) {
+        return Err(AppError::IncorrectRequest);
+    }
+    Ok(())
+}
+
+fn check_content(title: &str, description: &str, checklist: &[String]) -> Result<(), AppError> {
+    if title.trim().is_empty()
+        || title.chars().count() > MAX_TITLE_LENGTH
+        || checklist.len() > MAX_CHECKLIST_ITEMS
+    {
+        return Err(AppError::IncorrectRequest);
+    }
+    check_description(description)?;
+    for x in checklist {
+        check_text(x)?;
+    }
+    for x in [title, description]
+        .into_iter()
+        .chain(checklist.iter().map(String::as_str))
+    {
+        parse(x)?;
+    }
+    Ok(())
+}
+
+/// Tasks are created in Open status or in one reachable from it by a single
+/// workflow transition.
+fn check_status(state: &AppState, status: &str) -> Result<(), AppError> {
+    let status = ts::TaskStatus::from_str_name(status).ok_or(AppError::IncorrectRequest)?;
+    if status != ts::TaskStatus::Open
+        && !state
+            .workflow
+            .available(ts::TaskStatus::Open)
+            .any(|x| x.to == status)
+    {
+        return Err(AppError::TransitionNotAllowed);
+    }
+    Ok(())
+}
+
+/// Checks that labels belong to the user.
+async fn check_labels(
+    state: &AppState,
+    username: &str,
+    label_ids: &[String],
+) -> Result<(), AppError> {
+    if label_ids.is_empty() {
+        return Ok(());
+    }
+    if label_ids.len() > MAX_LABELS {
+        return Err(AppError::IncorrectRequest);
+    }
+    let existing: BTreeSet<String> = fetch_labels(state, username)
+        .await?
+        .into_iter()
+        .map(|x| x.id)
+        .collect();
+    if !label_ids.iter().all(|x| existing.contains(x)) {
+        return Err(AppError::LabelNotFound);
+    }
+    Ok(())
+}
+
+fn dedup(mut ids: Vec<String>) -> Vec<String> {
+    let mut seen = BTreeSet::new();
+    ids.retain(|x| seen.insert(x.clone()));
+    ids
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CreateTemplateRequest {
+    name: String,
+    title: String,
+    description: Option<String>,
+    /// Status of created tasks, Open by default
+    status: Option<String>,
+    label_ids: Option<Vec<String>>,
+    checklist: Option<Vec<String>>,
+    /// Whether other users can see the template and create tasks from it
+    shared: Option<bool>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateTemplateRequest {
+    new_name: Option<String>,
+    new_title: Option<String>,
+    new_description: Option<String>,
+    new_status: Option<String>,
+    new_label_ids: Option<Vec<String>>,
+    new_checklist: Option<Vec<String>>,
+    new_shared: Option<bool>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CreateTaskFromTemplateRequest {
+    template_id: i32,
+    variables: Option<HashMap<String, String>>,
+    assigned_to: Option<String>,
+    reviewed_by: Option<String>,
+    start_date: Option<String>,
+    due_date: Option<String>,
+    priority: Option<String>,
+    estimate_minutes: Option<i32>,
+    project_id: Option<String>,
+    parent_id: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct Template {
+    id: i32,
+    name: String,
+    title: String,
+    description: String,
+    status: String,
+    labels: Vec<Label>,
+    checklist: Vec<String>,
+    /// Names of placeholders used in the template
+    variables: Vec<String>,
+    shared: bool,
+    created_by: UserProfile,
+    created_at: String,
+    updated_at: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Part::*;
+
+    fn label(id: &str, name: &str) -> ts::Label {
+        ts::Label {
+            id: id.to_string(),
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn labels_are_resolved_by_name() {
+        let owner_labels = [label("a1", "bug"), label("a2", "ui"), label("a3", "urgent")];
+        let labels = [label("b1", "urgent"), label("b2", "bug")];
+        let ids = ["a1", "a2", "a3", "a4"].map(String::from);
+        assert_eq!(resolve_labels(&ids, &owner_labels, &labels), ["b2", "b1"]);
+        // Own template keeps its labels
+        assert_eq!(
+            resolve_labels(&ids, &owner_labels, &owner_labels),
+            ["a1", "a2", "a3"]
+        );
+    }
+
+    #[test]
+    fn placeholders_are_parsed() {
+        assert_eq!(parse("").unwrap(), [Text("")]);
+        assert_eq!(
+            parse("Report for {{ user }}, {{today}}").unwrap(),
+            [
+                Text("Report for "),
+                Variable("user"),
+                Text(", "),
+                Variable("today"),
+                Text("")
+            ]
+        );
+        assert_eq!(
+            parse("{{a}}{{b_2}} } {").unwrap(),
+            [
+                Text(""),
+                Variable("a"),
+                Text(""),
+                Variable("b_2"),
+                Text(" } {")
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_placeholders_are_rejected() {
+        for text in ["{{ user", "{{}}", "{{ }}", "{{ user name }}", "{{ a-b }}"] {
+            assert!(
+                matches!(parse(text), Err(AppError::InvalidTemplatePlaceholder)),
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn content_is_checked() {
+        assert!(check_content("Weekly {{ user }}", "", &["Step {{ n }}".to_string()]).is_ok());
+        assert!(check_content(" ", "", &[]).is_err());
+        assert!(check_content(&"x".repeat(MAX_TITLE_LENGTH + 1), "", &[]).is_err());
+        assert!(check_content("Title", "{{ broken", &[]).is_err());
+        assert!(check_content("Title", "", &["{{}}".to_string()]).is_err());
+        assert!(
+            check_content("Title", "", &vec!["x".to_string(); MAX_CHECKLIST_ITEMS + 1]).is_err()
+        );
+    }
+
+    #[test]
+    fn names_are_checked() {
+        assert!(check_name("Weekly report").is_ok());
+        assert!(check_name("").is_err());
+        assert!(check_name(" Weekly report").is_err());
+        assert!(check_name(&"x".repeat(MAX_NAME_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn duplicate_ids_are_removed_in_order() {
+        let ids = ["b", "a", "b", "c", "a"].map(String::from).to_vec();
+        assert_eq!(dedup(ids), ["b", "a", "c"]);
+    }
+}
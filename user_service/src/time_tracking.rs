@@ -0,0 +1,537 @@
+use crate::common::{AppClaims, AppError, AppState, AppStateRef, ERR_CODE_TIMER_NOT_RUNNING};
+use crate::proto::tasks_service as ts;
+use crate::tasks::parse_date;
+use crate::users::{Profiles, UserProfile};
+use axum::{
+    extract::{Path, Query, State},
+    http::header,
+    response::{IntoResponse, Response, Result},
+    Json,
+};
+use log::info;
+use serde::{Deserialize, Serialize};
+
+const MAX_NOTE_LENGTH: usize = 500;
+/// Entry can't be longer than a day.
+const MAX_MINUTES: i32 = 24 * 60;
+
+/// Starts timer of the user on the task, running timer of the user is stopped
+/// first.
+pub async fn start_timer_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+) -> Result<Json<TimeEntry>, AppError> {
+    info!("start_timer_handler: handling start timer request");
+    let request = tonic::Request::new(ts::GetTaskRequest {
+        user_id: claims.username,
+        task_id,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .start_timer(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_time_entry(&state, response.into_inner()).await
+}
+
+/// Stops timer of the user on the task and logs elapsed time rounded to
+/// minutes.
+pub async fn stop_timer_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+) -> Result<Json<TimeEntry>, AppError> {
+    info!("stop_timer_handler: handling stop timer request");
+    let request = tonic::Request::new(ts::GetTaskRequest {
+        user_id: claims.username,
+        task_id,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .stop_timer(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_time_entry(&state, response.into_inner()).await
+}
+
+/// Gets running timer of the user, `null` if there is none.
+pub async fn get_timer_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+) -> Result<Json<Option<TimeEntry>>, AppError> {
+    info!("get_timer_handler: handling get timer request");
+    let request = tonic::Request::new(ts::GetRunningTimerRequest {
+        user_id: claims.username,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .get_running_timer(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    match response.into_inner().response.unwrap() {
+        ts::time_entry_response::Response::Entry(x) => {
+            Ok(Json(make_time_entries(&state, vec![x]).await?.pop()))
+        }
+        ts::time_entry_response::Response::Error(e) if e.code == ERR_CODE_TIMER_NOT_RUNNING => {
+            Ok(Json(None))
+        }
+        ts::time_entry_response::Response::Error(e) => Err(e.into()),
+    }
+}
+
+/// Lists time entries of all users on the task, oldest first.
+pub async fn list_time_entries_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+) -> Result<Json<Vec<TimeEntry>>, AppError> {
+    info!("list_time_entries_handler: handling list time entries request");
+    let request = tonic::Request::new(ts::GetTaskRequest {
+        user_id: claims.username,
+        task_id,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .list_time_entries(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    match response.into_inner().response.unwrap() {
+        ts::time_entry_list_response::Response::Entries(x) => {
+            Ok(Json(make_time_entries(&state, x.entries).await?))
+        }
+        ts::time_entry_list_response::Response::Error(e) => Err(e.into()),
+    }
+}
+
+/// Logs time spent on the task by hand.
+pub async fn add_time_entry_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+    Json(req): Json<AddTimeEntryRequest>,
+) -> Result<Json<TimeEntry>, AppError> {
+    info!("add_time_entry_handler: handling add time entry request");
+    parse_date(&req.spent_on)?;
+    check_minutes(req.minutes)?;
+    let note = req.note.unwrap_or_default();
+    check_note(&note)?;
+    let request = tonic::Request::new(ts::AddTimeEntryRequest {
+        user_id: claims.username,
+        task_id,
+        spent_on: req.spent_on,
+        minutes: req.minutes,
+        note,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .add_time_entry(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_time_entry(&state, response.into_inner()).await
+}
+
+/// Changes own finished time entry.
+pub async fn update_time_entry_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path((task_id, entry_id)): Path<(String, String)>,
+    Json(req): Json<UpdateTimeEntryRequest>,
+) -> Result<Json<TimeEntry>, AppError> {
+    info!("update_time_entry_handler: handling update time entry request");
+    if let Some(x) = &req.new_spent_on {
+        parse_date(x)?;
+    }
+    if let Some(x) = req.new_minutes {
+        check_minutes(x)?;
+    }
+    if let Some(x) = &req.new_note {
+        check_note(x)?;
+    }
+    let request = tonic::Request::new(ts::UpdateTimeEntryRequest {
+        user_id: claims.username,
+        task_id,
+        entry_id,
+        new_spent_on: req.new_spent_on,
+        new_minutes: req.new_minutes,
+        new_note: req.new_note,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .update_time_entry(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_time_entry(&state, response.into_inner()).await
+}
+
+/// Deletes own time entry, deleting entry of running timer discards the timer.
+pub async fn delete_time_entry_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path((task_id, entry_id)): Path<(String, String)>,
+) -> Result<Json<TimeEntry>, AppError> {
+    info!("delete_time_entry_handler: handling delete time entry request");
+    let request = tonic::Request::new(ts::TimeEntryRequest {
+        user_id: claims.username,
+        task_id,
+        entry_id,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .delete_time_entry(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    make_time_entry(&state, response.into_inner()).await
+}
+
+/// Sums time logged on visible tasks per user, task or day, as JSON or CSV.
+pub async fn time_report_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Query(req): Query<TimeReportQuery>,
+) -> Result<Response, AppError> {
+    info!("time_report_handler: handling time report request");
+    for x in [&req.from, &req.to].into_iter().flatten() {
+        parse_date(x)?;
+    }
+    let mut group_by = Vec::new();
+    for x in req.group_by.as_deref().unwrap_or_default().split(',') {
+        let group = match x.trim() {
+            "" => continue,
+            "user" => ts::TimeReportGroup::ByUser,
+            "task" => ts::TimeReportGroup::ByTask,
+            "day" => ts::TimeReportGroup::ByDay,
+            _ => return Err(AppError::IncorrectRequest),
+        };
+        if !group_by.contains(&group) {
+            group_by.push(group);
+        }
+    }
+    let request = tonic::Request::new(ts::GetTimeReportRequest {
+        user_id: claims.username,
+        from: req.from,
+        to: req.to,
+        member_id: req.user,
+        task_id: req.task,
+        project_id: req.project,
+        group_by: group_by.iter().map(|x| i32::from(*x)).collect(),
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .get_time_report(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    let report = match response.into_inner().response.unwrap() {
+        ts::time_report_response::Response::Report(x) => x,
+        ts::time_report_response::Response::Error(e) => return Err(e.into()),
+    };
+    match req.format.unwrap_or(ReportFormat::Json) {
+        ReportFormat::Json => Ok(Json(make_time_report(&state, report).await?).into_response()),
+        ReportFormat::Csv => Ok(make_csv(&group_by, report)),
+    }
+}
+
+async fn make_time_entry(
+    state: &AppState,
+    response: ts::TimeEntryResponse,
+) -> Result<Json<TimeEntry>, AppError> {
+    match response.response.unwrap() {
+        ts::time_entry_response::Response::Entry(x) => Ok(Json(
+            make_time_entries(state, vec![x]).await?.pop().unwrap(),
+        )),
+        ts::time_entry_response::Response::Error(e) => Err(e.into()),
+    }
+}
+
+async fn make_time_entries(
+    state: &AppState,
+    entries: Vec<ts::TimeEntry>,
+) -> Result<Vec<TimeEntry>, AppError> {
+    let usernames: Vec<&str> = entries.iter().map(|x| x.user_id.as_str()).collect();
+    let profiles = Profiles::fetch(&state.user_database, &usernames).await?;
+    Ok(entries
+        .into_iter()
+        .map(|x| TimeEntry {
+            user: profiles.get(&x.user_id),
+            id: x.id,
+            task_id: x.task_id,
+            spent_on: x.spent_on,
+            minutes: x.minutes,
+            started_at: x.started_at.map(|x| x.to_string()),
+            note: x.note,
+            created_at: x.created_at.unwrap().to_string(),
+        })
+        .collect())
+}
+
+async fn make_time_report(
+    state: &AppState,
+    report: ts::TimeReport,
+) -> Result<TimeReport, AppError> {
+    let usernames: Vec<&str> = report
+        .rows
+        .iter()
+        .filter_map(|x| x.user_id.as_deref())
+        .collect();
+    let profiles = Profiles::fetch(&state.user_database, &usernames).await?;
+    Ok(TimeReport {
+        rows: report
+            .rows
+            .into_iter()
+            .map(|x| TimeReportRow {
+                user: x.user_id.map(|u| profiles.get(&u)),
+                task_id: x.task_id,
+                task_title: x.task_title,
+                day: x.day,
+                minutes: x.minutes,
+            })
+            .collect(),
+        total_minutes: report.total_minutes,
+    })
+}
+
+/// Makes CSV file with a column per group in the requested order and minutes
+/// in the last column.
+fn make_csv(group_by: &[ts::TimeReportGroup], report: ts::TimeReport) -> Response {
+    let mut header = Vec::new();
+    for group in group_by {
+        match group {
+            ts::TimeReportGroup::ByUser => header.push("user"),
+            ts::TimeReportGroup::ByTask => header.extend(["task_id", "task_title"]),
+            ts::TimeReportGroup::ByDay => header.push("day"),
+        }
+    }
+    header.push("minutes");
+    let mut csv = header.join(",") + "\r\n";
+    for row in report.rows {
+        let mut fields = Vec::new();
+        for group in group_by {
+            match group {
+                ts::TimeReportGroup::ByUser => fields.push(row.user_id.clone()),
+                ts::TimeReportGroup::ByTask => {
+                    fields.extend([row.task_id.clone(), row.task_title.clone()])
+                }
+                ts::TimeReportGroup::ByDay => fields.push(row.day.clone()),
+            }
+        }
+        fields.push(Some(row.minutes.to_string()));
+        let fields: Vec<String> = fields
+            .iter()
+            .map(|x| csv_field(x.as_deref().unwrap_or_default()))
+            .collect();
+        csv += &(fields.join(",") + "\r\n");
+    }
+    (
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+            (
+                header::CONTENT_DISPOSITION,
+                "attachment; filename=\"time-report.csv\"",
+            ),
+        ],
+        csv,
+    )
+        .into_response()
+}
+
+/// Quotes the field if needed. Fields starting with formula characters, tab
+/// or carriage return are prefixed with `'`, so spreadsheets don't evaluate
+/// task titles.
+fn csv_field(x: &str) -> String {
+    let x = if x.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{}", x)
+    } else {
+        x.to_string()
+    };
+    if x.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", x.replace('"', "\"\""))
+    } else {
+        x
+    }
+}
+
+fn check_minutes(minutes: i32) -> Result<(), AppError> {
+    if !(1..=MAX_MINUTES).contains(&minutes) {
+        return Err(AppError::IncorrectRequest);
+    }
+    Ok(())
+}
+
+fn check_note(note: &str) -> Result<(), AppError> {
+    if note.chars().count() > MAX_NOTE_LENGTH {
+        return Err(AppError::IncorrectRequest);
+    }
+    Ok(())
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AddTimeEntryRequest {
+    spent_on: String,
+    minutes: i32,
+    note: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateTimeEntryRequest {
+    new_spent_on: Option<String>,
+    new_minutes: Option<i32>,
+    new_note: Option<String>,
+}
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportFormat {
+    Json,
+    Csv,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TimeReportQuery {
+    /// First day of the report
+    from: Option<String>,
+    /// Last day of the report
+    to: Option<String>,
+    /// Only time logged by this user
+    user: Option<String>,
+    task: Option<String>,
+    project: Option<String>,
+    /// Comma separated `user`, `task` and `day`
+    group_by: Option<String>,
+    format: Option<ReportFormat>,
+}
+
+#[derive(Serialize)]
+pub struct TimeEntry {
+    id: String,
+    task_id: String,
+    user: UserProfile,
+    spent_on: String,
+    /// Missing while the timer is running
+    minutes: Option<i32>,
+    /// Start of the timer, missing for entries logged by hand
+    started_at: Option<String>,
+    note: String,
+    created_at: String,
+}
+
+#[derive(Serialize)]
+pub struct TimeReportRow {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<UserProfile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    task_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    task_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    day: Option<String>,
+    minutes: i64,
+}
+
+#[derive(Serialize)]
+pub struct TimeReport {
+    rows: Vec<TimeReportRow>,
+    total_minutes: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn csv(group_by: &[ts::TimeReportGroup], rows: Vec<ts::TimeReportRow>) -> String {
+        let minutes = rows.iter().map(|x| x.minutes).sum();
+        let report = ts::TimeReport {
+            rows,
+            total_minutes: minutes,
+        };
+        let response = make_csv(group_by, report);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            "text/csv; charset=utf-8"
+        );
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        String::from_utf8(body.to_vec()).unwrap()
+    }
+
+    fn row(user: &str, task: (&str, &str), day: &str, minutes: i64) -> ts::TimeReportRow {
+        ts::TimeReportRow {
+            user_id: Some(user.to_string()),
+            task_id: Some(task.0.to_string()),
+            task_title: Some(task.1.to_string()),
+            day: Some(day.to_string()),
+            minutes,
+        }
+    }
+
+    #[tokio::test]
+    async fn csv_has_column_per_group_in_requested_order() {
+        let rows = vec![
+            row("jane", ("t1", "Fix login, again"), "2024-03-01", 90),
+            row("john", ("t2", "Write docs"), "2024-03-02", 30),
+        ];
+        assert_eq!(
+            csv(
+                &[
+                    ts::TimeReportGroup::ByDay,
+                    ts::TimeReportGroup::ByTask,
+                    ts::TimeReportGroup::ByUser
+                ],
+                rows
+            )
+            .await,
+            "day,task_id,task_title,user,minutes\r\n\
+             2024-03-01,t1,\"Fix login, again\",jane,90\r\n\
+             2024-03-02,t2,Write docs,john,30\r\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn csv_without_groups_has_total_only() {
+        let total = ts::TimeReportRow {
+            minutes: 120,
+            ..Default::default()
+        };
+        assert_eq!(csv(&[], vec![total]).await, "minutes\r\n120\r\n");
+    }
+
+    #[test]
+    fn minutes_and_notes_are_limited() {
+        assert!(check_minutes(1).is_ok());
+        assert!(check_minutes(MAX_MINUTES).is_ok());
+        for x in [0, -5, MAX_MINUTES + 1] {
+            assert!(check_minutes(x).is_err(), "{}", x);
+        }
+        assert!(check_note(&"é".repeat(MAX_NOTE_LENGTH)).is_ok());
+        assert!(check_note(&"a".repeat(MAX_NOTE_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn csv_field_escapes_formulas() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("=1+1"), "'=1+1");
+        assert_eq!(csv_field("@SUM(A1)"), "'@SUM(A1)");
+        assert_eq!(csv_field("\t=1+1"), "'\t=1+1");
+        assert_eq!(csv_field("\r=1+1"), "\"'\r=1+1\"");
+        assert_eq!(csv_field("a,\"b\""), "\"a,\"\"b\"\"\"");
+    }
+}
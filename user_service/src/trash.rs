@@ -0,0 +1,192 @@
+use crate::common::{AppClaims, AppError, AppStateRef};
+use crate::events;
+use crate::proto::events::{self as ev, event::Payload};
+use crate::proto::tasks_service as ts;
+use crate::tasks::{make_task, make_tasks, tagged, Task, DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+use crate::users::{Profiles, UserProfile};
+use axum::{
+    extract::{Path, Query, State},
+    response::{Response, Result},
+    Json,
+};
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime};
+use tokio::time::sleep;
+const PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Lists deleted tasks which user owns, recently deleted first.
+pub async fn list_trash_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Query(req): Query<ListTrashRequest>,
+) -> Result<Json<TrashPage>, AppError> {
+    info!("list_trash_handler: handling list trash request");
+    let page_size = req.page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+    let offset = req.offset.unwrap_or(0);
+    if !(1..=MAX_PAGE_SIZE).contains(&page_size) || offset < 0 {
+        return Err(AppError::IncorrectRequest);
+    }
+    let request = tonic::Request::new(ts::ListTrashRequest {
+        user_id: claims.username,
+        offset,
+        page_size,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .list_trash(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    let trash = match response.into_inner().response.unwrap() {
+        ts::trash_response::Response::Trash(x) => x,
+        ts::trash_response::Response::Error(e) => return Err(e.into()),
+    };
+    let usernames: Vec<&str> = trash.items.iter().map(|x| x.deleted_by.as_str()).collect();
+    let profiles = Profiles::fetch(&state.user_database, &usernames).await?;
+    let deleted: Vec<_> = trash
+        .items
+        .iter()
+        .map(|x| {
+            let deleted_at = x.deleted_at.clone().unwrap();
+            let purge_at =
+                SystemTime::try_from(deleted_at.clone()).unwrap() + state.trash_retention;
+            (
+                deleted_at.to_string(),
+                profiles.get(&x.deleted_by),
+                prost_types::Timestamp::from(purge_at).to_string(),
+            )
+        })
+        .collect();
+    let tasks = trash.items.into_iter().map(|x| x.task.unwrap()).collect();
+    Ok(Json(TrashPage {
+        items: make_tasks(&state, tasks)
+            .await?
+            .into_iter()
+            .zip(deleted)
+            .map(|(task, (deleted_at, deleted_by, purge_at))| TrashItem {
+                task,
+                deleted_at,
+                deleted_by,
+                purge_at,
+            })
+            .collect(),
+        has_more: trash.has_more,
+    }))
+}
+
+pub async fn restore_task_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+) -> Result<Response, AppError> {
+    info!("restore_task_handler: handling restore task request");
+    let request = tonic::Request::new(ts::RestoreTaskRequest {
+        user_id: claims.username.clone(),
+        task_id,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .restore_task(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    match response.into_inner().response.unwrap() {
+        ts::task_response::Response::Task(x) => {
+            events::emit(
+                &state,
+                Payload::TaskRestored(ev::TaskRestored {
+                    task_id: x.id.clone(),
+                    user_id: claims.username,
+                }),
+            )
+            .await?;
+            Ok(tagged(make_task(&state, x).await?))
+        }
+        ts::task_response::Response::Error(e) => Err(e.into()),
+    }
+}
+
+/// Permanently deletes the task from trash.
+pub async fn purge_task_handler(
+    State(state): State<AppStateRef>,
+    claims: AppClaims,
+    Path(task_id): Path<String>,
+) -> Result<Json<Task>, AppError> {
+    info!("purge_task_handler: handling purge task request");
+    let request = tonic::Request::new(ts::PurgeTaskRequest {
+        user_id: claims.username.clone(),
+        task_id,
+    });
+    let response = state
+        .tasks_service
+        .write()
+        .await
+        .purge_task(request)
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?;
+    match response.into_inner().response.unwrap() {
+        ts::task_response::Response::Task(x) => {
+            events::emit(
+                &state,
+                Payload::TaskPurged(ev::TaskPurged {
+                    task_id: x.id.clone(),
+                    user_id: claims.username,
+                }),
+            )
+            .await?;
+            Ok(Json(make_task(&state, x).await?))
+        }
+        ts::task_response::Response::Error(e) => Err(e.into()),
+    }
+}
+
+/// Periodically purges tasks which are in trash longer than retention period.
+pub async fn run_trash_purge(state: AppStateRef) {
+    loop {
+        let request = tonic::Request::new(ts::PurgeExpiredTasksRequest {
+            deleted_before: Some((SystemTime::now() - state.trash_retention).into()),
+        });
+        let response = state
+            .tasks_service
+            .write()
+            .await
+            .purge_expired_tasks(request)
+            .await;
+        match response.map(|x| x.into_inner().response) {
+            Ok(Some(ts::purge_expired_tasks_response::Response::PurgedCount(n))) => {
+                info!("run_trash_purge: purged {} tasks", n)
+            }
+            Ok(Some(ts::purge_expired_tasks_response::Response::Error(e))) => {
+                error!("run_trash_purge: couldn't purge tasks: {}", e.message)
+            }
+            Ok(None) => error!("run_trash_purge: empty response"),
+            Err(e) => error!("run_trash_purge: couldn't purge tasks: {}", e),
+        }
+        sleep(PURGE_INTERVAL).await;
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ListTrashRequest {
+    offset: Option<i32>,
+    page_size: Option<i32>,
+}
+
+#[derive(Serialize)]
+pub struct TrashItem {
+    task: Task,
+    deleted_at: String,
+    deleted_by: UserProfile,
+    /// When the task is going to be purged permanently
+    purge_at: String,
+}
+
+#[derive(Serialize)]
+pub struct TrashPage {
+    items: Vec<TrashItem>,
+    has_more: bool,
+}
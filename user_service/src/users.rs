@@ -0,0 +1,91 @@
+use crate::common::AppError;
+use serde::Serialize;
+use std::collections::HashMap;
+use tokio_postgres::Client;
+
+/// Compact public profile of the user embedded into other responses.
+#[derive(Serialize, Clone)]
+pub struct UserProfile {
+    username: String,
+    first_name: Option<String>,
+    last_name: Option<String>,
+}
+
+impl UserProfile {
+    fn unknown(username: &str) -> Self {
+        UserProfile {
+            username: username.to_string(),
+            first_name: None,
+            last_name: None,
+        }
+    }
+}
+
+pub async fn ensure_user_exists(db: &Client, username: &str) -> Result<(), AppError> {
+    db.query_opt("SELECT 1 FROM users WHERE username=$1", &[&username])
+        .await
+        .map_err(|_| AppError::IncorrectRequest)?
+        .ok_or(AppError::NonExistingUser)?;
+    Ok(())
+}
+
+/// Profiles of users found by username.
+#[derive(Default)]
+pub struct Profiles(HashMap<String, UserProfile>);
+
+impl Profiles {
+    pub async fn fetch(db: &Client, usernames: &[&str]) -> Result<Self, AppError> {
+        if usernames.is_empty() {
+            return Ok(Profiles::default());
+        }
+        let rows = db
+            .query(
+                "SELECT username, first_name, last_name FROM users WHERE username=ANY($1)",
+                &[&usernames],
+            )
+            .await
+            .map_err(|_| AppError::IncorrectRequest)?;
+        Ok(Profiles(
+            rows.into_iter()
+                .map(|row| {
+                    let profile = UserProfile {
+                        username: row.get(0),
+                        first_name: row.get(1),
+                        last_name: row.get(2),
+                    };
+                    (profile.username.clone(), profile)
+                })
+                .collect(),
+        ))
+    }
+
+    pub fn get(&self, username: &str) -> UserProfile {
+        self.0
+            .get(username)
+            .cloned()
+            .unwrap_or_else(|| UserProfile::unknown(username))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_users_have_profile_with_username_only() {
+        let jane = UserProfile {
+            username: "jane".to_string(),
+            first_name: Some("Jane".to_string()),
+            last_name: Some("Doe".to_string()),
+        };
+        let profiles = Profiles(HashMap::from([("jane".to_string(), jane)]));
+        assert_eq!(
+            serde_json::to_value(profiles.get("jane")).unwrap(),
+            serde_json::json!({"username": "jane", "first_name": "Jane", "last_name": "Doe"})
+        );
+        assert_eq!(
+            serde_json::to_value(Profiles::default().get("john")).unwrap(),
+            serde_json::json!({"username": "john", "first_name": null, "last_name": null})
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use crate::proto::tasks_service::TaskStatus;
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+/// State machine describing which status changes are allowed for a task.
+///
+/// Every transition has a name (e.g. `reopen`) and moves a task from one of
+/// the `from` statuses to the `to` status. Status changes which are not
+/// described by a transition are rejected by the user service.
+pub struct Workflow {
+    transitions: Vec<Transition>,
+}
+
+pub struct Transition {
+    pub name: String,
+    pub from: Vec<TaskStatus>,
+    pub to: TaskStatus,
+}
+
+impl Workflow {
+    /// Loads workflow from JSON file in format of [`WorkflowConfig`].
+    pub fn from_file(path: &str) -> Result<Self, String> {
+        let content =
+            fs::read_to_string(path).map_err(|e| format!("couldn't read {}: {}", path, e))?;
+        let config: WorkflowConfig = serde_json::from_str(&content)
+            .map_err(|e| format!("couldn't parse {}: {}", path, e))?;
+        Self::try_from(config)
+    }
+
+    /// Finds transition with given name which can be applied to task in `from` status.
+    pub fn find(&self, name: &str, from: TaskStatus) -> Option<&Transition> {
+        self.transitions
+            .iter()
+            .find(|t| t.name == name && t.from.contains(&from))
+    }
+
+    /// Lists transitions with given name regardless of task status.
+    pub fn named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Transition> {
+        self.transitions.iter().filter(move |t| t.name == name)
+    }
+
+    /// Lists transitions which can be applied to task in `from` status.
+    pub fn available(&self, from: TaskStatus) -> impl Iterator<Item = &Transition> {
+        self.transitions
+            .iter()
+            .filter(move |t| t.from.contains(&from))
+    }
+}
+
+impl Default for Workflow {
+    fn default() -> Self {
+        use TaskStatus::*;
+        let transition = |name: &str, from: &[TaskStatus], to| Transition {
+            name: name.to_string(),
+            from: from.to_vec(),
+            to,
+        };
+        Workflow {
+            transitions: vec![
+                transition("start", &[Open], InProgress),
+                transition("stop", &[InProgress], Open),
+                transition("submit_for_review", &[InProgress], PendingReview),
+                transition("request_changes", &[PendingReview, Testing], InProgress),
+                transition("approve", &[PendingReview], Testing),
+                transition("complete", &[InProgress, Testing], Completed),
+                transition(
+                    "cancel",
+                    &[Open, InProgress, PendingReview, Testing],
+                    Cancelled,
+                ),
+                transition("reopen", &[Completed, Cancelled], Open),
+            ],
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorkflowConfig {
+    transitions: Vec<TransitionConfig>,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct TransitionConfig {
+    pub name: String,
+    pub from: Vec<String>,
+    pub to: String,
+}
+
+impl TryFrom<WorkflowConfig> for Workflow {
+    type Error = String;
+
+    fn try_from(config: WorkflowConfig) -> Result<Self, Self::Error> {
+        let parse_status = |name: &str| {
+            TaskStatus::from_str_name(name).ok_or_else(|| format!("unknown task status {}", name))
+        };
+        let mut transitions = Vec::with_capacity(config.transitions.len());
+        for t in config.transitions {
+            transitions.push(Transition {
+                from: t
+                    .from
+                    .iter()
+                    .map(|x| parse_status(x))
+                    .collect::<Result<_, _>>()?,
+                to: parse_status(&t.to)?,
+                name: t.name,
+            });
+        }
+        Ok(Workflow { transitions })
+    }
+}
+
+impl From<&Transition> for TransitionConfig {
+    fn from(t: &Transition) -> Self {
+        TransitionConfig {
+            name: t.name.clone(),
+            from: t.from.iter().map(|x| x.as_str_name().to_string()).collect(),
+            to: t.to.as_str_name().to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use TaskStatus::*;
+
+    #[test]
+    fn default_workflow_finds_transitions_from_status() {
+        let workflow = Workflow::default();
+        assert_eq!(workflow.find("start", Open).map(|t| t.to), Some(InProgress));
+        assert_eq!(
+            workflow.find("complete", Testing).map(|t| t.to),
+            Some(Completed)
+        );
+        assert!(workflow.find("start", InProgress).is_none());
+        assert!(workflow.find("unknown", Open).is_none());
+    }
+
+    #[test]
+    fn default_workflow_lists_available_transitions() {
+        let workflow = Workflow::default();
+        let names = |from| {
+            workflow
+                .available(from)
+                .map(|t| t.name.as_str())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(Open), ["start", "cancel"]);
+        assert_eq!(names(Completed), ["reopen"]);
+        assert_eq!(workflow.named("request_changes").count(), 1);
+    }
+
+    #[test]
+    fn config_is_parsed_into_workflow() {
+        let config: WorkflowConfig = serde_json::from_str(
+            r#"{"transitions": [{"name": "finish", "from": ["Open", "Testing"], "to": "Completed"}]}"#,
+        )
+        .unwrap();
+        let workflow = Workflow::try_from(config).unwrap();
+        let transition = workflow.find("finish", Testing).unwrap();
+        assert_eq!(transition.to, Completed);
+        let config = TransitionConfig::from(transition);
+        assert_eq!(config.from, ["Open", "Testing"]);
+        assert_eq!(config.to, "Completed");
+    }
+
+    #[test]
+    fn config_with_unknown_status_is_rejected() {
+        let config: WorkflowConfig = serde_json::from_str(
+            r#"{"transitions": [{"name": "finish", "from": ["Done"], "to": "Completed"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            Workflow::try_from(config).err().as_deref(),
+            Some("unknown task status Done")
+        );
+    }
+
+    #[test]
+    fn config_with_unknown_field_is_rejected() {
+        let config =
+            serde_json::from_str::<WorkflowConfig>(r#"{"transitions": [], "initial": "Open"}"#);
+        assert!(config.is_err());
+    }
+}